@@ -0,0 +1,104 @@
+// Advent of Code 2015 day 1: Not Quite Lisp
+
+use shared::{
+    Answer,
+    AocError,
+    solution::Solution,
+    validate,
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<isize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse(input))
+    }
+
+    fn part1(&self, steps: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(steps).into())
+    }
+
+    fn part2(&self, steps: &Self::Input) -> Result<Answer, AocError> {
+        match part2(steps) {
+            Some(position) => Ok(position.into()),
+            None => Err(AocError::Unsolvable { reason: "Santa never ended up in the basement".to_string() }),
+        }
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        match part {
+            1 => format!("Santa ended up on floor {answer}"),
+            _ => format!("Santa ended up in the basement in position {answer}"),
+        }
+    }
+}
+
+// Turn the directions into the change of floor for each one.
+// ( = +1
+// ) = -1
+// Line breaks are skipped, anything else is rejected and left out.
+pub fn parse(directions: &str) -> Vec<isize> {
+    let mut steps: Vec<isize> = Vec::new();
+
+    for (i, line) in directions.lines().enumerate() {
+        for (column, direction) in line.chars().enumerate() {
+            match direction {
+                '(' => {
+                    steps.push(1);
+                },
+                ')' => {
+                    steps.push(-1);
+                },
+                _ => {
+                    validate::reject(i + 1, column + 1, &direction.to_string(), "expected ( or )");
+                }
+            }
+        }
+    }
+
+    steps
+}
+
+// Move Santa by following the directions and return the floor he ends up on.
+pub fn part1(steps: &[isize]) -> isize {
+    // Santa starts on floor 0
+    steps.iter().sum()
+}
+
+// Move Santa by following the directions and return the position of the
+// direction that first takes him into the basement, or None if he never goes
+// into the basement.
+pub fn part2(steps: &[isize]) -> Option<usize> {
+
+    // Santa starts on floor 0
+    let mut floor: isize = 0;
+
+    for (i, step) in steps.iter().enumerate() {
+        floor += step;
+        // Leave as soon as we go into the basement. Positions start at 1.
+        if floor < 0 {
+            return Some(i + 1);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_everything_but_brackets_and_line_breaks() {
+        assert_eq!(parse("(()\r\n)\n"), [1, 1, -1, -1]);
+        assert!(validate::take().is_empty());
+
+        assert_eq!(parse("(x)\n)"), [1, -1, -1]);
+        let problems = validate::take();
+        assert_eq!(problems.len(), 1);
+        let shared::AocError::Input { span, .. } = &problems[0] else { panic!("{:?}", problems[0]) };
+        assert_eq!((span.line, span.column, span.len), (1, 2, 1));
+    }
+}
@@ -0,0 +1,6 @@
+use shared::solution;
+use aoc_2015_01_part1_2::Day01;
+
+fn main() {
+    solution::run(&Day01);
+}
@@ -0,0 +1,44 @@
+"""2015 day 1 in Python, run by aoc through solution.json.
+
+Reads the JSON request from stdin and prints the answers as JSON on stdout.
+"""
+import json
+import sys
+
+
+def final_floor(directions):
+    return directions.count("(") - directions.count(")")
+
+
+def basement_position(directions):
+    floor = 0
+    for position, direction in enumerate(directions, start=1):
+        floor += 1 if direction == "(" else -1
+        if floor == -1:
+            return position
+    return None
+
+
+def solve(request):
+    directions = request["input"].strip()
+    unexpected = set(directions) - set("()")
+    if unexpected:
+        return {"error": f"unexpected characters in the input: {''.join(sorted(unexpected))}"}
+
+    answers = {}
+    errors = {}
+    if 1 in request["parts"]:
+        answers["1"] = final_floor(directions)
+    if 2 in request["parts"]:
+        position = basement_position(directions)
+        if position is None:
+            errors["2"] = "Santa never enters the basement"
+        else:
+            answers["2"] = position
+
+    return {"answers": answers, "errors": errors}
+
+
+if __name__ == "__main__":
+    json.dump(solve(json.load(sys.stdin)), sys.stdout)
+    print()
@@ -0,0 +1,152 @@
+// Advent of Code 2015 day 2: I Was Told There Would Be No Math
+
+use shared::{
+    *,
+    solution::Solution,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Present>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse(input))
+    }
+
+    fn part1(&self, presents: &Self::Input) -> Result<Answer, AocError> {
+        part1(presents).map(Answer::from)
+    }
+
+    fn part2(&self, presents: &Self::Input) -> Result<Answer, AocError> {
+        part2(presents).map(Answer::from)
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        match part {
+            1 => format!("The total square footage required is {answer}"),
+            _ => format!("The total length of ribbon required is {answer} feet"),
+        }
+    }
+}
+
+// The length, width and height of a present
+pub type Present = (usize, usize, usize);
+
+// Read the dimensions of every present in the list. List should be lxwxh
+// format. Lines that can't be read are rejected and left out.
+pub fn parse(presents: &str) -> Vec<Present> {
+    let mut dimensions: Vec<Present> = Vec::new();
+
+    for (i, present) in presents.lines().enumerate() {
+        // Skip empty lines
+        if present.is_empty() {
+            continue;
+        }
+
+        match parse_dimensions(present) {
+            Ok(d) => dimensions.push(d),
+            Err((part, msg)) => validate::reject(i + 1, validate::column(present, part), part, msg),
+        }
+    }
+
+    dimensions
+}
+
+// Calculate the total square footage of wrapping paper needed to wrap all the
+// presents in the list.
+// Formula for calculating the square footage is:
+// 2*l*w + 2*w*h + 2*h*l + smallest_side
+//
+// Returns an AocError if the total overflows
+pub fn part1(presents: &[Present]) -> Result<usize, AocError> {
+    
+    let mut total_area: usize = 0;
+
+    for &(length, width, height) in presents {
+        let sides= [arith::mul(length, width)?, arith::mul(width, height)?, arith::mul(height, length)?];
+        let smallest_side = match sides.iter().min() {
+            Some(n) => n,
+            None => {
+                eprintln!("There was a problem finding the smallest side with {length}x{width}x{height}");
+                continue;
+            },
+        };
+
+        let present_square_footage = arith::sum([
+            arith::mul(2, sides[0])?,
+            arith::mul(2, sides[1])?,
+            arith::mul(2, sides[2])?,
+            *smallest_side,
+        ])?;
+
+        total_area = arith::add(total_area, present_square_footage)?;
+    }
+
+    Ok(total_area)
+}
+
+// Calculate the total length of ribbon needed for all the presents.
+// Smallest perimeter of any face, AND cubic feet of volume of the present
+//
+// Returns an AocError if the total overflows
+pub fn part2(presents: &[Present]) -> Result<usize, AocError> {
+    
+    let mut total_length: usize = 0;
+
+    for &(length, width, height) in presents {
+        let perimeters= [
+            arith::mul(2, arith::add(length, width)?)?,
+            arith::mul(2, arith::add(width, height)?)?,
+            arith::mul(2, arith::add(height, length)?)?,
+        ];
+        let smallest_perimeter = match perimeters.iter().min() {
+            Some(n) => n,
+            None => {
+                eprintln!("There was a problem finding the smallest perimeter with {length}x{width}x{height}");
+                continue;
+            },
+        };
+
+        let volume = arith::product([length, width, height])?;
+        let present_ribbon_length = arith::add(volume, *smallest_perimeter)?;
+
+        total_length = arith::add(total_length, present_ribbon_length)?;
+    }
+
+    Ok(total_length)
+}
+
+// Parse the length width and height from a string formatted lxwxh
+// Return a Result, with Ok(l, w, h) or Err((part, message)) where part is the
+// slice of the line that is wrong
+pub fn parse_dimensions(line: &str) -> Result<(usize, usize, usize), (&str, &'static str)> {
+
+    let parts: Vec<&str> = line.splitn(3, 'x').collect();
+    if parts.len() != 3 {
+        return Err((line, "We did not find 3 dimensions"))
+    }
+
+    let length: usize = match parts[0].parse() {
+        Ok(n) => n,
+        Err(_) => {
+            return Err((parts[0], "Length was not a valid number!"))
+        }
+    };
+
+    let width: usize = match parts[1].parse() {
+        Ok(n) => n,
+        Err(_) => {
+            return Err((parts[1], "Width was not a valid number!"))
+        }
+    };
+
+    let height: usize = match parts[2].parse() {
+        Ok(n) => n,
+        Err(_) => {
+            return Err((parts[2], "Height was not a valid number!"))
+        }
+    };
+
+    Ok((length, width, height))
+}
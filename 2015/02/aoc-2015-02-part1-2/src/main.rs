@@ -0,0 +1,6 @@
+use shared::solution;
+use aoc_2015_02_part1_2::Day02;
+
+fn main() {
+    solution::run(&Day02);
+}
@@ -0,0 +1,56 @@
+use std::{env, fs, process::{Command, Output}};
+
+const INPUT: &str = "2x3x4\n2xax4\n1x1x10\n";
+
+// Run the solver on INPUT with the given flags
+fn run(name: &str, flags: &[&str]) -> Output {
+    let path = env::temp_dir().join(format!("aoc-2015-02-{name}-{}.txt", std::process::id()));
+    fs::write(&path, INPUT).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc-2015-02-part1-2"))
+        .arg(&path)
+        .args(flags)
+        .output()
+        .unwrap();
+    let _ = fs::remove_file(&path);
+
+    output
+}
+
+fn text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).to_string()
+}
+
+// The bad width stops the run, pointing at the a
+#[test]
+fn strict_stops_at_the_bad_line() {
+    let output = run("strict", &[]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(text(&output.stdout), "");
+
+    let stderr = text(&output.stderr);
+    assert!(stderr.contains(":2:3\n"), "{stderr}");
+    assert!(stderr.contains("2 | 2xax4\n  |   ^\n"), "{stderr}");
+    assert!(stderr.contains("1 line(s) could not be used"), "{stderr}");
+}
+
+// The other presents are solved, with a warning about the bad one
+#[test]
+fn lenient_solves_without_the_bad_line() {
+    let output = run("lenient", &["--lenient"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        text(&output.stdout),
+        "The total square footage required is 101\nThe total length of ribbon required is 48 feet\n",
+    );
+    assert!(text(&output.stderr).starts_with("WARNING: skipped "), "{}", text(&output.stderr));
+}
+
+#[test]
+fn check_lists_the_problem_and_fails() {
+    let output = run("check", &["--check"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    let stdout = text(&output.stdout);
+    assert!(stdout.contains(":2:3: Width was not a valid number!: a\n"), "{stdout}");
+    assert!(stdout.ends_with(": 1 problem(s) found\n"), "{stdout}");
+}
@@ -0,0 +1,173 @@
+// Advent of Code 2015 day 3: Perfectly Spherical Houses in a Vacuum
+
+use shared::{
+    Answer,
+    AocError,
+    solution::Solution,
+    validate,
+};
+use std::collections::HashMap;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse(input))
+    }
+
+    fn part1(&self, directions: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(directions).into())
+    }
+
+    fn part2(&self, directions: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(directions).into())
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        match part {
+            1 => format!("Santa visited {answer} distinct houses!"),
+            _ => format!("The Santas visited {answer} distinct houses!"),
+        }
+    }
+}
+
+// The directions in the input, without any whitespace. Anything that isn't one
+// of ^v<> is rejected and left out.
+pub fn parse(input: &str) -> String {
+    let mut directions = String::new();
+    for (i, line) in input.lines().enumerate() {
+        for (column, dir) in line.chars().enumerate() {
+            match dir {
+                '^' | 'v' | '<' | '>' => directions.push(dir),
+                _ if dir.is_whitespace() => {},
+                _ => validate::reject(i + 1, column + 1, &dir.to_string(), "invalid direction"),
+            }
+        }
+    }
+
+    directions
+}
+
+// Follow the ^v<> directions to move santa to a new house.
+// Track each house in a HashMap with the key to be the x,y coordinates of the
+// house and the value to be the number of times it was visited.
+pub fn move_santa(directions: &str) -> HashMap<String, usize> {
+
+    let mut  x: isize = 0;
+    let mut y: isize = 0;
+
+    let mut visits: HashMap<String, usize> = HashMap::new();
+
+    for (i, dir) in directions.trim().chars().enumerate() {
+        let mut location_string = String::new();
+        location_string.push_str(&x.to_string());
+        location_string.push(',');
+        location_string.push_str(&y.to_string());
+
+        let num_visits = match visits.get(&location_string) {
+            Some(v) => {
+                visits.insert(location_string.clone(), v + 1);
+                visits.get(&location_string).unwrap()
+
+            },
+            None => {
+                visits.insert(location_string.clone(), 1);
+                // This unwrap should never panic, we just inserted the key
+                visits.get(&location_string).unwrap()
+            }
+        };
+
+        match dir {
+            '^' => y -= 1,
+            'v' => y += 1,
+            '<' => x -= 1,
+            '>' => x += 1,
+            _ => {
+                // If an invalid character is discovered we don't move, so on the
+                // next iteration of the loop the current positions visits will 
+                // be incremented.  To resolve, decrement here, unless we're on 
+                // the last direction
+                eprintln!("An invalid direction was encountered: {dir}");
+                if i == directions.len() - 1 {
+                    eprintln!("Decrementing {x},{y}");
+                    visits.insert(location_string, num_visits - 1);
+                }
+                continue;
+            }
+        }
+    }
+
+    visits
+}
+
+// The number of distinct houses Santa visited
+pub fn part1(directions: &str) -> usize {
+    move_santa(directions).len()
+}
+
+// Follow the ^v<> directions to move Santa and Robo-Santa, taking turns, to a
+// new house.
+// Track each house in a HashMap with the key to be the x,y coordinates of the
+// house and the value to be the number of times it was visited.
+pub fn move_santas(directions: &str) -> HashMap<String, usize> {
+
+    // Both santas start at 0,0
+    let mut santa_coords: Vec<(isize, isize)> = vec![(0, 0), (0, 0)];
+
+    // Pre-load the HashMap with 0,0 and a value of 2, sine both santas visit the
+    // starting location
+    let mut visits: HashMap<String, usize> = HashMap::new();
+    visits.insert("0,0".to_string(), 2);
+
+    for (i, dir) in directions.trim().chars().enumerate() {
+        // We're going to move Santa 0 for even iterations and Santa 1 for odd 
+        // iterations.  Pull the current coordinates for the appropriate Santa
+        let santa_to_move = i % 2;
+        let (mut x, mut y) = santa_coords[santa_to_move];
+
+        // Update the coordinates according to the direction.
+        match dir {
+            '^' => y -= 1,
+            'v' => y += 1,
+            '<' => x -= 1,
+            '>' => x += 1,
+            _ => {
+                eprintln!("An invalid direction was encountered: {dir}");
+                continue;
+            }
+        }
+
+        // Stringify the coordinates
+        let mut location_string = String::new();
+        location_string.push_str(&x.to_string());
+        location_string.push(',');
+        location_string.push_str(&y.to_string());
+
+        // Update the visits HashMap - We don't actually need the total number of 
+        // visits.
+        let _num_visits = match visits.get(&location_string) {
+            Some(v) => {
+                visits.insert(location_string.clone(), v + 1);
+                visits.get(&location_string).unwrap()
+
+            },
+            None => {
+                visits.insert(location_string.clone(), 1);
+                // This unwrap should never panic, we just inserted the key
+                visits.get(&location_string).unwrap()
+            }
+        };
+
+        // Store the new coordinates
+        santa_coords[santa_to_move] = (x, y);
+    }
+
+    visits
+}
+
+// The number of distinct houses Santa and Robo-Santa visited
+pub fn part2(directions: &str) -> usize {
+    move_santas(directions).len()
+}
@@ -0,0 +1,6 @@
+use shared::solution;
+use aoc_2015_03_part1_2::Day03;
+
+fn main() {
+    solution::run(&Day03);
+}
@@ -0,0 +1,78 @@
+// Advent of Code 2015 day 4: The Ideal Stocking Stuffer
+
+use shared::{
+    *,
+    checkpoint::Checkpoint,
+    solution::Solution,
+};
+
+// The hash has to start with this many zeros in each part
+pub const LEADING_ZEROS: [usize; 2] = [5, 6];
+
+// The secret key is the input as it is, and each part's search saves its
+// progress to a checkpoint of its own
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, secret_key: &Self::Input) -> Result<Answer, AocError> {
+        let mut checkpoint = Checkpoint::new("2015-04-part1", secret_key);
+        found(LEADING_ZEROS[0], part1(secret_key, &mut checkpoint))
+    }
+
+    fn part2(&self, secret_key: &Self::Input) -> Result<Answer, AocError> {
+        let mut checkpoint = Checkpoint::new("2015-04-part2", secret_key);
+        found(LEADING_ZEROS[1], part2(secret_key, &mut checkpoint))
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        let leading_zeros = LEADING_ZEROS[usize::from(part) - 1];
+        format!("The smallest number that produces a hash with {leading_zeros} leading zeros is {answer}")
+    }
+}
+
+// The number the search found, or why there isn't one
+fn found(leading_zeros: usize, number: Option<usize>) -> Result<Answer, AocError> {
+    match number {
+        Some(number) => Ok(number.into()),
+        None => Err(AocError::Unsolvable { reason: format!("no number produces a hash with {leading_zeros} leading zeros") }),
+    }
+}
+
+// The smallest number that makes an AdventCoin, or None if there isn't one
+pub fn part1(secret_key: &str, checkpoint: &mut Checkpoint) -> Option<usize> {
+    mine_advent_coins(secret_key, LEADING_ZEROS[0], checkpoint)
+}
+
+// The smallest number that makes an AdventCoin with the extra zero, or None if
+// there isn't one
+pub fn part2(secret_key: &str, checkpoint: &mut Checkpoint) -> Option<usize> {
+    mine_advent_coins(secret_key, LEADING_ZEROS[1], checkpoint)
+}
+
+// Append each number to the end of your secret_key string, calculate the MD5
+// hex and examine to see how many leading 0s are present. The numbers are
+// checked in parallel, and this will find the smallest number that will result
+// in a hash with x 0s. Progress is saved to the checkpoint so the search can be
+// resumed with --resume.
+pub fn mine_advent_coins(secret_key: &str, num_leading_zeros: usize, checkpoint: &mut Checkpoint) -> Option<usize> {
+    parallel::find_first_checkpointed(parallel::threads(), 0..usize::MAX, checkpoint, |number| {
+        let mut string = String::from(secret_key);
+        string.push_str(&number.to_string());
+
+        let digest = md5::compute(&string);
+
+        // We cannot examine bytes. Need to examine the hex string itself.
+        let digest_hex_string = format!("{:x}", digest);
+
+        digest_hex_string
+            .chars()
+            .take(num_leading_zeros)
+            .all(|char| char == '0')
+    })
+}
@@ -0,0 +1,6 @@
+use shared::solution;
+use aoc_2015_04_part1_2::Day04;
+
+fn main() {
+    solution::run(&Day04);
+}
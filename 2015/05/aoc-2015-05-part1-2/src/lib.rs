@@ -0,0 +1,137 @@
+// Advent of Code 2015 day 5: Doesn't He Have Intern-Elves For This?
+
+use shared::{
+    Answer,
+    AocError,
+    solution::Solution,
+};
+use std::collections::HashMap;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse(input).into_iter().map(String::from).collect())
+    }
+
+    fn part1(&self, strings: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(strings).into())
+    }
+
+    fn part2(&self, strings: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(strings).into())
+    }
+
+    fn describe(&self, _part: u8, answer: &Answer) -> String {
+        format!("The total number of nice strings is {answer}")
+    }
+}
+
+// The strings to check, one per line. Empty lines are skipped.
+pub fn parse(contents: &str) -> Vec<&str> {
+    contents.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+// Count the strings that are nice
+pub fn part1(strings: &[impl AsRef<str>]) -> usize {
+    strings.iter().filter(|s| is_nice(s.as_ref())).count()
+}
+
+// Count the strings that are nice by the new rules
+pub fn part2(strings: &[impl AsRef<str>]) -> usize {
+    strings.iter().filter(|s| is_nicer(s.as_ref())).count()
+}
+
+// Evaluate if a string is nice or not
+pub fn is_nice(input_string: &str) -> bool {
+
+    if input_string.trim().is_empty() {
+        return false
+    }
+
+    let input_string_chars: Vec<char> = input_string.trim().chars().collect();
+
+    let vowels = ['a', 'e', 'i', 'o', 'u'];
+    let forbidden_strings = ["ab", "cd", "pq", "xy"];
+
+    let mut vowel_count:u8 = 0;
+    let mut double_letters = false;
+
+    // Initialize last_c to a null-byte.
+    let mut last_c: char = '\0';
+
+    for c in input_string_chars {
+        let mut last_two = String::with_capacity(2);
+        last_two.push(last_c);
+        last_two.push(c);
+
+        // Fast fail if we have a forbidden string
+        if forbidden_strings.contains(&last_two.as_str()) {
+            return false
+        }
+
+        if c == last_c {
+            double_letters = true;
+        }
+
+        if vowels.contains(&c) {
+            vowel_count = vowel_count.saturating_add(1);
+        }
+
+        last_c = c;
+    }
+
+    vowel_count >= 3 && double_letters
+}
+
+// Evaluate if a string is nice by the new rules
+pub fn is_nicer(input_string: &str) -> bool {
+
+    if input_string.trim().is_empty() {
+        return false
+    }
+
+    let input_string_chars: Vec<char> = input_string.trim().chars().collect();
+
+    let mut double_letters: HashMap<String, (usize, usize)> = HashMap::new();
+
+    let mut repeating_chars: bool = false;
+    let mut pair_of_pairs: bool = false;
+
+    for (i, c) in input_string_chars.iter().enumerate() {
+        // Cannot perform evaluations on the first char.
+        if i == 0 {
+            continue;
+        }
+
+        let mut last_two = String::with_capacity(2);
+        last_two.push(input_string_chars[i-1]);
+        last_two.push(*c);
+
+        // Check if we've encountered these two letters before by searching for 
+        // them in the HashMap.  If we have seen them, confirm that the indicies 
+        // do not overlap.
+        // If they're new, store them in the HashMap with where we found them
+        match double_letters.get(&last_two) {
+            Some((pi1, pi2)) => {
+                if *pi1 != i && *pi1 != i-1 && *pi2 != i && *pi2 != i-1 {
+                    pair_of_pairs = true;
+                }
+            },
+            None => {
+                double_letters.insert(last_two, (i-1, i));
+            }
+        }
+
+        if i >= 2 && input_string_chars[i-2] == *c {
+            repeating_chars = true;
+        }
+    }
+
+    repeating_chars && pair_of_pairs
+}
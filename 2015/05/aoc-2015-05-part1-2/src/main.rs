@@ -0,0 +1,6 @@
+use shared::solution;
+use aoc_2015_05_part1_2::Day05;
+
+fn main() {
+    solution::run(&Day05);
+}
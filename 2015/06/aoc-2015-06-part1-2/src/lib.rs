@@ -0,0 +1,141 @@
+// Advent of Code 2015 day 6: Probably a Fire Hazard
+
+use shared::{
+    Answer,
+    AocError,
+    solution::Solution,
+    validate,
+};
+use regex::Regex;
+
+// Both parts through the shared Solution trait, run with solution::run
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<(String, usize, usize, usize, usize)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_directions(input))
+    }
+
+    fn part1(&self, directions: &Self::Input) -> Result<Answer, AocError> {
+        let mut grid: Vec<Vec<bool>> = vec![vec![false; 1000]; 1000];
+
+        execute(&mut grid, directions, |action, light| match action {
+            "turn on" => *light = true,
+            "turn off" => *light = false,
+            _ => *light = !*light,
+        });
+
+        Ok(count_lights_on(&grid).into())
+    }
+
+    fn part2(&self, directions: &Self::Input) -> Result<Answer, AocError> {
+        let mut grid: Vec<Vec<usize>> = vec![vec![0; 1000]; 1000];
+
+        execute(&mut grid, directions, |action, light| match action {
+            "turn on" => *light += 1,
+            "turn off" => *light = light.saturating_sub(1),
+            _ => *light += 2,
+        });
+
+        Ok(sum_brightness(&grid).into())
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        match part {
+            1 => format!("The number of lights on is {answer}"),
+            _ => format!("The total brightness is {answer}"),
+        }
+    }
+}
+
+// Follow each direction over its rectangle of lights, using apply to change
+// each light for the action
+pub fn execute<T>(
+    grid: &mut [Vec<T>],
+    directions: &[(String, usize, usize, usize, usize)],
+    apply: impl Fn(&str, &mut T),
+) {
+    for direction in directions {
+        let action = direction.0.as_str();
+        if !matches!(action, "turn on" | "turn off" | "toggle") {
+            eprintln!("An unknown action was encountered! {action}");
+            continue;
+        }
+
+        for row in &mut grid[direction.2..=direction.4] {
+            for light in &mut row[direction.1..=direction.3] {
+                apply(action, light);
+            }
+        }
+    }
+}
+
+// Pull the relevent information out of the direction line. Directions whose
+// rectangle ends before it starts are rejected.
+//
+// # Panics!
+//
+// This function will panic if the regex cannot compile.
+// This function will also panic if for some reason the regex matches numerical 
+// data that is invalid
+pub fn parse_directions(lines: &str) -> Vec<(String, usize, usize, usize, usize)> {
+    let re = Regex::new(
+        r"(?P<action>(turn (on|off))|toggle) (?P<sx>[0-9]{1,3}),(?P<sy>[0-9]{1,3}) through (?P<ex>[0-9]{1,3}),(?P<ey>[0-9]{1,3})"
+    ).unwrap();
+
+    let mut directions = Vec::new();
+
+    for (i, line) in lines.lines().enumerate() {
+        if line.trim().is_empty() { continue; }
+
+        let caps = match re.captures(line.trim()) {
+            Some(c) => c,
+            None => {
+                validate::reject(i + 1, 1, line, "expected \"turn on|turn off|toggle <x>,<y> through <x>,<y>\"");
+                continue;
+            }
+        };
+
+        let action = caps["action"].to_string();
+        // Safe to use unwrap here, since the regex should only match digits
+        let sx: usize = caps["sx"].parse().unwrap();
+        let sy: usize = caps["sy"].parse().unwrap();
+        let ex: usize = caps["ex"].parse().unwrap();
+        let ey: usize = caps["ey"].parse().unwrap();
+
+        if ex < sx || ey < sy {
+            let end = caps.name("ex").map_or("", |m| &line.trim()[m.start()..]);
+            validate::reject(i + 1, validate::column(line, end), end, "the rectangle must end after it starts");
+            continue;
+        }
+
+        directions.push((action, sx, sy, ex, ey));
+    }
+
+    directions
+}
+
+// The number of lights that are on
+pub fn count_lights_on(grid: &[Vec<bool>]) -> usize {
+    grid.iter().flatten().filter(|&&on| on).count()
+}
+
+// The total brightness of all the lights
+pub fn sum_brightness(grid: &[Vec<usize>]) -> usize {
+    grid.iter().flatten().sum()
+}
+
+fn _visualize_grid(grid: &[Vec<bool>]) {
+    for (y, _) in grid.iter().enumerate() {
+        for light in &grid[y] {
+            if *light {
+                print!("O");
+            } else {
+                print!(".");
+            }
+        }
+        println!();
+    }
+}
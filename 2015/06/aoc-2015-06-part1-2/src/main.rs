@@ -0,0 +1,6 @@
+use shared::solution;
+use aoc_2015_06_part1_2::Day06;
+
+fn main() {
+    solution::run(&Day06);
+}
@@ -1,76 +1,124 @@
-use shared::*;
+// Advent of Code 2015 day 7: Some Assembly Required
+
+use shared::{
+    Answer,
+    AocError,
+    error::Span,
+    solution::Solution,
+    validate,
+};
 use std::collections::HashMap;
 use std::collections::VecDeque;
+use std::fmt;
 
 const DEBUG: bool = false;
 
-fn main() {
-    let args = parse_args();
-    
-    let file_contents = load_input_file(&args[1]);
+// Part 2 starts from the signal part 1 finds on a, so it works that out again
+// first
+pub struct Day07;
 
-    build_computer(&file_contents);
-}
+impl Solution for Day07 {
+    type Input = Vec<Gate>;
 
-// Build the circuit using the instructions
-// Create a list of wires and a queue of gates to process.
-// As we parse each instruction we will update values and then loop through all 
-// pending gates for any that may now be solved.
-fn build_computer(instructions: &str) {
-    let mut wires: HashMap<String, Option<u16>> = HashMap::new();
-    let mut pending_gates: VecDeque<Gate> = VecDeque::new();
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse(input))
+    }
 
-    for instruction in instructions.lines() {
-        // Parse all the values in one instruction and generate the gate
-        parse_instruction(&instruction, &mut pending_gates);
-        // loop through pending gates to try to solve
-        process_gate_queue(&mut wires, &mut pending_gates);
+    fn part1(&self, gates: &Self::Input) -> Result<Answer, AocError> {
+        part1(gates).map(Answer::from)
     }
 
-    // Flush the remaining gates after all instructions have been read.
-    while pending_gates.len() > 0 {
-        // loop through pending gates to try to solve
-        process_gate_queue(&mut wires, &mut pending_gates);
+    fn part2(&self, gates: &Self::Input) -> Result<Answer, AocError> {
+        part2(gates, part1(gates)?).map(Answer::from)
     }
 
-    let a = match wires.get("a") {
-        Some(n) => n.unwrap(),
-        None => 0
-    };
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        match part {
+            1 => format!("Wire a is {answer}"),
+            _ => format!("The value of a is {answer}"),
+        }
+    }
+}
 
-    println!("First a is {}", a);
+// Parse every instruction into the gate it describes. Malformed instructions are
+// rejected and left out.
+pub fn parse(instructions: &str) -> Vec<Gate> {
+    let mut gates: Vec<Gate> = Vec::new();
+    for (i, line) in instructions.lines().enumerate() {
+        if line.trim().is_empty() { continue; }
+        match parse_instruction(line) {
+            Ok(gate) => gates.push(gate),
+            Err((text, message, expected)) => validate::reject_error(AocError::Input {
+                span: Box::new(Span::of(i + 1, line, text)),
+                message: message.to_string(),
+                expected: Some(expected.to_string()),
+                hint: None,
+            }),
+        }
+    }
 
+    gates
+}
+
+// Build the circuit from the gates and return the signal on wire a.
+//
+// Returns an AocError if some wires never get a signal
+pub fn part1(gates: &[Gate]) -> Result<u16, AocError> {
+    build_computer(gates, HashMap::new())
+}
+
+// Override wire b with the signal part 1 found on a, reset the other wires and
+// build the circuit again. Returns the new signal on wire a.
+//
+// Returns an AocError if some wires never get a signal
+pub fn part2(gates: &[Gate], a: u16) -> Result<u16, AocError> {
     let mut wires: HashMap<String, Option<u16>> = HashMap::new();
     wires.insert("b".to_string(), Some(a));
 
-    for instruction in instructions.lines() {
-        if instruction.ends_with(" -> b") {
-            println!("We skipped setting b: {}", instruction);
+    build_computer(gates, wires)
+}
+
+// Build the circuit on top of the wires that are already set. The gates for
+// those wires are skipped so the values aren't overwritten.
+// As we add each gate we will update values and then loop through all pending
+// gates for any that may now be solved.
+//
+// Returns an AocError if a pass over the pending gates solves none of them, as
+// their inputs are missing or depend on each other in a loop
+pub fn build_computer(gates: &[Gate], mut wires: HashMap<String, Option<u16>>) -> Result<u16, AocError> {
+    let mut pending_gates: VecDeque<Gate> = VecDeque::new();
+    let preset: Vec<String> = wires.keys().cloned().collect();
+
+    for gate in gates {
+        if preset.contains(&gate.output) {
+            if DEBUG { println!("We skipped setting {}: {gate}", gate.output); }
             continue;
         }
-        // Parse all the values in one instruction and generate the gate
-        parse_instruction(&instruction, &mut pending_gates);
+        pending_gates.push_back(gate.clone());
         // loop through pending gates to try to solve
         process_gate_queue(&mut wires, &mut pending_gates);
     }
 
     // Flush the remaining gates after all instructions have been read.
-    while pending_gates.len() > 0 {
+    while !pending_gates.is_empty() {
+        let pending = pending_gates.len();
         // loop through pending gates to try to solve
         process_gate_queue(&mut wires, &mut pending_gates);
-    }
 
-    let a = match wires.get("a") {
-        Some(n) => n.unwrap(),
-        None => 0
-    };
+        if pending_gates.len() == pending {
+            let mut stuck: Vec<&str> = pending_gates.iter().map(|gate| gate.output.as_str()).collect();
+            stuck.sort();
+            return Err(AocError::Unsolvable {
+                reason: format!("wires {} never get a signal", stuck.join(", ")),
+            });
+        }
+    }
 
-    println!("The value of a is {}", a);
-    
+    Ok(wires.get("a").copied().flatten().unwrap_or(0))
 }
 
 // Process the queue of pending gates for any that can be solved.
-fn process_gate_queue(
+pub fn process_gate_queue(
     wires: &mut HashMap<String, Option<u16>>,
     pending_gates: &mut VecDeque<Gate>
 ) {
@@ -104,7 +152,7 @@ fn process_gate_queue(
 }
 
 // Execute a gate action
-fn execute_gate(
+pub fn execute_gate(
     wires: &mut HashMap<String, Option<u16>>,
     gate: &Gate
 ) -> Option<u16> {
@@ -123,7 +171,9 @@ fn execute_gate(
     if DEBUG { println!("action: {:#?}", gate.action); }
 
     // Generate our output by performing the gate action on our inputs
-    let output = match gate.action {
+    
+
+    match gate.action {
         GateAction::DIRECT => {
             match input1 {
                 Some(v) => {
@@ -189,7 +239,7 @@ fn execute_gate(
                 Some(v1) => {
                     match input2 {
                         Some(v2) => {
-                            Some(v1 << v2)
+                            Some(v1.checked_shl(v2.into()).unwrap_or(0))
                         },
                         None => {
                             if DEBUG { println!("Can't solve because we don't have {:#?}", gate.input2); }
@@ -208,7 +258,7 @@ fn execute_gate(
                 Some(v1) => {
                     match input2 {
                         Some(v2) => {
-                            Some(v1 >> v2)
+                            Some(v1.checked_shr(v2.into()).unwrap_or(0))
                         },
                         None => {
                             if DEBUG { println!("Can't solve because we don't have {:#?}", gate.input2); }
@@ -222,18 +272,13 @@ fn execute_gate(
                 },
             }
         },
-    };
-
-    output
+    }
 }
 
 // Parse one line of instruction
-// Identify the inputs, output, and action. Create wires that don't already exist 
-// and execute the gate action if possible.
-fn parse_instruction(
-    instruction: &str,
-    pending_gates: &mut VecDeque<Gate>
-) {
+// Identify the inputs, output, and action, or return the part of the instruction
+// that is malformed, why, and what was expected there.
+pub fn parse_instruction(instruction: &str) -> Result<Gate, (&str, &'static str, &'static str)> {
 
     // Initialize the gate
     let mut gate = Gate {
@@ -249,8 +294,7 @@ fn parse_instruction(
     // name.
     let parts: Vec<&str> = instruction.split(" -> ").collect();
     if parts.len() != 2 {
-        eprintln!("The instruction seems to be malformed!: {instruction}");
-        return;
+        return Err((instruction, "malformed instruction", "<inputs> -> <wire>"));
     }
     gate.output.push_str(parts[1].trim());
 
@@ -280,33 +324,26 @@ fn parse_instruction(
                 "AND" => GateAction::AND,
                 "LSHIFT" => GateAction::LSHIFT,
                 "RSHIFT" => GateAction::RSHIFT,
-                _ => {
-                    eprintln!("The instruction seems to be malformed! {instruction}");
-                    return;
-                },
+                _ => return Err((parts[1], "unknown gate", "AND, OR, LSHIFT or RSHIFT")),
             };
             gate.input1.push_str(parts[0].trim());
             gate.input2 = Some(parts[2].trim().to_string());
         },
-        _ => {
-            eprintln!("The instruction seems to be malformed!: {instruction}");
-            return;
-        },
+        _ => return Err((instruction, "malformed instruction", "a wire, NOT a wire, or two wires joined by a gate")),
     }
     //if DEBUG { println!("found input wire: {}", gate.input1)}
     //if DEBUG { println!("found input wire 2: {:#?}", gate.input2)}
     //if DEBUG { println!("gate action: {:#?}", gate.action)}
     
-    // Push the gate into the queue to be processes
-    pending_gates.push_back(gate);
+    Ok(gate)
 }
 
 // Retreive a Wire_value from the HashMap, or create it if it doesn't exist yet.
 // Return the reference to the new entry
 // Note we use .entry() here instead of .get() to avoid lifetime issues of creating
 // a temporary value that goes out of scope.
-fn retrieve_or_create_wire<'a>(
-    wires: &'a mut HashMap<String, Option<u16>>,
+pub fn retrieve_or_create_wire(
+    wires: &mut HashMap<String, Option<u16>>,
     name: &str
 ) -> Option<u16> {
     let wire= wires
@@ -319,11 +356,11 @@ fn retrieve_or_create_wire<'a>(
             }
         );
 
-    wire.clone()
+    *wire
 }
 
 #[derive(Debug, Copy, Clone)]
-enum GateAction {
+pub enum GateAction {
     DIRECT,
     OR,
     AND,
@@ -332,10 +369,24 @@ enum GateAction {
     NOT,
 }
 
-#[derive(Debug)]
-struct Gate {
-    action: GateAction,
-    input1: String,
-    input2: Option<String>,
-    output: String,
+// One gate of the circuit, an action with its input and output wires. Inputs
+// may be wire names or signal values.
+#[derive(Debug, Clone)]
+pub struct Gate {
+    pub action: GateAction,
+    pub input1: String,
+    pub input2: Option<String>,
+    pub output: String,
+}
+
+// Write the gate back out as the instruction it came from
+impl fmt::Display for Gate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.action, &self.input2) {
+            (GateAction::DIRECT, _) => write!(f, "{} -> {}", self.input1, self.output),
+            (GateAction::NOT, _) => write!(f, "NOT {} -> {}", self.input1, self.output),
+            (action, Some(input2)) => write!(f, "{} {:?} {} -> {}", self.input1, action, input2, self.output),
+            (action, None) => write!(f, "{} {:?} -> {}", self.input1, action, self.output),
+        }
+    }
 }
@@ -0,0 +1,6 @@
+use shared::solution;
+use aoc_2015_07_part1_2::Day07;
+
+fn main() {
+    solution::run(&Day07);
+}
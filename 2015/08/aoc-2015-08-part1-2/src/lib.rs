@@ -0,0 +1,245 @@
+// Advent of Code 2015 day 8: Matchsticks
+
+use shared::{
+    Answer,
+    AocError,
+    solution::Solution,
+    validate,
+};
+
+const DEBUG: bool = false;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse(input).into_iter().map(String::from).collect())
+    }
+
+    fn part1(&self, strings: &Self::Input) -> Result<Answer, AocError> {
+        let (total_chars, total_mem) = part1(strings);
+        Ok((total_mem - total_chars).into())
+    }
+
+    fn part2(&self, strings: &Self::Input) -> Result<Answer, AocError> {
+        let (total_chars, total_mem) = part2(strings);
+        Ok((total_chars - total_mem).into())
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        match part {
+            1 => format!("The code is {answer} characters longer than the strings in memory"),
+            _ => format!("The encoded strings are {answer} characters longer than the code"),
+        }
+    }
+}
+
+// The string literals in the list, one per line. Lines that aren't a string in
+// double quotes, with only \\, \" and \x escapes, are rejected and left out.
+pub fn parse(input: &str) -> Vec<&str> {
+    let mut strings: Vec<&str> = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let literal = line.trim();
+        if literal.is_empty() { continue; }
+
+        match check_literal(literal) {
+            Ok(()) => strings.push(literal),
+            Err((part, message)) => validate::reject(i + 1, validate::column(line, part), part, message),
+        }
+    }
+
+    strings
+}
+
+// Check that the literal is a string in double quotes with only the escapes
+// the counting understands, or return the part that isn't and why
+fn check_literal(literal: &str) -> Result<(), (&str, &'static str)> {
+    if literal.len() < 2 || !literal.starts_with('"') || !literal.ends_with('"') {
+        return Err((literal, "strings must be wrapped in double quotes"));
+    }
+
+    let inner = &literal[1..literal.len() - 1];
+    let bytes = inner.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => return Err((&inner[i..i + 1], "quotes inside a string must be escaped")),
+            b'\\' => {
+                i += match bytes.get(i + 1) {
+                    Some(b'\\' | b'"') => 2,
+                    Some(b'x') if bytes.len() > i + 3 && bytes[i + 2].is_ascii_hexdigit() && bytes[i + 3].is_ascii_hexdigit() => 4,
+                    _ => {
+                        let end = inner[i + 1..].chars().next().map_or(i + 1, |c| i + 1 + c.len_utf8());
+                        return Err((&inner[i..end], "expected an escape of \\\\, \\\" or \\x and two hex digits"));
+                    },
+                };
+            },
+            _ => i += 1,
+        }
+    }
+
+    Ok(())
+}
+
+// Count the characters of code and the characters in memory for all of the
+// strings, returned as (characters in memory, characters of code)
+pub fn part1(strings: &[impl AsRef<str>]) -> (usize, usize) {
+    let mut total_chars: usize = 0;
+    let mut total_mem: usize = 0;
+
+    let mut escaped: bool = false;
+    let mut hex: u8 = 0;
+
+    for line in strings {
+        let line = line.as_ref();
+        if DEBUG {println!("Examining: {line}"); }
+        for char in line.chars() {
+            if DEBUG {println!("Working char: {char}"); }
+            // Every char takes up memory.
+            total_mem += 1;
+            if DEBUG {println!("Total memory: {total_mem}"); }
+
+            match char {
+                // If we encounter a \ we're either already escaped and it's 1 
+                // char or start escaping.
+                '\\' => {
+                    if DEBUG {println!("Caught a \\"); }
+                    if escaped {
+                        if DEBUG {println!("We're already escaped"); }
+                        total_chars += 1;
+                        if DEBUG {println!("Total chars: {total_chars}"); }
+                        escaped = false;
+                        if DEBUG {println!("No longer escaped."); }
+                    } else {
+                        escaped = true;
+                        if DEBUG {println!("We're not escaped! Now escaping!"); }
+                    }
+                },
+                // If we encounter an x, it's either an x or there was a \ and
+                // it should be hex
+                'x' => {
+                    if escaped {
+                        hex += 1;
+                        if DEBUG {println!("Caught an x while escaped! Hex char {hex}"); }
+                    } else {
+                        total_chars += 1;
+                        if DEBUG {println!("Total chars: {total_chars}"); }
+                    }
+                },
+                // If we encounter a " it only counts as char if we're escaped
+                '"' => {
+                    if escaped {
+                        if DEBUG {println!("Caught an x while escaped! Hex char {hex}"); }
+                        total_chars += 1;
+                        escaped = false;
+                    }
+                }
+                _ => {
+                    // Are we escaped? Must have been examining a hex escape.
+                    // Check if we're done.
+                    if escaped && hex > 0 {
+                        if hex < 3 {
+                            // We haven't counted 3 chars yet. Should still be hex.
+                            if !char.is_ascii_hexdigit() {
+                                eprintln!("We encountered a {char} where there should be valid hex! {line}");
+                            }
+                            hex += 1;
+                            if DEBUG {println!("We're escaped! This should be hex. Hex char {hex}"); }
+                            if hex == 3 {
+                                // We've counted 3 hex (1 char)
+                                escaped = false;
+                                hex = 0;
+                                total_chars += 1;
+                                if DEBUG {println!("We've counted 3 hex chars. Done escaping!"); }
+                                if DEBUG {println!("Total chars: {total_chars}"); }
+                            }
+                        } else {
+                            eprintln!("We're escaping, but hex didn't get reset somewhere!");
+                        }
+                    } else {
+                        total_chars += 1;
+                        if DEBUG {println!("Total chars: {total_chars}"); }
+                    }
+                },
+            }
+        }
+    }
+
+    (total_chars, total_mem)
+}
+
+// Count the characters of the encoded strings and the characters of code for
+// all of the strings, returned as (encoded characters, characters of code)
+pub fn part2(strings: &[impl AsRef<str>]) -> (usize, usize) {
+    let mut total_encoded: usize = 0;
+    let mut total_mem: usize = 0;
+
+    for line in strings {
+        let line = line.as_ref();
+        if DEBUG {println!("Examining: {line}"); }
+        // Add "" around line
+        total_encoded += 2;
+        for char in line.chars() {
+            if DEBUG {println!("Working char: {char}"); }
+            // Every char takes up memory.
+            total_mem += 1;
+            if DEBUG {println!("Total memory: {total_mem}"); }
+
+            match char {
+                '\\' => {
+                    // Add a backslash in-front of \
+                    total_encoded += 2;
+                },
+                '"' => {
+                    // Add a backslash in-front of "
+                    total_encoded += 2;
+                }
+                _ => {
+                    total_encoded += 1;
+                },
+            }
+        }
+    }
+
+    (total_encoded, total_mem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::property::{self, Config};
+
+    // Write the string as a literal, the way part2 counts it
+    fn escape(string: &str) -> String {
+        format!("\"{}\"", string.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
+    #[test]
+    fn escaping_round_trips() {
+        let config = Config { cases: 200, max_size: 30, seed: 1 };
+        property::check("escaping round-trips", config, property::text("ax0f\"\\"), |string| {
+            let literal = escape(string);
+
+            let parsed = parse(&literal);
+            let problems = validate::take();
+            if parsed != [literal.as_str()] || !problems.is_empty() {
+                return Err(format!("{literal} was rejected: {problems:?}"));
+            }
+
+            // Reading the literal back gives the string, and part2 agrees on
+            // how long the literal is
+            let (in_memory, code) = part1(&parsed);
+            if (in_memory, code) != (string.len(), literal.len()) {
+                return Err(format!("{literal} reads as {in_memory} characters in {code} of code"));
+            }
+            let (encoded, _) = part2(&[string]);
+            if encoded != literal.len() {
+                return Err(format!("part2 encodes {string} in {encoded} characters, not {}", literal.len()));
+            }
+
+            Ok(())
+        });
+    }
+}
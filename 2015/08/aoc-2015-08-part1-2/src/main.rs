@@ -0,0 +1,6 @@
+use shared::solution;
+use aoc_2015_08_part1_2::Day08;
+
+fn main() {
+    solution::run(&Day08);
+}
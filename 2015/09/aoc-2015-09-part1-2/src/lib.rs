@@ -0,0 +1,211 @@
+// Advent of Code 2015 day 9: All in a Single Night
+
+use shared::{
+    Answer,
+    AocError,
+    intern::Interner,
+    solution::Solution,
+    validate,
+};
+
+// With debug on the routes are shown as they are tried, and the best one at
+// the end
+#[derive(Default)]
+pub struct Day09 {
+    pub debug: bool,
+}
+
+impl Solution for Day09 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let map = parse(self.debug, input);
+        if self.debug { println!("{:#?}", map); }
+        Ok(map)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Answer, AocError> {
+        let (route, cost) = part1(self.debug, map);
+        if self.debug { println!("Shortest route: {} = {cost}", map.locations.names_of(&route).join(" -> ")); }
+        Ok(cost.into())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Answer, AocError> {
+        let (route, cost) = part2(self.debug, map);
+        if self.debug { println!("Longest route: {} = {cost}", map.locations.names_of(&route).join(" -> ")); }
+        Ok(cost.into())
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        match part {
+            1 => format!("The shortest route is {answer}"),
+            _ => format!("The longest route is {answer}"),
+        }
+    }
+}
+
+// Every location and the cost of travelling between each pair of them, indexed
+// by location id. None if there is no route between the pair.
+#[derive(Debug)]
+pub struct Map {
+    pub locations: Interner,
+    pub costs: Vec<Vec<Option<usize>>>,
+}
+
+// Try every route from every starting location and return the shortest one
+// with its cost
+pub fn part1(debug: bool, map: &Map) -> (Vec<usize>, usize) {
+    find_route(debug, map, usize::MAX, |cost, best_cost| cost < best_cost)
+}
+
+// Try every route from every starting location and return the longest one
+// with its cost
+pub fn part2(debug: bool, map: &Map) -> (Vec<usize>, usize) {
+    find_route(debug, map, 0, |cost, best_cost| cost > best_cost)
+}
+
+// Try every route from every starting location and return the best one with
+// its cost. is_better says whether a cost beats the best cost so far.
+fn find_route(debug: bool, map: &Map, worst_cost: usize, is_better: fn(usize, usize) -> bool) -> (Vec<usize>, usize) {
+
+    let mut best_route: Vec<usize> = Vec::new();
+    let mut best_route_cost: usize = worst_cost;
+    // Start at each possible start location and traverse
+    for start in 0..map.locations.len() {
+        let route: Vec<usize> = vec![start];
+        if debug { println!("Starting at {}", map.locations.name(start)); }
+        if debug { println!("Route: {}", map.locations.names_of(&route).join(" -> ")); }
+        (best_route, best_route_cost) = traverse_route(debug, map, is_better, start, route.clone(), 0, best_route.clone(), best_route_cost);
+    }
+
+    (best_route, best_route_cost)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn traverse_route(
+    debug: bool,
+    map: &Map,
+    is_better: fn(usize, usize) -> bool,
+    location: usize,
+    route: Vec<usize>,
+    cost: usize,
+    mut best_route: Vec<usize>,
+    mut best_route_cost: usize
+ ) -> (Vec<usize>, usize) {
+
+    for (destination, c) in map.costs[location].iter().enumerate() {
+        // Is there a route, and have we already been here?
+        let Some(c) = c else { continue; };
+        if route.contains(&destination) {
+            continue;
+        }
+        let mut new_route = route.clone();
+        new_route.push(destination);
+        let new_cost = cost + c;
+        if debug { println!("Traveling to {} with cost {c}", map.locations.name(destination)); }
+        if debug { println!("Route: {}", map.locations.names_of(&new_route).join(" -> ")); }
+        if debug { println!("Cost: {new_cost}"); }
+        (best_route, best_route_cost) = traverse_route(debug, map, is_better, destination, new_route.clone(), new_cost, best_route.clone(), best_route_cost);
+        if debug { println!("Back to route: {}", map.locations.names_of(&route).join(" -> ")); }
+    }
+
+    // We've been everywhere!
+    if route.len() == map.locations.len() {
+        if debug { println!("We've been everywhere!"); }
+        if debug { println!("Route: {}", map.locations.names_of(&route).join(" -> ")); }
+        if debug { println!("Cost: {cost}"); }
+        if debug { println!("Current Best Route: {}", map.locations.names_of(&best_route).join(" -> ")); }
+        if debug { println!("Current Cost: {best_route_cost}"); }
+        if is_better(cost, best_route_cost) {
+            if debug { println!("THIS IS THE NEW BEST ROUTE!"); }
+            best_route = route.clone();
+            best_route_cost = cost;
+        }
+    }
+
+    (best_route, best_route_cost)
+}
+
+// Parse the list of routes. Each location is given an id, and the cost of
+// travelling between two locations is stored in a matrix indexed by those ids.
+pub fn parse(debug: bool, list: &str) -> Map {
+    let mut locations = Interner::new();
+    let mut routes: Vec<(usize, usize, usize)> = Vec::new();
+
+    for (i, line) in list.lines().enumerate() {
+        if line.trim().is_empty() { continue; }
+
+        let parts: Vec<&str> = line.trim().split(" to ").collect();
+        if parts.len() != 2 {
+            validate::reject(i + 1, 1, line, "expected \"<from> to <to> = <cost>\"");
+            continue;
+        }
+        let from = parts[0];
+
+        let parts: Vec<&str> = parts[1].split(" = ").collect();
+        if parts.len() != 2 {
+            validate::reject(i + 1, validate::column(line, parts[0]), parts[0], "expected \"<to> = <cost>\"");
+            continue;
+        }
+        let to = parts[0];
+        let cost: usize = match parts[1].parse() {
+            Ok(n) => n,
+            Err(_) => {
+                validate::reject(i + 1, validate::column(line, parts[1]), parts[1], "expected a cost");
+                continue;
+            }
+        };
+
+        if debug { println!("Route from {from} to {to} with cost {cost}"); }
+
+        routes.push((locations.intern(from), locations.intern(to), cost));
+    }
+
+    // Routes go both ways
+    let mut all_routes: Vec<Vec<Option<usize>>> = vec![vec![None; locations.len()]; locations.len()];
+    for (from, to, cost) in routes {
+        all_routes[from][to] = Some(cost);
+        all_routes[to][from] = Some(cost);
+    }
+
+    Map { locations, costs: all_routes }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::property::{self, Config};
+
+    #[test]
+    fn routes_visit_every_location_once() {
+        let config = Config { cases: 50, max_size: 6, seed: 1 };
+        let strategy = property::name_graph(1..=150, false, |from, to, cost| format!("{from} to {to} = {cost}"));
+        property::check("routes visit every location once", config, strategy, |input| {
+            let map = parse(false, input);
+            // Shrinking drops lines, and without every route there may be no
+            // way to visit everywhere
+            if map.costs.iter().enumerate().any(|(i, row)| row.iter().enumerate().any(|(j, c)| i != j && c.is_none())) {
+                return Ok(());
+            }
+
+            let shortest = part1(false, &map);
+            let longest = part2(false, &map);
+            for (route, cost) in [&shortest, &longest] {
+                let mut visited = route.clone();
+                visited.sort();
+                if visited != (0..map.locations.len()).collect::<Vec<usize>>() {
+                    return Err(format!("{route:?} doesn't visit every location once"));
+                }
+                let legs: usize = route.windows(2).map(|leg| map.costs[leg[0]][leg[1]].unwrap()).sum();
+                if legs != *cost {
+                    return Err(format!("{route:?} costs {legs}, not {cost}"));
+                }
+            }
+            if shortest.1 > longest.1 {
+                return Err(format!("the shortest route costs {} but the longest {}", shortest.1, longest.1));
+            }
+
+            Ok(())
+        });
+    }
+}
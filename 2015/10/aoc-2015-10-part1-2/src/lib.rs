@@ -0,0 +1,116 @@
+// Advent of Code 2015 day 10: Elves Look, Elves Say
+
+use shared::{
+    Answer,
+    AocError,
+    solution::Solution,
+};
+
+// The puzzle's rounds of look-and-say: 40 for part 1 and 50 for part 2
+pub const ROUNDS: [usize; 2] = [40, 50];
+
+#[derive(Default)]
+pub struct Day10 {
+    pub debug: bool,
+}
+
+impl Solution for Day10 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        if self.debug { println!("{input}"); }
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(&self, digits: &Self::Input) -> Result<Answer, AocError> {
+        Ok(play(self.debug, digits, ROUNDS[0]).into())
+    }
+
+    fn part2(&self, digits: &Self::Input) -> Result<Answer, AocError> {
+        Ok(play(self.debug, digits, ROUNDS[1]).into())
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        format!("After {} iteration(s) the answer is: {answer}", ROUNDS[usize::from(part) - 1])
+    }
+}
+
+// One round of look-and-say, each run of a digit becomes its length then the
+// digit
+pub fn see_and_say(debug:bool, input: &str) -> String {
+    let input = input.trim();
+    // At the start of the string the last char is irrelevant - lets use a null-byte
+    let mut last_char = '\0';
+    let mut char_count: usize = 0; // How many of the same char have we seen
+
+    let mut result: String = String::new();
+
+    for char in input.chars() {
+        if debug { println!("Found a {char}"); }
+        if char == last_char {
+            char_count += 1;
+            if debug { println!("Seen {char_count} {char}"); }
+        } else {
+            // Do not store the null byte we start with
+            if last_char != '\0' {
+                if debug { println!("Recording {char_count} {last_char}"); }
+                result.push_str(&char_count.to_string());
+                result.push(last_char);
+            }
+
+            last_char = char;
+            char_count = 1;
+        }
+    }
+    // record the final char
+    if last_char != '\0' {
+        if debug { println!("Recording {char_count} {last_char}"); }
+        result.push_str(&char_count.to_string());
+        result.push(last_char);
+    }
+
+    if debug { println!("{result}"); }
+    result
+}
+
+// Play look-and-say for the number of rounds, starting from the input, and
+// return the length of the result
+pub fn play(debug: bool, input: &str, iterations: usize) -> usize {
+    let mut result = input.to_string();
+
+    for _ in 0..iterations {
+        result = see_and_say(debug, &result);
+    }
+
+    result.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::property::{self, Config};
+
+    // Conway's constant: once a sequence has split into its elements, it grows
+    // by this much each round
+    const CONWAY: f64 = 1.303_577_269;
+
+    #[test]
+    fn length_grows_by_conways_constant() {
+        let config = Config { cases: 50, max_size: 10, seed: 1 };
+        property::check("look-and-say grows by Conway's constant", config, property::text("123"), |seed| {
+            // 22 is the only sequence that never grows
+            if seed == "22" {
+                return if play(false, seed, 10) == 2 { Ok(()) } else { Err("22 changed".to_string()) };
+            }
+
+            let before = play(false, seed, 20);
+            let after = play(false, seed, 30);
+            let growth = (after as f64 / before as f64).powf(0.1);
+            if (growth / CONWAY - 1.0).abs() > 0.01 {
+                return Err(format!("grew by {growth:.4} a round from {before} to {after}"));
+            }
+
+            Ok(())
+        });
+    }
+}
@@ -1,4 +1,5 @@
 use shared::*;
+use aoc_2015_10_part1_2::{play, Day10};
 
 fn main() {
     let args = parse_args_iterations();
@@ -6,64 +7,31 @@ fn main() {
     if (args.len() == 3 && &args[2] == "--debug") || (args.len() == 4 && (&args[2] == "--debug" || &args[3] == "--debug")) {
         debug = true;
     }
-    
-    let file_contents = load_input_file(&args[1]);
-
-    if debug { println!("{file_contents}"); }
 
-    let iterations:usize = match args.len() {
-        3 if args[2].parse::<usize>().is_ok() => args[2].parse::<usize>().unwrap(),
+    let iterations: Option<usize> = match args.len() {
+        3 if args[2].parse::<usize>().is_ok() => args[2].parse::<usize>().ok(),
         4 if args[2].parse::<usize>().is_ok() || args[3].parse::<usize>().is_ok() => {
-            if args[2].parse::<usize>().is_ok() {
-                args[2].parse::<usize>().unwrap()
-            } else {
-                args[3].parse::<usize>().unwrap()
-            }
+            args[2].parse::<usize>().or_else(|_| args[3].parse::<usize>()).ok()
         },
-        _ => 1,
+        _ => None,
     };
 
-    let mut result = file_contents.clone();
-    
-    for _ in 0..iterations {
-        result = see_and_say(debug, &result);
-    }
-
-    println!("After {iterations} iteration(s) the answer is: {}", result.len());
-}
-
-fn see_and_say(debug:bool, input: &str) -> String {
-    let input = input.trim();
-    // At the start of the string the last char is irrelevant - lets use a null-byte
-    let mut last_char = '\0';
-    let mut char_count: usize = 0; // How many of the same char have we seen
+    // Without an iteration count the parts play their own number of rounds,
+    // and --part picks which
+    let Some(iterations) = iterations else {
+        solution::run_file(&Day10 { debug }, &args[1]);
+        return;
+    };
 
-    let mut result: String = String::new();
+    // A given iteration count is just played out
+    let file_contents = load_input_file(&args[1]);
+    validate::finish();
 
-    for char in input.chars() {
-        if debug { println!("Found a {char}"); }
-        if char == last_char {
-            char_count += 1;
-            if debug { println!("Seen {char_count} {char}"); }
-        } else {
-            // Do not store the null byte we start with
-            if last_char != '\0' {
-                if debug { println!("Recording {char_count} {last_char}"); }
-                result.push_str(&char_count.to_string());
-                result.push(last_char);
-            }
+    if debug { println!("{file_contents}"); }
 
-            last_char = char;
-            char_count = 1;
-        }
-    }
-    // record the final char
-    if last_char != '\0' {
-        if debug { println!("Recording {char_count} {last_char}"); }
-        result.push_str(&char_count.to_string());
-        result.push(last_char);
-    }
+    let mut stats = stats::Stats::new();
+    let length = stats.measure("play", || play(debug, &file_contents, iterations));
+    println!("After {iterations} iteration(s) the answer is: {length}");
 
-    if debug { println!("{result}"); }
-    result
-}
\ No newline at end of file
+    stats.report();
+}
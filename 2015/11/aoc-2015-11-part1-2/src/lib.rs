@@ -0,0 +1,301 @@
+// Advent of Code 2015 day 11: Corporate Policy
+
+use shared::{
+    Answer,
+    AocError,
+    error::Span,
+    solution::Solution,
+    validate,
+};
+
+// Letters that may not appear in a password
+pub const FORBIDDEN_CHARS: [char; 3] = ['i', 'l', 'o'];
+
+// The length of a valid password
+pub const PASSWORD_LENGTH: usize = 8;
+
+// The next two valid passwords are the answers to parts 1 and 2
+#[derive(Default)]
+pub struct Day11 {
+    pub debug: bool,
+}
+
+impl Solution for Day11 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        if self.debug { println!("{input}"); }
+        // A bad password is only left out with --lenient, leaving nothing to
+        // count from
+        parse(input).ok_or_else(|| AocError::Unsolvable { reason: "there is no password to start from".to_string() })
+    }
+
+    fn part1(&self, password: &Self::Input) -> Result<Answer, AocError> {
+        next_valid_password(self.debug, password).map(Answer::Text)
+    }
+
+    fn part2(&self, password: &Self::Input) -> Result<Answer, AocError> {
+        let first = next_valid_password(self.debug, password)?;
+        next_valid_password(self.debug, &count_with_chars(self.debug, &first, &FORBIDDEN_CHARS)?).map(Answer::Text)
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        format!("After {part} iteration(s) the answer is: {answer}")
+    }
+}
+
+// Read the starting password. A password that isn't 8 lowercase letters is
+// rejected, giving None.
+pub fn parse(input: &str) -> Option<String> {
+    let password = input.trim();
+    match check_letters(password) {
+        Ok(()) => Some(password.to_string()),
+        Err(err) => {
+            validate::reject_error(err);
+            None
+        },
+    }
+}
+
+// Count up from the password until it is valid. The password itself is
+// returned if it is already valid.
+//
+// Returns an AocError if the password isn't 8 lowercase letters, as counting
+// up from anything else never reaches a valid password, or if counting up runs
+// out of 8 letter passwords first
+pub fn next_valid_password(debug: bool, password: &str) -> Result<String, AocError> {
+    let password = password.trim();
+    check_letters(password)?;
+
+    let mut result = skip_forbidden(password);
+
+    while !validate_password(debug, &result, &FORBIDDEN_CHARS) {
+        result = skip_forbidden(&count_with_chars(debug, &result, &FORBIDDEN_CHARS)?);
+        if result.len() > PASSWORD_LENGTH {
+            return Err(AocError::Unsolvable {
+                reason: format!("there is no valid password after {password}"),
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+// Counting up only works on 8 lowercase letters
+fn check_letters(password: &str) -> Result<(), AocError> {
+    if let Some(index) = password.chars().position(|c| !c.is_ascii_lowercase()) {
+        return Err(invalid_char(password, index));
+    }
+    if password.len() != PASSWORD_LENGTH {
+        return Err(AocError::Input {
+            span: Box::new(Span::of(1, password, password)),
+            message: format!("the password is {} letters long", password.len()),
+            expected: Some(format!("{PASSWORD_LENGTH} letters")),
+            hint: None,
+        });
+    }
+
+    Ok(())
+}
+
+// Every password that counting up reaches while a forbidden letter is still in
+// it is invalid, so skip straight to the first one without it: the forbidden
+// letter becomes the next letter, and everything after it becomes a
+fn skip_forbidden(password: &str) -> String {
+    match password.find(FORBIDDEN_CHARS) {
+        Some(at) => {
+            let next = (password.as_bytes()[at] + 1) as char;
+            format!("{}{next}{}", &password[..at], "a".repeat(password.len() - at - 1))
+        },
+        None => password.to_string(),
+    }
+}
+
+// The error for the character at index in the password not being a letter
+fn invalid_char(password: &str, index: usize) -> AocError {
+    let (start, c) = password.char_indices().nth(index).unwrap_or((0, ' '));
+    let part = &password[start..start + c.len_utf8()];
+
+    AocError::Input {
+        span: Box::new(Span::of(1, password, part)),
+        message: "passwords can only contain letters".to_string(),
+        expected: Some("a lowercase letter".to_string()),
+        hint: None,
+    }
+}
+
+// A valid password is 8 letters, with a run of three increasing letters, two
+// different pairs and none of the forbidden letters
+pub fn validate_password(debug: bool, input: &str, forbidden_chars: &[char]) -> bool {
+
+    let char_list: Vec<char> = vec!['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z'];
+
+    let mut char_indicies: Vec<usize> = Vec::new();
+
+    for ic in input.chars() {
+        if forbidden_chars.contains(&ic) {
+            return false;
+        }
+        let ci: usize = match char_list.iter().position(|&c| c == ic) {
+            Some(n) => n,
+            // Only letters can be valid, parse rejects anything else
+            None => return false,
+        };
+        char_indicies.push(ci);
+    }
+    char_indicies.reverse();
+
+    let mut double_char_locations: Vec<usize> = Vec::new();
+    let mut char_run: bool = false;
+
+    for (i, _p) in char_indicies.iter().enumerate() {
+        if 
+        i >= 1 // is it possible to have seen double chars yet?
+        && (char_indicies[i-1] == char_indicies[i]) // Are the chars the same?
+        && double_char_locations.len() < 4 // Are we still looking for double chars?
+        && (!double_char_locations.contains(&(i-1)) && !double_char_locations.contains(&i)) // Have we not seen these before?
+        {
+            // These are double chars. Save them.
+            if debug { println!("Char {} and {} are the same. Saving", i-1, i); }
+            double_char_locations.push(i-1);
+            double_char_locations.push(i);
+        }
+
+        // Are the last 3 chars incremented by 1?
+        if 
+        i >= 2 // is it possible to have seen three chars yet?
+        && !char_run // Are we still looking for a run?
+            && let (Some(second_last), Some(last)) = (
+                char_indicies[i-2].checked_sub(2),
+                char_indicies[i-1].checked_sub(1)
+            )
+                && second_last == last && last == char_indicies[i] {
+                    // This is a run.
+                    char_run = true;
+                }
+    }
+
+    double_char_locations.len() == 4 && char_run && input.len() == 8
+}
+
+// Increment the password like a base 26 number of letters, skipping over the
+// forbidden letters
+//
+// Returns an AocError if the password has anything other than lowercase
+// letters in it
+pub fn count_with_chars(debug: bool, password: &str, forbidden_chars: &[char]) -> Result<String, AocError> {
+    let char_list: Vec<char> = vec!['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z'];
+
+    let mut input: Vec<char> = password.chars().collect();
+
+    // If the input is empty, push the first char and return
+    if input.is_empty() {
+        if debug { println!("Empty input. Starting with {}", &char_list[0]); }
+        input.push(char_list[0]);
+        let result: String = input.iter().collect();
+        return Ok(result);
+    }
+    if debug { println!("Input: {}", input.iter().collect::<String>()); }
+
+    // We're adding 1, so there is a "carry" by default
+    let mut carry: bool = true;
+    // Start with the last char
+    let mut i: usize = input.len() - 1;
+    let mut count = 0;
+    // As long as there is still a carry and we're not at the end of the string,
+    // continue incrementing chars at the next index. We need to count the chars
+    // processed here, since usize >= 0 is always true, so we can't use the index
+    // countdown to know when we're at the end of the list.
+    while carry && count < input.len() {
+        let mut next_index = match char_list.iter().position(|&c| c == input[i]) {
+            Some(n) => {
+                if n + 1 >= char_list.len() {
+                    n + 1 - char_list.len()
+                } else {
+                    carry = false;
+                    n + 1
+                }
+            },
+            None => return Err(invalid_char(password, i)),
+        };
+        if debug { println!("At index {}, {} becomes {}", i, input[i], char_list[next_index]); }
+        if forbidden_chars.contains(&char_list[next_index]) {
+            if debug { println!("This char is listed as forbidden! Picking the next one."); }
+            next_index = match char_list.iter().position(|&c| c == input[i]) {
+                Some(n) => {
+                    if n + 1 >= char_list.len() {
+                        n + 1 - char_list.len()
+                    } else {
+                        carry = false;
+                        n + 1
+                    }
+                },
+                None => return Err(invalid_char(password, i)),
+            };
+        }
+        input[i] = char_list[next_index];
+
+        // Don't decrement i below 0 since it's usize
+        i = i.saturating_sub(1);
+        count += 1;
+    }
+    // If carry is still set then we need to add a "digit" to the front.
+    if carry {
+        input = {
+            let mut t = vec!['a'];
+            t.append(&mut input);
+            t
+        };
+        if debug { println!("Remaining carry after all characters processed."); }
+    }
+
+    let result: String = input.iter().collect();
+    if debug { println!("Result: {}", result); }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::property::{self, Config};
+
+    // Passwords are counted like numbers: longer ones come later, then
+    // alphabetical order
+    fn order(password: &str) -> (usize, &str) {
+        (password.len(), password)
+    }
+
+    #[test]
+    fn counting_is_strictly_increasing() {
+        let config = Config { cases: 200, max_size: 8, seed: 1 };
+        property::check("counting up is strictly increasing", config, property::text("abhijklnopyz"), |password| {
+            let mut current = password.to_string();
+            // A few steps, so carries out of the last letter are covered
+            for _ in 0..30 {
+                let next = count_with_chars(false, &current, &FORBIDDEN_CHARS).map_err(|err| err.to_string())?;
+                if order(&next) <= order(&current) {
+                    return Err(format!("{current} counted up to {next}"));
+                }
+                current = next;
+            }
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn parse_rejects_passwords_that_cant_be_counted() {
+        assert_eq!(parse("abcdefgh\n").as_deref(), Some("abcdefgh"));
+        assert!(validate::take().is_empty());
+
+        assert_eq!(parse("abcDefgh\n"), None);
+        assert_eq!(parse("abc\n"), None);
+        let spans: Vec<(usize, usize)> = validate::take().iter()
+            .map(|problem| match problem {
+                AocError::Input { span, .. } => (span.column, span.len),
+                _ => panic!("{problem:?}"),
+            })
+            .collect();
+        assert_eq!(spans, [(4, 1), (1, 3)]);
+    }
+}
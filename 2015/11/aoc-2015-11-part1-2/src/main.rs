@@ -1,5 +1,5 @@
 use shared::*;
-use std::process;
+use aoc_2015_11_part1_2::{parse, next_valid_password, count_with_chars, Day11, FORBIDDEN_CHARS};
 
 fn main() {
     let args = parse_args_iterations();
@@ -7,169 +7,56 @@ fn main() {
     if (args.len() == 3 && &args[2] == "--debug") || (args.len() == 4 && (&args[2] == "--debug" || &args[3] == "--debug")) {
         debug = true;
     }
-    
-    let file_contents = load_input_file(&args[1]);
-
-    if debug { println!("{file_contents}"); }
 
-    let iterations:usize = match args.len() {
-        3 if args[2].parse::<usize>().is_ok() => args[2].parse::<usize>().unwrap(),
+    let iterations: Option<usize> = match args.len() {
+        3 if args[2].parse::<usize>().is_ok() => args[2].parse::<usize>().ok(),
         4 if args[2].parse::<usize>().is_ok() || args[3].parse::<usize>().is_ok() => {
-            if args[2].parse::<usize>().is_ok() {
-                args[2].parse::<usize>().unwrap()
-            } else {
-                args[3].parse::<usize>().unwrap()
-            }
+            args[2].parse::<usize>().or_else(|_| args[3].parse::<usize>()).ok()
         },
-        _ => 1,
+        _ => None,
     };
 
-    let forbidden_chars: Vec<char> = vec!['i', 'l', 'o'];
-    let mut result = file_contents.clone();
-    //let mut result = String::new();
-    
-    for n in 1..=iterations {
-        while !validate_password(debug, &result, &forbidden_chars) {
-            result = count_with_chars(debug, &result, &forbidden_chars);
-        }
-        println!("After {n} iteration(s) the answer is: {}", result);
-        // increment by 1 before next itter
-        result = count_with_chars(debug, &result, &forbidden_chars);
-    }
-}
-
-fn validate_password(debug: bool, input: &str, forbidden_chars: &Vec<char>) -> bool {
-
-    let char_list: Vec<char> = vec!['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z'];
-
-    let mut char_indicies: Vec<usize> = Vec::new();
-
-    for ic in input.chars() {
-        if forbidden_chars.contains(&ic) {
-            return false;
-        }
-        let ci: usize = match char_list.iter().position(|&c| c == ic) {
-            Some(n) => n,
-            None => {
-                eprintln!("An invalid char was encountered!");
-                return false;
-            }
-        };
-        char_indicies.push(ci);
-    }
-    char_indicies.reverse();
-
-    let mut double_char_locations: Vec<usize> = Vec::new();
-    let mut char_run: bool = false;
-
-    for (i, _p) in char_indicies.iter().enumerate() {
-        if 
-        i >= 1 // is it possible to have seen double chars yet?
-        && (char_indicies[i-1] == char_indicies[i]) // Are the chars the same?
-        && double_char_locations.len() < 4 // Are we still looking for double chars?
-        && (!double_char_locations.contains(&(i-1)) && !double_char_locations.contains(&i)) // Have we not seen these before?
-        {
-            // These are double chars. Save them.
-            if debug { println!("Char {} and {} are the same. Saving", i-1, i); }
-            double_char_locations.push(i-1);
-            double_char_locations.push(i);
-        }
-
-        // Are the last 3 chars incremented by 1?
-        if 
-        i >= 2 // is it possible to have seen three chars yet?
-        && !char_run // Are we still looking for a run?
-        {
-            if let (Some(second_last), Some(last)) = (
-                char_indicies[i-2].checked_sub(2),
-                char_indicies[i-1].checked_sub(1)
-            ) {
-                if second_last == last && last == char_indicies[i] {
-                    // This is a run.
-                    char_run = true;
-                }
-            }
-        }
-    }
-
-    double_char_locations.len() == 4 && char_run && input.len() == 8
-}
+    // Without an iteration count only the two passwords the parts ask for are
+    // found
+    let Some(iterations) = iterations else {
+        solution::run_file(&Day11 { debug }, &args[1]);
+        return;
+    };
 
-fn count_with_chars(debug: bool, input: &str, forbidden_chars: &Vec<char>) -> String {
-    let char_list: Vec<char> = vec!['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z'];
+    let file_contents = load_input_file(&args[1]);
+    let mut stats = stats::Stats::new();
+    let password = stats.measure("parse", || parse(&file_contents));
+    validate::finish();
 
-    let mut input: Vec<char> = input.chars().collect();
+    if debug { println!("{file_contents}"); }
 
-    // If the input is empty, push the first char and return
-    if input.len() == 0 {
-        if debug { println!("Empty input. Starting with {}", &char_list[0]); }
-        input.push(char_list[0]);
-        let result: String = input.iter().collect();
-        return result;
-    }
-    if debug { println!("Input: {}", input.iter().collect::<String>()); }
+    // A bad password is only left out with --lenient, leaving nothing to count from
+    let mut result = match password {
+        Some(password) => password,
+        None => AocError::Unsolvable { reason: "there is no password to start from".to_string() }.exit(),
+    };
 
-    // We're adding 1, so there is a "carry" by default
-    let mut carry: bool = true;
-    // Start with the last char
-    let mut i: usize = input.len() - 1;
-    let mut count = 0;
-    // As long as there is still a carry and we're not at the end of the string,
-    // continue incrementing chars at the next index. We need to count the chars
-    // processed here, since usize >= 0 is always true, so we can't use the index
-    // countdown to know when we're at the end of the list.
-    while carry && count < input.len() {
-        let mut next_index = match char_list.iter().position(|&c| c == input[i]) {
-            Some(n) => {
-                if n + 1 >= char_list.len() {
-                    n + 1 - char_list.len()
-                } else {
-                    carry = false;
-                    n + 1
-                }
-            },
-            None => {
-                eprintln!("An invalid char was encountered!");
-                process::exit(1);
-            }
+    for n in 1..=iterations {
+        // The first two passwords are parts 1 and 2, any after that are extra
+        // rounds
+        let phase = if n <= 2 { format!("part{n}") } else { format!("round{n}") };
+        result = match stats.measure(&phase, || next_valid_password(debug, &result)) {
+            Ok(password) => password,
+            Err(err) => err.exit(),
         };
-        if debug { println!("At index {}, {} becomes {}", i, input[i], char_list[next_index]); }
-        if forbidden_chars.contains(&char_list[next_index]) {
-            if debug { println!("This char is listed as forbidden! Picking the next one."); }
-            next_index = match char_list.iter().position(|&c| c == input[i]) {
-                Some(n) => {
-                    if n + 1 >= char_list.len() {
-                        n + 1 - char_list.len()
-                    } else {
-                        carry = false;
-                        n + 1
-                    }
-                },
-                None => {
-                    eprintln!("An invalid char was encountered!");
-                    process::exit(1);
-                }
-            };
+        // The first two passwords are the answers to parts 1 and 2
+        if n > 2 || solution::wants(n as u8) {
+            println!("After {n} iteration(s) the answer is: {}", result);
         }
-        input[i] = char_list[next_index];
-
-        // Don't decrement i below 0 since it's usize
-        if i > 0 {
-            i -= 1;
+        if n <= 2 {
+            solution::answer(n as u8, &result);
         }
-        count += 1;
-    }
-    // If carry is still set then we need to add a "digit" to the front.
-    if carry {
-        input = {
-            let mut t = vec!['a'];
-            t.append(&mut input);
-            t
+        // increment by 1 before next itter
+        result = match count_with_chars(debug, &result, &FORBIDDEN_CHARS) {
+            Ok(password) => password,
+            Err(err) => err.exit(),
         };
-        if debug { println!("Remaining carry after all characters processed."); }
     }
 
-    let result: String = input.iter().collect();
-    if debug { println!("Result: {}", result); }
-    result
-}
\ No newline at end of file
+    stats.report();
+}
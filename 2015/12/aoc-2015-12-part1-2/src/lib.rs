@@ -0,0 +1,134 @@
+// Advent of Code 2015 day 12: JSAbacusFramework.io
+
+use serde_json::Value;
+use shared::{
+    Answer,
+    AocError,
+    arith,
+    error::Span,
+    solution::Solution,
+};
+
+#[derive(Default)]
+pub struct Day12 {
+    pub debug: bool,
+}
+
+impl Solution for Day12 {
+    type Input = Value;
+
+    // There's no skipping part of a document, so invalid JSON stops even when
+    // lenient
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        if self.debug { println!("{input}"); }
+        let document = parse(input).map_err(|err| AocError::Input {
+            span: Box::new(Span::at(err.line(), err.column(), 1)),
+            message: format!("the JSON is invalid: {err}"),
+            expected: None,
+            hint: None,
+        })?;
+        if self.debug { println!("{:#?}", document); }
+        Ok(document)
+    }
+
+    fn part1(&self, document: &Self::Input) -> Result<Answer, AocError> {
+        part1(self.debug, document).map(Answer::from)
+    }
+
+    fn part2(&self, document: &Self::Input) -> Result<Answer, AocError> {
+        part2(self.debug, document).map(Answer::from)
+    }
+
+    fn describe(&self, _part: u8, answer: &Answer) -> String {
+        format!("The sum of all the numbers is: {answer}")
+    }
+}
+
+// Parse the JSON document
+pub fn parse(input: &str) -> Result<Value, serde_json::Error> {
+    serde_json::from_str(input)
+}
+
+// The sum of all of the numbers in the document
+//
+// Returns an AocError if a number isn't a whole number that fits in an i64
+pub fn part1(debug: bool, document: &Value) -> Result<i64, AocError> {
+    Ok(sum_or_step(&debug, false, document, 0)?.unwrap_or(0))
+}
+
+// The sum of the numbers in the document, ignoring any object with a
+// property that is "red"
+//
+// Returns an AocError if a number isn't a whole number that fits in an i64
+pub fn part2(debug: bool, document: &Value) -> Result<i64, AocError> {
+    Ok(sum_or_step(&debug, true, document, 0)?.unwrap_or(0))
+}
+
+// Iterate over JSON object or array for a sum
+// If an object, start a temporary sum, and return the original sum if ignore_red
+// is set and the object contains property with a value "red". Any other value
+// has nothing to add to the sum.
+fn iterate_json_for_sum(debug: &bool, ignore_red: bool, input: &Value, sum: i64) -> Result<i64, AocError> {
+    let mut sum = sum;
+
+    if let Some(a) = input.as_array() {
+        for v in a {
+            sum = match sum_or_step(debug, ignore_red, v, sum)? {
+                Some(n) => n,
+                None => sum,
+            };
+        }
+    } else if let Some(o) = input.as_object() {
+        if *debug { println!("Entering an object.  Starting a temp_sum"); }
+        let mut temp_sum: i64 = 0;
+        for (_, v) in o {
+            temp_sum = match sum_or_step(debug, ignore_red, v, temp_sum)? {
+                Some(n) => n,
+                None => {
+                    if *debug { println!("\"red\" located! The sum remains unchainged. {sum}"); }
+                    return Ok(sum);
+                }
+            };
+        }
+        if *debug { println!("Made it through the object without finding red.  Adding {} to {}", temp_sum, sum); }
+        sum = arith::add(sum, temp_sum)?;
+    }
+
+    Ok(sum)
+}
+
+// Check the vlaue we have - If it's an object or array, step into it
+// If it's a number, convert it to an i64 and add it to the sum.
+// If it's red and we're ignoring red, return None to trigger a bail-out
+//
+// Returns an AocError if the number cannot be converted to i64
+fn sum_or_step(debug: &bool, ignore_red: bool, value: &Value, sum: i64) -> Result<Option<i64>, AocError> {
+    let mut sum = sum;
+
+    
+    if ignore_red && value.as_str() == Some("red") {
+        if *debug { println!("\"red\" located! Bail out!"); }
+        return Ok(None);
+
+    } else if value.is_number() {
+        if *debug { println!("{} is a number!", value); }
+
+        let num = match value.as_i64() {
+            Some(n) => n,
+            None => {
+                return Err(AocError::Unsolvable {
+                    reason: format!("{value} is not a whole number that fits in an i64"),
+                });
+            }
+        };
+        
+        sum = arith::add(sum, num)?;
+        
+        if *debug { println!("New sum is: {}", sum); }
+    } else if value.is_array() || value.is_object() {
+        if *debug { println!("The value is an object or array! Stepping in."); }
+        sum = iterate_json_for_sum(debug, ignore_red, value, sum)?;
+    }
+
+    Ok(Some(sum))
+}
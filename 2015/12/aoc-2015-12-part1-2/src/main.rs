@@ -0,0 +1,9 @@
+use shared::*;
+use aoc_2015_12_part1_2::Day12;
+
+fn main() {
+    let args = parse_args();
+    let debug = args.len() == 3 && &args[2] == "--debug";
+
+    solution::run_file(&Day12 { debug }, &args[1]);
+}
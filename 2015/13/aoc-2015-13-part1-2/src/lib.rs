@@ -0,0 +1,215 @@
+// Advent of Code 2015 day 13: Knights of the Dinner Table
+
+use shared::{
+    Answer,
+    AocError,
+    intern::Interner,
+    solution::Solution,
+    validate,
+};
+use regex::Regex;
+
+// With debug on the seating arrangement behind each answer is shown too
+#[derive(Default)]
+pub struct Day13 {
+    pub debug: bool,
+}
+
+impl Solution for Day13 {
+    type Input = Guests;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        if self.debug { println!("{input}"); }
+        Ok(parse(&self.debug, input))
+    }
+
+    fn part1(&self, guests: &Self::Input) -> Result<Answer, AocError> {
+        let (best_happiness, best_arrangement) = part1(self.debug, guests);
+        if self.debug { println!("{}", best_arrangement.join(", ")); }
+        Ok(best_happiness.into())
+    }
+
+    fn part2(&self, guests: &Self::Input) -> Result<Answer, AocError> {
+        let (best_happiness, best_arrangement) = part2(self.debug, guests);
+        if self.debug { println!("{}", best_arrangement.join(", ")); }
+        Ok(best_happiness.into())
+    }
+
+    fn describe(&self, _part: u8, answer: &Answer) -> String {
+        format!("The best change in happiness is: {answer}")
+    }
+}
+
+// Every guest, and the change in happiness for each guest sitting next to each
+// other guest, indexed by guest id
+#[derive(Debug)]
+pub struct Guests {
+    pub names: Interner,
+    pub happiness: Vec<Vec<isize>>,
+}
+
+impl Guests {
+    // The same guests with me seated as well. Nobody cares where I sit, so every
+    // entry is 0.
+    pub fn with_me(&self) -> Guests {
+        let mut names = self.names.clone();
+        names.intern("Me");
+
+        let mut happiness: Vec<Vec<isize>> = vec![vec![0; names.len()]; names.len()];
+        for (row, guest_row) in happiness.iter_mut().zip(&self.happiness) {
+            row[..guest_row.len()].copy_from_slice(guest_row);
+        }
+
+        Guests { names, happiness }
+    }
+}
+
+// The best change in happiness, and the names in the seating arrangement that
+// gives it
+pub fn part1(debug: bool, guests: &Guests) -> (isize, Vec<String>) {
+    best_seating(&debug, guests)
+}
+
+// The best change in happiness, with me seated as well, and the names in the
+// seating arrangement that gives it
+pub fn part2(debug: bool, guests: &Guests) -> (isize, Vec<String>) {
+    best_seating(&debug, &guests.with_me())
+}
+
+fn best_seating(debug: &bool, guests: &Guests) -> (isize, Vec<String>) {
+    let (best_happiness, best_arrangement) = calculate_seating(debug, guests, isize::MIN, vec![], vec![]);
+    let names = guests.names.names_of(&best_arrangement).iter().map(|name| name.to_string()).collect();
+
+    (best_happiness, names)
+}
+
+// Iterate over each possible seating combination to find the MAX change in happieness
+fn calculate_seating(debug: &bool, guests: &Guests, best_happiness: isize, best_arrangement: Vec<usize>, current_arrangement: Vec<usize>) -> (isize, Vec<usize>) {
+    let mut best_happiness = best_happiness;
+    let mut best_arrangement = best_arrangement;
+
+    for (k, name) in guests.names.iter() {
+        if current_arrangement.contains(&k) {
+            if *debug { println!("{} has already been seated", name); }
+            continue;
+        }
+        let mut new_arrangement = current_arrangement.clone();
+        new_arrangement.push(k);
+        if *debug { println!("Adding {}\n{}", name, guests.names.names_of(&new_arrangement).join(", ")); }
+        (best_happiness, best_arrangement) = calculate_seating(debug, guests, best_happiness, best_arrangement, new_arrangement.clone());
+    }
+
+    if current_arrangement.len() == guests.names.len() {
+        if *debug { println!("Everyone has been seated"); }
+        let happiness = calculate_happiness(debug, guests, &current_arrangement);
+        if happiness > best_happiness {
+            best_happiness = happiness;
+            best_arrangement = current_arrangement.clone();
+        }
+    }
+
+    (best_happiness, best_arrangement)
+}
+
+// Calculate the total_happiness change using a current arrangement and the happiness data
+pub fn calculate_happiness(debug: &bool, guests: &Guests, current_arrangement: &[usize]) -> isize {
+
+    if *debug { println!("Calculating happiness for: {}", guests.names.names_of(current_arrangement).join(", ")); }
+    let mut total_happiness: isize = 0;
+    for (i, &current) in current_arrangement.iter().enumerate() {
+        // The previous person for the first person is the last person
+        let prev_i = if i == 0 {
+            current_arrangement.len()-1
+        } else {
+            i - 1
+        };
+        let previous = current_arrangement[prev_i];
+
+        // Add previous person to current person
+        total_happiness += guests.happiness[previous][current];
+        if *debug { println!("{} {} {}", guests.names.name(previous), guests.happiness[previous][current], guests.names.name(current)); }
+
+        // Add current person to previous person
+        total_happiness += guests.happiness[current][previous];
+        if *debug { println!("{} {} {}", guests.names.name(current), guests.happiness[current][previous], guests.names.name(previous)); }
+    }
+
+    if *debug { println!("Total happineness change: {}", total_happiness); }
+    
+    total_happiness
+}
+
+// Parse the input using regex
+// Should be in the format <person1> would <sign> <amount> happiness units by sitting next to <person2>.
+// Pairs that aren't mentioned have no change in happiness.
+//
+// Panics!
+// This function will panic if the regex fails to build
+pub fn parse(debug: &bool, input: &str) -> Guests {
+
+    let mut names = Interner::new();
+    let mut pairs: Vec<(usize, usize, isize)> = Vec::new();
+
+    // Build the regex
+    //    <person1> would <sign> <amount> happiness units by sitting next to <person2>.
+    let re = Regex::new(
+        r"(?P<person1>[A-Za-z]+) would (?P<sign>(lose|gain)) (?P<happiness>[0-9]+) happiness units by sitting next to (?P<person2>[A-Za-z]+)\."
+    ).unwrap();
+
+    // Loop over each line and create or update the data for each person
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() { if *debug { println!("Empty line"); } continue; }
+
+        let caps = match re.captures(line) {
+            Some(c) => c,
+            None => {
+                validate::reject(i + 1, 1, line, "expected \"<name> would gain|lose <n> happiness units by sitting next to <name>.\"");
+                continue;
+            }
+        };
+        if *debug { println!("Found: {}, {}, {}, {}",
+            &caps["person1"],
+            &caps["sign"],
+            &caps["happiness"],
+            &caps["person2"]); }
+
+        let happiness = match caps["sign"].to_string().as_str() {
+            "gain" => {
+                let num: isize = match caps["happiness"].parse() {
+                    Ok(n) => n,
+                    Err(_) => {
+                        validate::reject(i + 1, validate::column(line, &caps["happiness"]), &caps["happiness"], "happiness is too large");
+                        continue;
+                    }
+                };
+                num
+            },
+            "lose" => {
+                let num: isize = match caps["happiness"].parse() {
+                    Ok(n) => n,
+                    Err(_) => {
+                        validate::reject(i + 1, validate::column(line, &caps["happiness"]), &caps["happiness"], "happiness is too large");
+                        continue;
+                    }
+                };
+                -num
+            },
+            _ => {
+                validate::reject(i + 1, validate::column(line, &caps["sign"]), &caps["sign"], "expected gain or lose");
+                continue;
+            }
+        };
+        // Record the happiness entry for person1 next to person2
+        pairs.push((names.intern(&caps["person1"]), names.intern(&caps["person2"]), happiness));
+
+    }
+
+    let mut happiness: Vec<Vec<isize>> = vec![vec![0; names.len()]; names.len()];
+    for (person1, person2, h) in pairs {
+        happiness[person1][person2] = h;
+    }
+
+    let guests = Guests { names, happiness };
+    if *debug { println!("{:#?}", guests); }
+    guests
+}
@@ -0,0 +1,197 @@
+// Advent of Code 2015 day 14: Reindeer Olympics
+
+use shared::{
+    Answer,
+    AocError,
+    intern::Interner,
+    solution::Solution,
+    validate,
+};
+use regex::Regex;
+
+// The puzzle's race lasts 2503 seconds
+pub const RACE_SECONDS: usize = 2503;
+
+pub struct Day14 {
+    pub debug: bool,
+    // How long the race lasts
+    pub seconds: usize,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Day14 { debug: false, seconds: RACE_SECONDS }
+    }
+}
+
+impl Solution for Day14 {
+    type Input = (Interner, Vec<Reindeer>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        if self.debug { println!("{input}"); }
+        Ok(parse(input))
+    }
+
+    fn part1(&self, (names, reindeer_data): &Self::Input) -> Result<Answer, AocError> {
+        let (winner_name, winner) = part1(self.debug, names, reindeer_data, self.seconds);
+        if self.debug { println!("{winner_name} won, travelling {winner}km in {} seconds.", self.seconds); }
+        Ok(winner.into())
+    }
+
+    fn part2(&self, (names, reindeer_data): &Self::Input) -> Result<Answer, AocError> {
+        let (winner_name, winner) = part2(self.debug, names, reindeer_data, self.seconds);
+        if self.debug { println!("{winner_name} won, earning {winner} points in {} seconds.", self.seconds); }
+        Ok(winner.into())
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        match part {
+            1 => format!("The winner travelled {answer}km in {} seconds.", self.seconds),
+            _ => format!("The winner earned {answer} points in {} seconds.", self.seconds),
+        }
+    }
+}
+
+// ((speed, flying duration), resting duration)
+pub type Reindeer = ((usize, usize), usize);
+
+// Race the reindeer for the number of seconds and return the name of the
+// winner with the distance it travelled
+pub fn part1(debug: bool, names: &Interner, reindeer_data: &[Reindeer], seconds: usize) -> (String, usize) {
+    let results = run(&debug, names, reindeer_data, seconds);
+    if debug { println!("{:#?}", results); }
+
+    let distances: Vec<usize> = results.iter().map(|&(distance, _)| distance).collect();
+    winner(names, &distances)
+}
+
+// Race the reindeer for the number of seconds and return the name of the
+// winner with the points it earned
+pub fn part2(debug: bool, names: &Interner, reindeer_data: &[Reindeer], seconds: usize) -> (String, usize) {
+    let results = run(&debug, names, reindeer_data, seconds);
+    if debug { println!("{:#?}", results); }
+
+    let points: Vec<usize> = results.iter().map(|&(_, points)| points).collect();
+    winner(names, &points)
+}
+
+// The name of the reindeer with the highest score, and that score
+fn winner(names: &Interner, scores: &[usize]) -> (String, usize) {
+    let mut winner: usize = 0;
+    let mut winner_name: String = String::new();
+    for (id, score) in scores.iter().enumerate() {
+        if *score > winner {
+            winner = *score;
+            winner_name = names.name(id).to_string();
+        }
+    }
+
+    (winner_name, winner)
+}
+
+// Run the reindeer for a provided number of seconds. Returns the distance and
+// points of each reindeer, indexed by reindeer id.
+fn run(debug: &bool, names: &Interner, reindeer_data: &[Reindeer], seconds: usize) -> Vec<(usize, usize)> {
+    // Keep track of the position and points of each reindeer
+    let mut state: Vec<(usize, usize)> = vec![(0, 0); reindeer_data.len()];
+    // Keep track of the countdown of each reindeer
+    let mut countdown: Vec<(usize, usize)> = reindeer_data.iter()
+        .map(|&((_, s_duration), r_duration)| (s_duration, r_duration))
+        .collect();
+
+    for i in 1..=seconds {
+        if *debug { println!("Second {i}"); }
+
+        for (id, ((speed, s_duration), r_duration)) in reindeer_data.iter().enumerate() {
+            
+            let name = names.name(id);
+            let (rdistance, _) = &mut state[id];
+            let (rsduration, rrduration) = &mut countdown[id];
+
+            if *rsduration > 0 {
+                if *debug { println!("{name} moving for {rsduration} more seconds"); }
+                *rdistance += *speed;
+                *rsduration -= 1;
+                if *rsduration == 0 {
+                    if *debug { println!("{name} done moving"); }
+                    *rrduration = *r_duration;
+                }
+            } else {
+                if *rrduration > 0 {
+                    if *debug { println!("{name} resting for {rrduration} more seconds"); }
+                    *rrduration -= 1;
+                    if *rrduration == 0 {
+                        if *debug { println!("{name} done resting"); }
+                        *rsduration = *s_duration;
+                    }
+                }
+            }
+        }
+
+        // Award points to every reindeer that has travelled the furthest so far
+        let winner = state.iter().map(|&(distance, _)| distance).max().unwrap_or(0);
+        for (distance, points) in state.iter_mut() {
+            if *distance >= winner {
+                *points += 1;
+            }
+        }
+    }
+
+    state
+}
+
+// Parse the input using regex. Each reindeer is given an id, and the data is
+// indexed by that id.
+//
+// Panics!
+// This function will panic if the regex cannot be compiled.
+pub fn parse(input: &str) -> (Interner, Vec<Reindeer>) {
+
+    let re = Regex::new(
+        r"(?P<name>[A-z]+) can fly (?P<speed>[0-9]+) km\/s for (?P<s_duration>[0-9]+) seconds, but then must rest for (?P<r_duration>[0-9]+) seconds\.").unwrap();
+    
+    let mut names = Interner::new();
+    let mut reindeer_data: Vec<Reindeer> = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() { continue; }
+
+        let caps = match re.captures(line) {
+            Some(c) => c,
+            None => {
+                validate::reject(i + 1, 1, line, "expected \"<name> can fly <n> km/s for <n> seconds, but then must rest for <n> seconds.\"");
+                continue;
+            }
+        };
+        let speed = match caps["speed"].parse::<usize>() {
+            Ok(n) => n,
+            Err(_) => {
+                validate::reject(i + 1, validate::column(line, &caps["speed"]), &caps["speed"], "speed is too large");
+                continue;
+            }
+        };
+        let s_duration = match caps["s_duration"].parse::<usize>() {
+            Ok(n) => n,
+            Err(_) => {
+                validate::reject(i + 1, validate::column(line, &caps["s_duration"]), &caps["s_duration"], "flying time is too large");
+                continue;
+            }
+        };
+        let r_duration = match caps["r_duration"].parse::<usize>() {
+            Ok(n) => n,
+            Err(_) => {
+                validate::reject(i + 1, validate::column(line, &caps["r_duration"]), &caps["r_duration"], "rest time is too large");
+                continue;
+            }
+        };
+
+        let id = names.intern(&caps["name"]);
+        if id == reindeer_data.len() {
+            reindeer_data.push(((speed, s_duration), r_duration));
+        } else {
+            reindeer_data[id] = ((speed, s_duration), r_duration);
+        }
+    }
+
+    (names, reindeer_data)
+}
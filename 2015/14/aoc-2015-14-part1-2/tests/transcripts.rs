@@ -0,0 +1,12 @@
+use shared::snapshot;
+
+const SNAPSHOTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots");
+const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../example-input.txt");
+
+// The example race second by second. 140 seconds is long enough for both
+// reindeer to fly, rest and start flying again.
+#[test]
+fn example_race() {
+    let output = snapshot::run_binary(env!("CARGO_BIN_EXE_aoc-2015-14-part1-2"), &[EXAMPLE, "140", "--debug"]);
+    snapshot::assert_snapshot(SNAPSHOTS, "example-race", &output);
+}
@@ -0,0 +1,291 @@
+// Advent of Code 2015 day 15: Science for Hungry People
+
+use shared::{
+    *,
+    solution::Solution,
+};
+use regex::Regex;
+
+#[derive(Default)]
+pub struct Day15 {
+    pub debug: bool,
+}
+
+impl Solution for Day15 {
+    type Input = Vec<Ingredient>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        if self.debug { println!("{input}"); }
+        let ingredients = parse(&self.debug, input);
+        if self.debug { println!("{:#?}", ingredients); }
+        Ok(ingredients)
+    }
+
+    fn part1(&self, ingredients: &Self::Input) -> Result<Answer, AocError> {
+        part1(self.debug, ingredients).map(Answer::from)
+    }
+
+    fn part2(&self, ingredients: &Self::Input) -> Result<Answer, AocError> {
+        part2(self.debug, ingredients).map(Answer::from)
+    }
+
+    fn describe(&self, _part: u8, answer: &Answer) -> String {
+        format!("The max score is {answer}")
+    }
+}
+
+// The highest score of any cookie that uses 100 teaspoons of ingredients
+//
+// Returns an AocError if a score overflows
+pub fn part1(debug: bool, ingredients: &[Ingredient]) -> Result<usize, AocError> {
+    best_cookie(debug, ingredients, None)
+}
+
+// The highest score of any cookie that uses 100 teaspoons of ingredients with
+// exactly 500 calories
+//
+// Returns an AocError if a score overflows
+pub fn part2(debug: bool, ingredients: &[Ingredient]) -> Result<usize, AocError> {
+    best_cookie(debug, ingredients, Some(500))
+}
+
+// The highest score of any cookie that uses 100 teaspoons of ingredients, only
+// counting cookies with the target calories if there is one. 0 if there are no
+// ingredients.
+fn best_cookie(debug: bool, ingredients: &[Ingredient], calories: Option<isize>) -> Result<usize, AocError> {
+    // Split the search on the amount of the first ingredient and search the
+    // rest of the amounts for each one in parallel
+    if ingredients.is_empty() {
+        Ok(0)
+    } else if ingredients.len() < 2 {
+        let mut max_score: usize = 0;
+        permute_ingredient_amounts(&debug, ingredients, calories, &mut vec![0; ingredients.len()], 0, 100, &mut max_score)?;
+        Ok(max_score)
+    } else {
+        parallel::map_reduce(parallel::threads(), 0..101, Ok(0), |first| {
+            let mut amounts = vec![0; ingredients.len()];
+            amounts[0] = first;
+            let mut max_score: usize = 0;
+            permute_ingredient_amounts(&debug, ingredients, calories, &mut amounts, 1, 100 - first, &mut max_score)?;
+            Ok(max_score)
+        }, |a, b| Ok(a?.max(b?)))
+    }
+}
+
+// Permute the amount of each ingredient to find the max
+fn permute_ingredient_amounts(
+    debug: &bool,
+    ingredient_list: &[Ingredient],
+    calories: Option<isize>,
+    amounts: &mut Vec<usize>,
+    index: usize,
+    remaining: usize,
+    max_score: &mut usize,
+) -> Result<(), AocError> {
+    if index == ingredient_list.len() - 1 {
+        amounts[index] = remaining;
+        let (score, total_calories) = calculate_score(ingredient_list, amounts)?;
+        if calories.is_none_or(|calories| calories == total_calories) {
+            *max_score = (*max_score).max(score);
+        }
+        return Ok(());
+    }
+
+    for i in 0..=remaining {
+        amounts[index] = i;
+        if *debug { println!("{:#?}", amounts); }
+        permute_ingredient_amounts(debug, ingredient_list, calories, amounts, index + 1, remaining - i, max_score)?;
+    }
+
+    Ok(())
+}
+
+// Calculate the score and the calories of a cookie from the amount of each
+// ingredient
+//
+// Returns an AocError if the score or calories overflow
+pub fn calculate_score(ingredient_list: &[Ingredient], amounts: &[usize]) -> Result<(usize, isize), AocError> {
+    let mut capacity = 0;
+    let mut durability = 0;
+    let mut flavor = 0;
+    let mut texture = 0;
+    let mut total_calories = 0;
+
+    for (i, ingredient) in ingredient_list.iter().enumerate() {
+        let amount = amounts[i] as isize;
+        capacity = arith::add(capacity, arith::mul(amount, ingredient.capacity)?)?;
+        durability = arith::add(durability, arith::mul(amount, ingredient.durability)?)?;
+        flavor = arith::add(flavor, arith::mul(amount, ingredient.flavor)?)?;
+        texture = arith::add(texture, arith::mul(amount, ingredient.texture)?)?;
+        total_calories = arith::add(total_calories, arith::mul(amount, ingredient.calories)?)?;
+    }
+
+    // Ensure no negative totals
+    capacity = capacity.max(0);
+    durability = durability.max(0);
+    flavor = flavor.max(0);
+    texture = texture.max(0);
+
+    // Calculate the total score
+    let score = arith::product([capacity, durability, flavor, texture])?.try_into().unwrap_or(0);
+
+    Ok((score, total_calories))
+}
+
+// Parse each ingredient with regex and build a vec of the ingredients
+//
+// Panics!
+// This function will panic if the regex fails to compile
+pub fn parse(debug: &bool, input: &str) -> Vec<Ingredient> {
+
+    let mut ingredient_list: Vec<Ingredient> = Vec::new();
+
+    let re = Regex::new(
+        r"(?P<name>[A-z]+): capacity (?P<capacity>\-?[0-9]+), durability (?P<durability>\-?[0-9]+), flavor (?P<flavor>\-?[0-9]+), texture (?P<texture>\-?[0-9]+), calories (?P<calories>\-?[0-9]+)"
+    ).unwrap();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() { continue; }
+
+        let caps = match re.captures(line.trim()) {
+            Some(o) => o,
+            None => {
+                let (part, expected) = malformed_part(line.trim());
+                validate::reject_error(AocError::Input {
+                    span: Box::new(error::Span::of(i + 1, line, part)),
+                    message: "malformed ingredient".to_string(),
+                    expected: Some(expected),
+                    hint: Some("every property must be given, in this order, as a whole number".to_string()),
+                });
+                continue;
+            }
+        };
+
+        let name: String = caps["name"].to_string();
+        let Some(capacity) = property(i + 1, line, &caps, "capacity") else { continue; };
+        let Some(durability) = property(i + 1, line, &caps, "durability") else { continue; };
+        let Some(flavor) = property(i + 1, line, &caps, "flavor") else { continue; };
+        let Some(texture) = property(i + 1, line, &caps, "texture") else { continue; };
+        let Some(calories) = property(i + 1, line, &caps, "calories") else { continue; };
+
+        if *debug { println!("Found: {}: capacity {}, durability {}, flavor {}, texture {}, calories {}",
+            name, capacity, durability, flavor, texture, calories)}
+
+        ingredient_list.push(Ingredient::new(name, capacity, durability, flavor, texture, calories));
+
+    }
+
+    ingredient_list
+}
+
+// The value of the named property captured from the given line of the input.
+// None if it is too large, after rejecting it.
+fn property(line_number: usize, line: &str, caps: &regex::Captures, name: &str) -> Option<isize> {
+    match caps[name].parse::<isize>() {
+        Ok(value) => Some(value),
+        Err(_) => {
+            validate::reject_error(AocError::Input {
+                span: Box::new(error::Span::of(line_number, line, &caps[name])),
+                message: format!("{name} is too large"),
+                expected: None,
+                hint: None,
+            });
+            None
+        },
+    }
+}
+
+// The properties of an ingredient in the order they are listed
+const PROPERTIES: [&str; 5] = ["capacity", "durability", "flavor", "texture", "calories"];
+
+// The part of an ingredient line that doesn't match the expected format and
+// what should have been there. Missing properties point just past the end of
+// the line. Falls back to the whole line if no single part is to blame.
+fn malformed_part(line: &str) -> (&str, String) {
+    let whole = "<name>: capacity <n>, durability <n>, flavor <n>, texture <n>, calories <n>".to_string();
+    let Some((name, properties)) = line.split_once(':') else {
+        return (line, whole);
+    };
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphabetic()) {
+        return (name, "an ingredient name".to_string());
+    }
+
+    let mut fields = properties.split(',');
+    for property in PROPERTIES {
+        let Some(field) = fields.next() else {
+            return (&line[line.len()..], format!("{property} <n>"));
+        };
+        let field = field.trim();
+        let valid = match field.split_once(' ') {
+            Some((name, amount)) => {
+                let digits = amount.strip_prefix('-').unwrap_or(amount);
+                name == property && !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+            },
+            None => false,
+        };
+        if !valid {
+            return (field, format!("{property} <n>"));
+        }
+    }
+
+    (line, whole)
+}
+
+// An ingredient and its properties per teaspoon
+#[derive(Debug)]
+pub struct Ingredient {
+    pub name: String,
+    pub capacity: isize,
+    pub durability: isize,
+    pub flavor: isize,
+    pub texture: isize,
+    pub calories: isize,
+}
+
+impl Ingredient {
+    pub fn new(
+        name: String,
+        capacity: isize,
+        durability: isize,
+        flavor: isize,
+        texture: isize,
+        calories: isize
+    ) -> Ingredient {
+
+        Ingredient { name, capacity, durability, flavor, texture, calories }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(input: &str) -> String {
+        parse(&false, input);
+        let problems = validate::take();
+        assert_eq!(problems.len(), 1, "{problems:?}");
+
+        problems[0].report(false)
+    }
+
+    // One test, as the problems found by parse are shared by the whole process
+    #[test]
+    fn malformed_ingredients_point_at_the_bad_property() {
+        let bad_capacity = report("Sugar: capacity x, durability 0, flavor 0, texture 2, calories 3\n");
+        assert!(bad_capacity.contains("\n1 | Sugar: capacity x, durability 0, flavor 0, texture 2, calories 3\n"), "{bad_capacity}");
+        assert!(bad_capacity.contains("\n  |        ^^^^^^^^^^ expected capacity <n>\n"), "{bad_capacity}");
+
+        let out_of_order = report("Sugar: capacity 1, flavor 0, durability 0, texture 2, calories 3\n");
+        assert!(out_of_order.contains("\n  |                    ^^^^^^^^ expected durability <n>\n"), "{out_of_order}");
+
+        let missing = report("Sugar: capacity 1, durability 0, flavor 0, texture 2\n");
+        assert!(missing.contains(&format!("\n  | {}^ expected calories <n>\n", " ".repeat(52))), "{missing}");
+
+        let no_name = report("capacity 1, durability 0, flavor 0, texture 2, calories 3\n");
+        assert!(no_name.contains(&format!("\n  | {} expected <name>:", "^".repeat(57))), "{no_name}");
+
+        let too_large = report("Sugar: capacity 99999999999999999999, durability 0, flavor 0, texture 2, calories 3\n");
+        assert!(too_large.contains("ERROR: capacity is too large\n"), "{too_large}");
+        assert!(too_large.ends_with("\n  |                 ^^^^^^^^^^^^^^^^^^^^"), "{too_large}");
+    }
+}
@@ -0,0 +1,295 @@
+// Advent of Code 2015 day 16: Aunt Sue
+
+use shared::{
+    Answer,
+    AocError,
+    solution::Solution,
+    validate,
+};
+
+#[derive(Default)]
+pub struct Day16 {
+    pub debug: bool,
+}
+
+impl Solution for Day16 {
+    type Input = Vec<Sue>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        if self.debug { println!("{input}"); }
+        let sues = parse(input);
+        if self.debug { println!("{:#?}", sues); }
+        Ok(sues)
+    }
+
+    fn part1(&self, sues: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(sues).into())
+    }
+
+    fn part2(&self, sues: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(sues).into())
+    }
+
+    fn describe(&self, _part: u8, answer: &Answer) -> String {
+        format!("Found a Sue with all attributes either matching or unknown: {answer}")
+    }
+}
+
+// What the MFCSAM detected in the gift, with an id of 0
+pub fn ticker_tape() -> Sue {
+    Sue::new(
+        0,
+        Some(3),
+        Some(7),
+        Some(2),
+        Some(3),
+        Some(0),
+        Some(0),
+        Some(5),
+        Some(3),
+        Some(2),
+        Some(1),
+    )
+}
+
+// Find the Sue that matches the ticker tape, where every attribute that is
+// known for her matches the reading. Returns her id, or 0 if no Sue matches.
+pub fn part1(sues: &[Sue]) -> usize {
+    find_sue(sues, false)
+}
+
+// Find the Sue that matches the ticker tape like part 1, except the cats and
+// trees readings are the fewest there can be, and the pomeranians and goldfish
+// readings are the most there can be.
+pub fn part2(sues: &[Sue]) -> usize {
+    find_sue(sues, true)
+}
+
+// Step through each Sue and return the id of the first one where all the known
+// values match, or 0 if none do. With ranges the cats, trees, pomeranians and
+// goldfish readings are limits rather than exact counts.
+fn find_sue(sues: &[Sue], ranges: bool) -> usize {
+    let sue_to_find: Sue = ticker_tape();
+
+    // Whether a known value rules a Sue out
+    let differs = |known: Option<usize>, reading: Option<usize>| known.is_some() && known != reading;
+    let not_more = |known: Option<usize>, reading: Option<usize>| {
+        if ranges { known.is_some() && known <= reading } else { differs(known, reading) }
+    };
+    let not_fewer = |known: Option<usize>, reading: Option<usize>| {
+        if ranges { known.is_some() && known >= reading } else { differs(known, reading) }
+    };
+
+    for sue in sues {
+        if differs(sue.children, sue_to_find.children)
+            || not_more(sue.cats, sue_to_find.cats)
+            || differs(sue.samoyeds, sue_to_find.samoyeds)
+            || not_fewer(sue.pomeranians, sue_to_find.pomeranians)
+            || differs(sue.akitas, sue_to_find.akitas)
+            || differs(sue.vizslas, sue_to_find.vizslas)
+            || not_fewer(sue.goldfish, sue_to_find.goldfish)
+            || not_more(sue.trees, sue_to_find.trees)
+            || differs(sue.cars, sue_to_find.cars)
+            || differs(sue.perfumes, sue_to_find.perfumes)
+        {
+            continue;
+        }
+
+        return sue.id;
+    }
+
+    sue_to_find.id
+}
+
+// Parse each line of input to load all the known Data about each Sue
+// and load it into a Vec<Sue>.  Unknown values get a None. Malformed lines and
+// attributes are rejected and left out.
+pub fn parse(input: &str) -> Vec<Sue> {
+    let mut known_sue_list: Vec<Sue> = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() { continue; }
+
+        // Split by the first : which should yeild 
+        let (sueid, attributes) = match line.split_once(": ") {
+            Some(t) => t,
+            None => {
+                validate::reject(i + 1, 1, line, "expected \"Sue <id>: <attribute>: <n>, ...\"");
+                continue;
+            }
+        };
+
+        // Pull the id out of the first string
+        let id = match sueid.trim().split_once(" ") {
+            Some(s) => {
+                match s.1.trim().parse::<usize>() {
+                    Ok(n) => n,
+                    Err(_) => {
+                        validate::reject(i + 1, validate::column(line, s.1), s.1, "id is not a number");
+                        continue;
+                    }
+                }
+            },
+            None => {
+                validate::reject(i + 1, 1, sueid, "expected \"Sue <id>\"");
+                continue;
+            }
+        };
+
+        // Split attributes into individual parts to be processed
+        let attributes: Vec<&str> = attributes.trim().split(",").collect();
+        if attributes.is_empty() {
+            validate::reject(i + 1, 1, line, "there were not enough attributes");
+            continue;
+        }
+
+        let mut children: Option<usize> = None;
+        let mut cats: Option<usize> = None;
+        let mut samoyeds: Option<usize> = None;
+        let mut pomeranians: Option<usize> = None;
+        let mut akitas: Option<usize> = None;
+        let mut vizslas: Option<usize> = None;
+        let mut goldfish: Option<usize> = None;
+        let mut trees: Option<usize> = None;
+        let mut cars: Option<usize> = None;
+        let mut perfumes: Option<usize> = None;
+
+        // Loop through the attributes
+        for attr in attributes {
+
+            let (name, amount) = match attr.trim().split_once(":") {
+                Some(t) => (t.0.trim(), t.1.trim()),
+                None => {
+                    validate::reject(i + 1, validate::column(line, attr.trim()), attr.trim(), "expected \"<attribute>: <n>\"");
+                    continue;
+                }
+            };
+            let amount = match amount.parse::<usize>() {
+                Ok(n) => n,
+                Err(_) => {
+                    validate::reject(i + 1, validate::column(line, amount), amount, "invalid number for the attribute");
+                    continue;
+                }
+            };
+
+            match name {
+                "children" => {
+                    children = Some(amount);
+                },
+                "cats" => {
+                    cats = Some(amount);
+                },
+                "samoyeds" => {
+                    samoyeds = Some(amount);
+                },
+                "pomeranians" => {
+                    pomeranians = Some(amount);
+                },
+                "akitas" => {
+                    akitas = Some(amount);
+                },
+                "vizslas" => {
+                    vizslas = Some(amount);
+                },
+                "goldfish" => {
+                    goldfish = Some(amount);
+                },
+                "trees" => {
+                    trees = Some(amount);
+                },
+                "cars" => {
+                    cars = Some(amount);
+                },
+                "perfumes" => {
+                    perfumes = Some(amount);
+                },
+                _ => {
+                    validate::reject(i + 1, validate::column(line, name), name, "unknown attribute");
+                    continue;
+                }
+            }
+        }
+
+        let new_sue = Sue::new(id, children, cats, samoyeds, pomeranians, akitas, vizslas, goldfish, trees, cars, perfumes);
+        known_sue_list.push(new_sue);
+    }
+
+    known_sue_list
+}
+
+// What is known about one of the Aunt Sues. Unknown things are None.
+#[derive(Debug)]
+pub struct Sue {
+    pub id: usize,
+    pub children: Option<usize>,
+    pub cats: Option<usize>,
+    pub samoyeds: Option<usize>,
+    pub pomeranians: Option<usize>,
+    pub akitas: Option<usize>,
+    pub vizslas: Option<usize>,
+    pub goldfish: Option<usize>,
+    pub trees: Option<usize>,
+    pub cars: Option<usize>,
+    pub perfumes: Option<usize>,
+}
+
+impl Sue {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: usize,
+        children: Option<usize>,
+        cats: Option<usize>,
+        samoyeds: Option<usize>,
+        pomeranians: Option<usize>,
+        akitas: Option<usize>,
+        vizslas: Option<usize>,
+        goldfish: Option<usize>,
+        trees: Option<usize>,
+        cars: Option<usize>,
+        perfumes: Option<usize>
+    ) -> Sue {
+
+        Sue {
+            id,
+            children,
+            cats,
+            samoyeds,
+            pomeranians,
+            akitas,
+            vizslas,
+            goldfish,
+            trees,
+            cars,
+            perfumes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::generate;
+
+    // How many aunts a part would pick if each were the only aunt
+    fn matching(sues: &[Sue], part: fn(&[Sue]) -> usize) -> Vec<usize> {
+        sues.iter()
+            .filter(|sue| part(std::slice::from_ref(*sue)) == sue.id)
+            .map(|sue| sue.id)
+            .collect()
+    }
+
+    #[test]
+    fn generated_inputs_have_one_aunt_for_each_part() {
+        let generator = generate::find(2015, 16).unwrap();
+        for seed in 0..200 {
+            let sues = parse(&generator.generate(50, seed));
+            assert!(validate::take().is_empty(), "seed {seed} wrote an invalid input");
+
+            let part1_sues = matching(&sues, part1);
+            let part2_sues = matching(&sues, part2);
+            assert_eq!(part1_sues.len(), 1, "seed {seed}: part 1 matches {part1_sues:?}");
+            assert_eq!(part2_sues.len(), 1, "seed {seed}: part 2 matches {part2_sues:?}");
+            assert_ne!(part1_sues, part2_sues, "seed {seed}: both parts match the same aunt");
+        }
+    }
+}
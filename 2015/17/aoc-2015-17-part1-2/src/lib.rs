@@ -0,0 +1,238 @@
+// Advent of Code 2015 day 17: No Such Thing as Too Much
+
+use shared::{
+    Answer,
+    AocError,
+    arith,
+    solution::Solution,
+    validate,
+};
+
+// The puzzle's eggnog, in litres
+pub const EGGNOG: usize = 150;
+
+pub struct Day17 {
+    pub debug: bool,
+    // The litres the containers have to hold
+    pub max: usize,
+}
+
+impl Default for Day17 {
+    fn default() -> Self {
+        Day17 { debug: false, max: EGGNOG }
+    }
+}
+
+impl Solution for Day17 {
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        if self.debug { println!("{input}"); }
+        Ok(parse(input))
+    }
+
+    fn part1(&self, buckets: &Self::Input) -> Result<Answer, AocError> {
+        part1(&combinations_by_size(self.debug, buckets, self.max)?).map(Answer::from)
+    }
+
+    fn part2(&self, buckets: &Self::Input) -> Result<Answer, AocError> {
+        let (min, num_min) = part2(&combinations_by_size(self.debug, buckets, self.max)?);
+        if self.debug { println!("The fewest number of buckets was {min}"); }
+        Ok(num_min.into())
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        match part {
+            1 => format!("There are {answer} combinations that make {}", self.max),
+            _ => format!("There are {answer} combinations of the fewest buckets that make {}", self.max),
+        }
+    }
+}
+
+// The number of combinations of containers that hold exactly max litres,
+// indexed by how many containers they use. With debug the combinations are
+// searched for so they can be listed, otherwise they are counted.
+//
+// Returns an AocError if a count overflows
+pub fn combinations_by_size(debug: bool, buckets: &[usize], max: usize) -> Result<Vec<usize>, AocError> {
+    if debug {
+        Ok(search_combinations(debug, buckets, max))
+    } else {
+        count_combinations(buckets, max)
+    }
+}
+
+// The number of combinations of containers that hold exactly max litres
+//
+// Returns an AocError if the total overflows
+pub fn part1(by_size: &[usize]) -> Result<usize, AocError> {
+    arith::sum(by_size.iter().copied())
+}
+
+// The fewest containers that can hold exactly max litres, and the number of
+// combinations that use that many. usize::MAX if there aren't any.
+pub fn part2(by_size: &[usize]) -> (usize, usize) {
+    match by_size.iter().position(|&count| count > 0) {
+        Some(min) => (min, by_size[min]),
+        None => (usize::MAX, 0),
+    }
+}
+
+// The number of combinations of containers that hold exactly max litres,
+// indexed by how many containers they use.
+//
+// Taking the containers one at a time, each either joins a combination or
+// doesn't, so ways[n][s] counts the ways to hold s litres with n of the
+// containers seen so far.
+//
+// Returns an AocError if a count overflows, as every container of size 0
+// doubles the number of combinations
+pub fn count_combinations(buckets: &[usize], max: usize) -> Result<Vec<usize>, AocError> {
+    let mut ways: Vec<Vec<usize>> = vec![vec![0; max + 1]; buckets.len() + 1];
+    ways[0][0] = 1;
+
+    for (seen, &bucket) in buckets.iter().enumerate() {
+        // Go downwards so that each container is only used once
+        for n in (0..=seen).rev() {
+            for s in (bucket..=max).rev() {
+                ways[n + 1][s] = arith::add(ways[n + 1][s], ways[n][s - bucket])?;
+            }
+        }
+    }
+
+    Ok(ways.iter().map(|w| w[max]).collect())
+}
+
+// The same counts as count_combinations, found by searching for every
+// combination. This is far slower, but it can list them, so it's used with
+// --debug and as the reference in the tests.
+pub fn search_combinations(debug: bool, buckets: &[usize], max: usize) -> Vec<usize> {
+    let combinations = find_combinations(debug, buckets, max);
+    if debug { println!("{:#?}", combinations); }
+
+    let mut by_size: Vec<usize> = vec![0; buckets.len() + 1];
+    for combination in &combinations {
+        by_size[combination.len()] += 1;
+    }
+
+    by_size
+}
+
+// Every combination of containers that holds exactly max litres, as the
+// sorted indexes of the containers in it
+pub fn find_combinations(debug: bool, buckets: &[usize], max: usize) -> Vec<Vec<usize>> {
+    let mut combinations: Vec<Vec<usize>> = Vec::new();
+    recurse_buckets(&debug, buckets, &mut combinations, &max, Vec::new());
+
+    combinations
+}
+
+fn recurse_buckets(debug: &bool, buckets: &[usize], combinations: &mut Vec<Vec<usize>>, max: &usize, current_combination: Vec<usize>) {
+    // Use the index of each bucket, since there can be duplicate bucket values
+    for (i, v) in buckets.iter().enumerate() {
+        // If we already have this index in the sequence then skip it
+        if current_combination.contains(&i) { continue; }
+
+        // Calculate our current bucket total
+        let mut sum: usize = 0;
+        for bi in current_combination.iter() {
+            sum += buckets[*bi];
+        }
+        if *debug { println!("Current sum: {sum}"); }
+
+        // Skip if we're already over the max or if this value puts us over the max
+        if sum >= *max || *v > *max - sum {
+            if *debug { println!("Already over {max}, or {v} puts us over."); }
+            continue;
+        // If this value puts at at exactly the max, we can save this combination
+        // Sort the combination first, so that we can de-duplicate and only add it once.
+        } else if (sum + *v) == *max {
+            let mut new_combination = current_combination.clone();
+            new_combination.push(i);
+            new_combination.sort();
+            
+            if combinations.contains(&new_combination) {
+                if *debug { println!("This combination already exists, skipping."); }
+                continue;
+            }
+            if *debug { println!("Adding {v} hits {max}, saving combination: [{}]", new_combination.iter().map(|num| buckets[*num].to_string()).collect::<Vec<String>>().join(", ")); }
+            combinations.push(new_combination.clone());
+            continue;
+        }
+
+        let mut new_combination = current_combination.clone();
+        new_combination.push(i);
+
+        recurse_buckets(debug, buckets, combinations, max, new_combination);
+    }
+}
+
+// Load the bucket data as a Vec<usize>. Lines that aren't a size are rejected.
+pub fn parse(input: &str) -> Vec<usize> {
+    let mut buckets: Vec<usize> = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let size = line.trim();
+        if size.is_empty() { continue; }
+
+        let bucket: usize = match size.parse::<usize>() {
+            Ok(n) => n,
+            Err(_) => {
+                validate::reject(i + 1, validate::column(line, size), size, "expected a bucket size");
+                continue;
+            }
+        };
+
+        buckets.push(bucket);
+    }
+
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::{
+        differential::{self, Config},
+        property,
+    };
+
+    // Small enough that the generated containers can actually hit it
+    const TARGET: usize = 60;
+
+    #[test]
+    fn counting_matches_the_search() {
+        differential::check(
+            2015,
+            17,
+            Config::default(),
+            |input| Ok(search_combinations(false, &parse(input), TARGET)),
+            |input| count_combinations(&parse(input), TARGET),
+        );
+    }
+
+    #[test]
+    fn combinations_hold_the_target() {
+        let config = Config { cases: 50, max_size: 12, seed: 1 };
+        property::check("combinations sum to the target", config, property::numbers(1..=40), |input| {
+            let buckets = parse(input);
+            let combinations = find_combinations(false, &buckets, TARGET);
+
+            for combination in &combinations {
+                let litres: usize = combination.iter().map(|&i| buckets[i]).sum();
+                if litres != TARGET {
+                    return Err(format!("{combination:?} holds {litres} litres"));
+                }
+                if combination.windows(2).any(|w| w[0] >= w[1]) {
+                    return Err(format!("{combination:?} uses a container twice or isn't sorted"));
+                }
+            }
+            let counted = part1(&count_combinations(&buckets, TARGET).unwrap()).unwrap();
+            if combinations.len() != counted {
+                return Err(format!("found {} combinations but counted {counted}", combinations.len()));
+            }
+
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,25 @@
+use shared::*;
+use aoc_2015_17_part1_2::{Day17, EGGNOG};
+
+fn main() {
+    let args = parse_args_iterations();
+    let mut debug: bool = false;
+    if (args.len() == 3 && &args[2] == "--debug") || (args.len() == 4 && (&args[2] == "--debug" || &args[3] == "--debug")) {
+        debug = true;
+    }
+
+    // Pull the max out of the args
+    let max: usize = match args.len() {
+        3 if args[2].parse::<usize>().is_ok() => args[2].parse::<usize>().unwrap(),
+        4 if args[2].parse::<usize>().is_ok() || args[3].parse::<usize>().is_ok() => {
+            if args[2].parse::<usize>().is_ok() {
+                args[2].parse::<usize>().unwrap()
+            } else {
+                args[3].parse::<usize>().unwrap()
+            }
+        },
+        _ => EGGNOG,
+    };
+
+    solution::run_file(&Day17 { debug, max }, &args[1]);
+}
@@ -0,0 +1,323 @@
+// Advent of Code 2015 day 18: Like a GIF For Your Yard
+
+use shared::{
+    Answer,
+    AocError,
+    cycle,
+    solution::Solution,
+    validate,
+};
+
+// The puzzle's number of steps
+pub const STEPS: usize = 100;
+
+pub struct Day18 {
+    pub debug: bool,
+    // How many steps to animate the grid for
+    pub iterations: usize,
+}
+
+impl Default for Day18 {
+    fn default() -> Self {
+        Day18 { debug: false, iterations: STEPS }
+    }
+}
+
+impl Solution for Day18 {
+    type Input = Vec<Vec<bool>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        if self.debug { println!("{input}"); }
+        Ok(parse(input))
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<Answer, AocError> {
+        let grid = part1(self.debug, grid, self.iterations);
+        if self.debug { print_grid(&self.debug, &grid); }
+        Ok(count_on(&grid).into())
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<Answer, AocError> {
+        let grid = part2(self.debug, grid, self.iterations);
+        if self.debug { print_grid(&self.debug, &grid); }
+        Ok(count_on(&grid).into())
+    }
+
+    fn describe(&self, _part: u8, answer: &Answer) -> String {
+        format!("There are {answer} lights on after {} iterations.", self.iterations)
+    }
+}
+
+// Animate the grid for the number of iterations and return the final grid.
+pub fn part1(debug: bool, grid: &[Vec<bool>], iterations: usize) -> Vec<Vec<bool>> {
+    animate(debug, grid.to_vec(), iterations, &[])
+}
+
+// Animate the grid for the number of iterations and return the final grid.
+// The corner lights are stuck on.
+pub fn part2(debug: bool, grid: &[Vec<bool>], iterations: usize) -> Vec<Vec<bool>> {
+    let (grid, fixed_on) = stick_corners(grid.to_vec());
+    animate(debug, grid, iterations, &fixed_on)
+}
+
+// Turn the corner lights on, returning the grid and where the corners are
+fn stick_corners(mut grid: Vec<Vec<bool>>) -> (Vec<Vec<bool>>, Vec<(usize, usize)>) {
+    if grid.is_empty() || grid[0].is_empty() {
+        return (grid, Vec::new());
+    }
+    let fixed_on: Vec<(usize, usize)> = vec![(0,0), (0,grid.len()-1), (grid[0].len()-1, 0), (grid[0].len()-1, grid.len()-1)];
+
+    for (x, y) in fixed_on.iter() {
+        grid[*y][*x] = true;
+    }
+
+    (grid, fixed_on)
+}
+
+// Animate the grid one iteration at a time, calling show with the starting
+// grid and after every iteration, and return the final grid. The corner lights
+// are stuck on for part 2. Unlike part1 and part2 this never skips over cycles,
+// so every frame is shown.
+pub fn step_by_step<F>(debug: bool, grid: &[Vec<bool>], iterations: usize, part: u8, mut show: F) -> Vec<Vec<bool>>
+where
+    F: FnMut(&[Vec<bool>]),
+{
+    let (mut grid, fixed_on) = if part == 2 { stick_corners(grid.to_vec()) } else { (grid.to_vec(), Vec::new()) };
+
+    show(&grid);
+    for _ in 0..iterations {
+        grid = iterate_grid(&debug, &grid, &fixed_on);
+        show(&grid);
+    }
+
+    grid
+}
+
+// Animate the grid for the number of iterations, keeping the fixed_on lights on
+fn animate(debug: bool, grid: Vec<Vec<bool>>, iterations: usize, fixed_on: &[(usize, usize)]) -> Vec<Vec<bool>> {
+    if debug { println!("{:#?}", grid); }
+
+    // The grid is finite, so it must eventually repeat. Skip over whole cycles
+    // rather than iterating the grid every time.
+    cycle::nth_state(grid, iterations, |g| iterate_grid(&debug, g, fixed_on))
+}
+
+// The number of lights that are on
+pub fn count_on(grid: &[Vec<bool>]) -> usize {
+    let mut count: usize = 0;
+    for line in grid {
+        for v in line {
+            if *v {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+// Display the grid
+pub fn print_grid(debug: &bool, grid: &[Vec<bool>]) {
+    // Clear the screen
+    if !*debug { print!("\x1B[2J\x1B[1;1H"); }
+
+    for line in grid.iter() {
+        for &c in line.iter() {
+            print!("{}", if c { '#' } else { '.' });
+        }
+        println!();
+    }
+
+    if *debug { println!(); }
+}
+
+// Perform one iteration of the grid, where each true stays true if 2 or 3 neighbours
+// are also true and otherwise goes false, and each false turns true if exactly 
+// 3 neighbours are true. The fixed_on positions are always true.
+fn iterate_grid(debug: &bool, grid: &[Vec<bool>], fixed_on: &[(usize, usize)]) -> Vec<Vec<bool>> {
+    let mut new_grid = grid.to_vec();
+    for y in 0..grid.len() {
+        for x in 0..grid[y].len() {
+            if *debug { println!("x:{},y:{}", x, y); }
+
+            if fixed_on.contains(&(x, y)) {
+                if !grid[y][x] { new_grid[y][x] = true; }
+                continue;
+            }
+
+            // Possible neighbour positions
+            let modifiers: Vec<(isize, isize)> = vec![
+                (-1,-1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1)
+            ];
+            let mut on_neighbours: usize = 0;
+            // For each possible neighbour
+            for (xm, ym) in modifiers {
+                // Get modified x value, with out of bounds checks
+                let mx = match xm {
+                    -1 => match x.checked_sub(1) {
+                        Some(n) => n,
+                        None => {
+                            if *debug { println!("{x}-1 is out of bounds!"); }
+                            continue;
+                        },
+                    },
+                    0 => x,
+                    1 => match x.checked_add(1) {
+                        Some(n) => n,
+                        None => {
+                            if *debug { println!("{x}+1 is out of bounds!"); }
+                            continue;
+                        },
+                    },
+                    _ => {
+                        eprintln!("Impossible modifier! {xm}");
+                        continue;
+                    },
+                };
+                // Get modified y value, with out of bounds checks
+                let my = match ym {
+                    -1 => match y.checked_sub(1) {
+                        Some(n) => n,
+                        None => {
+                            if *debug { println!("{y}-1 is out of bounds!"); }
+                            continue;
+                        },
+                    },
+                    0 => y,
+                    1 => match y.checked_add(1) {
+                        Some(n) => n,
+                        None => {
+                            if *debug { println!("{y}+1 is out of bounds!"); }
+                            continue;
+                        },
+                    },
+                    _ => {
+                        eprintln!("Impossible modifier! {ym}");
+                        continue;
+                    },
+                };
+
+                // Check for out of bounds the other way.
+                if mx >= grid[y].len() || my >= grid.len() {
+                    if *debug { println!("Grid is {} by {}, and values are {} and {}", grid[y].len(), grid.len(), mx, my); }
+                    continue;
+                }
+
+                // If the neighbour grid position is on, count it
+                if grid[my][mx] {
+                    on_neighbours += 1;
+                    if *debug { println!("Neighbour {mx},{my} is on!"); }
+                }
+            }
+            if *debug { println!("Found {on_neighbours} neighbours on"); }
+
+            // Current is on
+            if grid[y][x] {
+                // Turn off if on neighbours 2 or 3
+                if on_neighbours != 2 && on_neighbours != 3 {
+                    if *debug { println!("{x},{y} was on, but does not have 2 or 3 neighbours on"); }
+                    new_grid[y][x] = false;
+                }
+            // Current is off
+            } else if !grid[y][x]  {
+                // Turn on if on neighbours is 3
+                if on_neighbours == 3 {
+                    if *debug { println!("{x},{y} was off, and has 3 neighbours on"); }
+                    new_grid[y][x] = true;
+                }
+            }
+        }
+    }
+
+    new_grid
+}
+
+// Create a Vec<Vec<bool>> from a grid file, where . is false and # is true.
+// Lines with any other character, or that are a different length to the first
+// line, are rejected.
+pub fn parse(input: &str) -> Vec<Vec<bool>> {
+    let mut grid: Vec<Vec<bool>> = Vec::new();
+    let mut length: Option<usize> = None;
+
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() { continue; }
+        let row = line.trim();
+
+        // Create a Vec<bool> of the line, where . = false and # = true
+        let l: Vec<bool> = match row.chars().map(|c| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err(c),
+        }).collect() {
+            Ok(l) => l,
+            Err(c) => {
+                let at = row.find(c).unwrap_or(0);
+                let part = &row[at..at + c.len_utf8()];
+                validate::reject(i + 1, validate::column(line, part), part, "lights must be . or #");
+                continue;
+            }
+        };
+
+        if length.is_none() {
+            length = Some(l.len());
+        } else if Some(l.len()) != length {
+            validate::reject(i + 1, validate::column(line, row), row, "this line is a different length to the first");
+            continue;
+        }
+
+        grid.push(l);
+    }
+
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::property::{self, Config};
+
+    #[test]
+    fn animating_in_steps_matches_all_at_once() {
+        let config = Config { cases: 50, max_size: 8, seed: 1 };
+        property::check("animating in steps matches all at once", config, property::grid("#."), |input| {
+            let grid = parse(input);
+            if !validate::take().is_empty() {
+                return Ok(());
+            }
+
+            // Skipping whole cycles must land on the same grid as stepping
+            let mut stepped = grid.clone();
+            for step in 1..=40 {
+                stepped = part1(false, &stepped, 1);
+                if part1(false, &grid, step) != stepped {
+                    return Err(format!("the grid differs after {step} steps"));
+                }
+            }
+
+            // Every frame of the animation is shown, ending on the answer
+            for part in [1, 2] {
+                let mut frames = 0;
+                let last = step_by_step(false, &grid, 40, part, |_| frames += 1);
+                let solved = if part == 1 { part1(false, &grid, 40) } else { part2(false, &grid, 40) };
+                if frames != 41 || last != solved {
+                    return Err(format!("part {part} showed {frames} frames and ended on a different grid"));
+                }
+            }
+
+            let stuck = part2(false, &grid, 40);
+            let (last_row, last_col) = (stuck.len() - 1, stuck[0].len() - 1);
+            if ![(0, 0), (0, last_col), (last_row, 0), (last_row, last_col)].iter().all(|&(y, x)| stuck[y][x]) {
+                return Err("a corner light went out".to_string());
+            }
+
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,60 @@
+use shared::*;
+use aoc_2015_18_part1_2::{parse, print_grid, count_on, step_by_step, Day18, STEPS};
+use std::{
+    thread,
+    time,
+};
+
+fn main() {
+    // --animate shows every iteration of the grid as it goes, pausing between
+    // them. It only means something to this day, so it comes out before the
+    // usual checks.
+    let mut args = collect_args();
+    let animate = args.iter().any(|arg| arg == "--animate");
+    args.retain(|arg| arg != "--animate");
+    let args = check_args_iterations(args);
+    let mut debug: bool = false;
+    if (args.len() == 3 && &args[2] == "--debug") || (args.len() == 4 && (&args[2] == "--debug" || &args[3] == "--debug")) {
+        debug = true;
+    }
+
+    // Pull the iterations out of the args
+    let iterations: usize = match args.len() {
+        3 if args[2].parse::<usize>().is_ok() => args[2].parse::<usize>().unwrap(),
+        4 if args[2].parse::<usize>().is_ok() || args[3].parse::<usize>().is_ok() => {
+            if args[2].parse::<usize>().is_ok() {
+                args[2].parse::<usize>().unwrap()
+            } else {
+                args[3].parse::<usize>().unwrap()
+            }
+        },
+        _ => STEPS,
+    };
+
+    if !animate {
+        solution::run_file(&Day18 { debug, iterations }, &args[1]);
+        return;
+    }
+
+    let file_contents = load_input_file(&args[1]);
+    if debug { println!("{file_contents}"); }
+
+    let grid = parse(&file_contents);
+    validate::finish();
+
+    for (part, pause) in [(1, 500), (2, 100)] {
+        if solution::wants(part) {
+            let grid = step_by_step(debug, &grid, iterations, part, |frame| show(debug, frame, time::Duration::from_millis(pause)));
+            let lights_on = count_on(&grid);
+
+            println!("There are {lights_on} lights on after {iterations} iterations.");
+            solution::answer(part, lights_on);
+        }
+    }
+}
+
+// Display a frame of the animation and pause on it
+fn show(debug: bool, grid: &[Vec<bool>], pause: time::Duration) {
+    print_grid(&debug, grid);
+    thread::sleep(pause);
+}
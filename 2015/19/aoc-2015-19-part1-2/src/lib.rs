@@ -0,0 +1,242 @@
+// Advent of Code 2015 day 19: Medicine for Rudolph
+
+use shared::{
+    *,
+    checkpoint::Checkpoint,
+    solution::Solution,
+};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Default)]
+pub struct Day19 {
+    pub debug: bool,
+}
+
+impl Solution for Day19 {
+    // The medicine molecule and the replacements
+    type Input = (String, Vec<(String, String)>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        if self.debug { println!("{input}"); }
+        let (molecule, replacements) = parse(input);
+        if self.debug { println!("{}\n{:#?}", molecule, replacements); }
+        Ok((molecule, replacements))
+    }
+
+    fn part1(&self, (molecule, replacements): &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(molecule, replacements).into())
+    }
+
+    fn part2(&self, (molecule, replacements): &Self::Input) -> Result<Answer, AocError> {
+        let checkpoint = Checkpoint::new("2015-19-part2", &format!("{molecule}\n{replacements:?}"));
+        match part2(self.debug, molecule.clone(), replacements, checkpoint) {
+            Some(min_steps) => Ok(min_steps.into()),
+            None => Err(AocError::Unsolvable { reason: "the target molecule could not be created".to_string() }),
+        }
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        match part {
+            1 => format!("There are {answer} unique molecules."),
+            _ => format!("It took {answer} steps to find the target molecule."),
+        }
+    }
+}
+
+// The number of distinct molecules one replacement away from the medicine
+// molecule
+pub fn part1(molecule: &str, replacements: &[(String, String)]) -> usize {
+    let mut transformations: HashMap<String, Vec<String>> = HashMap::new();
+    for (from, to) in replacements {
+        transformations.entry(from.clone())
+            .or_default() // Insert a new Vec<String> if the key doesn't exist
+            .push(to.clone());
+    }
+
+    create_molecules(molecule, &transformations).len()
+}
+
+// For each possible transformation, find the position within the string, and
+// replace the value with each new value.  Each created value is a new molecule
+pub fn create_molecules(input: &str, transformations: &HashMap<String, Vec<String>>) -> HashSet<String> {
+    let mut molecules: HashSet<String> = HashSet::new();
+
+    for (k, v) in transformations {
+        let mut position = 0;
+
+        while let Some(index) = input[position..].find(k) {
+            let actual_index = position + index;
+
+            for value in v {
+                // Create a new string with the key replaced by the value
+                let mut replaced = input.to_string();
+                replaced.replace_range(actual_index..actual_index + k.len(), value);
+                molecules.insert(replaced);
+            }
+
+            // Step over one character, so overlapping matches are still found
+            position = actual_index + k.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+
+    molecules
+}
+
+// The fewest steps to make the target molecule starting from a single
+// electron, or None if it can't be made. The search works backwards, from the
+// target to "e", using the replacements the other way round.
+//
+// The best number of steps found so far is checkpointed. Resuming restarts
+// the search, but with that as the bound to beat.
+pub fn part2(
+    debug: bool,
+    target_molecule: String,
+    replacements: &[(String, String)],
+    checkpoint: Checkpoint,
+) -> Option<usize> {
+    let mut transformations: HashMap<String, Vec<String>> = HashMap::new();
+    for (from, to) in replacements {
+        transformations.entry(to.clone())
+            .or_default() // Insert a new Vec<String> if the key doesn't exist
+            .push(from.clone());
+    }
+
+    let smallest_molecule = "e".to_string();
+
+    let mut search = Search {
+        min_steps: checkpoint.resume().unwrap_or(usize::MAX),
+        seen_molecules: HashSet::new(),
+        checkpoint,
+    };
+
+    progress::start("Day 19 part 2", !debug);
+    if search.min_steps < usize::MAX { progress::best(search.min_steps); }
+    new_molecules(debug, target_molecule, &transformations, 1, &smallest_molecule, &mut search);
+    progress::finish();
+    search.checkpoint.clear();
+
+    if search.min_steps == usize::MAX {
+        None
+    } else {
+        Some(search.min_steps)
+    }
+}
+
+// State shared by the whole search
+struct Search {
+    // Fewest steps found so far
+    min_steps: usize,
+    // Digests of every molecule already explored
+    seen_molecules: HashSet<md5::Digest>,
+    checkpoint: Checkpoint,
+}
+
+// for each possible transformation, create a new molecule and step into that
+// molecule if we haven't seen it yet.
+fn new_molecules(
+    debug: bool,
+    input: String,
+    transformations: &HashMap<String, Vec<String>>,
+    step: usize,
+    target: &str,
+    search: &mut Search,
+) {
+    progress::tick();
+
+    if debug { println!("Step: {step} - {input}"); }
+
+    // no point checking beyond what we've already decided is the min
+    if step > search.min_steps {
+        if debug { println!("We've already found a solution smaller than this - Skipping..."); }
+        return;
+    }
+
+    let num_es = input.chars().filter(|&c| c == 'e').count();
+    if num_es > 1 || (num_es == 1 && input.len() > 1) {
+        if debug { println!("There are too many \"e\"s - Skipping..."); }
+        return;
+    }
+
+    for (k, v) in transformations {
+
+        //if debug { println!("Searching for {k}"); }
+
+        // Search for the key, and skip to the next if we don't find
+        let index = match input.find(k) {
+            Some(n) => n,
+            None => {
+                continue;
+            }
+        };
+
+        if debug { println!("Found {k} at position {index}"); }
+
+        // For each of the possible replacement values, make a new molecule
+        for value in v {
+            // Create a new string with the key replaced by the value
+            let mut replaced = input.to_string();
+            replaced.replace_range(index..index + k.len(), value);
+
+            let digest = md5::compute(replaced.clone());
+            if search.seen_molecules.contains(&digest) {
+                if debug { println!("Duplicate molecule."); }
+                continue;
+            } else {
+                search.seen_molecules.insert(digest);
+            }
+
+            // If we've found the target, update the min_steps if needed and continue.
+            if replaced.as_str() == target {
+                if debug { println!("Target Found!!!"); }
+                if step < search.min_steps {
+                    if debug { println!("New record of {step} steps"); }
+                    search.min_steps = step;
+                    search.checkpoint.save(&step);
+                    progress::best(step);
+                }
+                continue;
+            }
+
+            // Step into the next iteration with the newly generated molecule
+            new_molecules(debug, replaced, transformations, step + 1, target, search);
+        }
+    }
+
+}
+
+// Parse input file contents line by line.
+// Replacement lines should be in the format string => string
+// Or just the string for the medicine molecule. If => is not in the line, the
+// molecule is set to that line. The replacements are kept in the order given.
+// Any molecule after the first, and any replacement with nothing on one side,
+// is rejected.
+pub fn parse(input: &str) -> (String, Vec<(String, String)>) {
+    let mut molecule = String::new();
+    let mut replacements: Vec<(String, String)> = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() { continue; }
+
+        let parts = match line.trim().split_once("=>") {
+            Some(p) => p,
+            None => {
+                if molecule.is_empty() {
+                    molecule = line.trim().to_string();
+                } else {
+                    validate::reject(i + 1, validate::column(line, line.trim()), line.trim(), "the molecule was already given on an earlier line");
+                }
+                continue;
+            }
+        };
+
+        let (from, to) = (parts.0.trim(), parts.1.trim());
+        if from.is_empty() || to.is_empty() {
+            validate::reject(i + 1, validate::column(line, line.trim()), line.trim(), "a replacement needs a molecule on both sides of =>");
+            continue;
+        }
+
+        replacements.push((from.to_string(), to.to_string()));
+    }
+
+    (molecule, replacements)
+}
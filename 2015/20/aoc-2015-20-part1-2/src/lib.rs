@@ -0,0 +1,70 @@
+// Advent of Code 2015 day 20: Infinite Elves and Infinite Houses
+
+use shared::{
+    Answer,
+    AocError,
+    math,
+    solution::Solution,
+};
+
+// The input is the number of presents to reach, the first number in it
+#[derive(Default)]
+pub struct Day20 {
+    pub debug: bool,
+}
+
+impl Solution for Day20 {
+    type Input = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        input.split(|c: char| !c.is_ascii_digit())
+            .find_map(|number| number.parse::<usize>().ok())
+            .ok_or_else(|| AocError::Unsolvable { reason: "the input has no number of presents in it".to_string() })
+    }
+
+    fn part1(&self, target: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(*target).into())
+    }
+
+    fn part2(&self, target: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(self.debug, *target).into())
+    }
+
+    fn describe(&self, _part: u8, answer: &Answer) -> String {
+        format!("The lowest house number to get enough presents is: {answer}")
+    }
+}
+
+// Every house n gets 10 presents from each elf that divides it, so house n gets
+// at least 10 * n presents and the answer can be no higher than target / 10.
+// Sieve the divisor sums up to that bound and find the first one big enough.
+pub fn part1(target: usize) -> usize {
+    let target_divisor_sum = target.div_ceil(10) as u64;
+    let limit = target_divisor_sum.max(1) as usize;
+
+    let divisor_sums = math::divisor_sum_sieve(limit);
+    divisor_sums.iter()
+        .skip(1)
+        .position(|&sum| sum >= target_divisor_sum)
+        .map(|n| n + 1)
+        .unwrap_or(limit)
+}
+
+// Each elf visits 50 houses, delivering 11 presents per house. House n always
+// gets 11 * n presents from elf n, so the answer can be no higher than
+// target / 11. Sieve the presents for every house up to that bound and find the
+// first one with enough.
+pub fn part2(debug: bool, target: usize) -> usize {
+    let target_divisor_sum = target.div_ceil(11) as u64;
+    let limit = target_divisor_sum.max(1) as usize;
+
+    let divisor_sums = math::bounded_divisor_sum_sieve(limit, 50);
+    for (n, &sum) in divisor_sums.iter().enumerate().skip(1) {
+        if debug { println!("House {n} got {} presents", sum * 11); }
+        if sum >= target_divisor_sum {
+            return n;
+        }
+    }
+
+    limit
+}
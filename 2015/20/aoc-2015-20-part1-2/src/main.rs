@@ -0,0 +1,37 @@
+use shared::{
+    collect_args,
+    solution,
+};
+use std::process;
+use aoc_2015_20_part1_2::Day20;
+
+fn main() {
+    let args: Vec<String> = collect_args();
+    let mut debug: bool = false;
+    let mut input: usize = 1;
+    let mut i: usize = 1;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--debug" => {
+                debug = true;
+            },
+            "-i" => {
+                if i+1 >= args.len() {
+                    eprintln!("Not enough arguments provided for input");
+                    process::exit(1);
+                }
+                input = match args[i+1].parse::<usize>() {
+                    Ok(n) => n,
+                    Err(_) => input,
+                };
+                i += 1;
+            },
+            _ => {
+                eprintln!("Usage: {} [-i <input>] [--debug] [--part <1|2|both>]", args[0]);
+            }
+        }
+        i += 1;
+    }
+
+    solution::run_input(&Day20 { debug }, &input.to_string());
+}
@@ -0,0 +1,332 @@
+// Advent of Code 2015 day 21: RPG Simulator 20XX
+
+use shared::{
+    *,
+    solution::Solution,
+};
+
+#[derive(Default)]
+pub struct Day21 {
+    pub debug: bool,
+}
+
+impl Solution for Day21 {
+    // The boss's hit points, damage and armor
+    type Input = (isize, isize, isize);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        if self.debug { println!("{input}"); }
+        Ok(parse(self.debug, input))
+    }
+
+    fn part1(&self, boss: &Self::Input) -> Result<Answer, AocError> {
+        part1(self.debug, *boss)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::Unsolvable { reason: "you never won a game".to_string() })
+    }
+
+    fn part2(&self, boss: &Self::Input) -> Result<Answer, AocError> {
+        part2(self.debug, *boss)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::Unsolvable { reason: "you never lost a game".to_string() })
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        match part {
+            1 => format!("The least gold you can spend is {answer}"),
+            _ => format!("The Most gold you can spend and still LOSE is {answer}"),
+        }
+    }
+}
+
+// The least gold that can be spent on a loadout and still win against the
+// boss, or None if no loadout wins
+pub fn part1(debug: bool, boss: (isize, isize, isize)) -> Option<usize> {
+    // Play a game with each loadout in parallel, keeping the cheapest win
+    let min_cost: usize = play_loadouts(debug, boss, usize::MAX, usize::min, |won, cost| {
+        if won { cost } else { usize::MAX }
+    });
+
+    if min_cost < usize::MAX {
+        Some(min_cost)
+    } else {
+        None
+    }
+}
+
+// The most gold that can be spent on a loadout and still lose against the
+// boss, or None if no loadout loses
+pub fn part2(debug: bool, boss: (isize, isize, isize)) -> Option<usize> {
+    // Play a game with each loadout in parallel, keeping the most expensive loss
+    let max_cost: usize = play_loadouts(debug, boss, 0, usize::max, |won, cost| {
+        if won { 0 } else { cost }
+    });
+
+    if max_cost > 0 {
+        Some(max_cost)
+    } else {
+        None
+    }
+}
+
+// Play a game against the boss with every possible loadout in parallel. The
+// keep closure turns whether the game was won and the loadout's cost into a
+// value, and combine picks between two of them.
+fn play_loadouts(
+    debug: bool,
+    boss: (isize, isize, isize),
+    identity: usize,
+    combine: fn(usize, usize) -> usize,
+    keep: impl Fn(bool, usize) -> usize + Sync,
+) -> usize {
+    let (boss_hp, boss_damage, boss_armor) = boss;
+
+    // Possible items
+    let item_list: Vec<Item> = build_item_list();
+
+    let player_hp: isize = 100;
+
+    // Every possible loadout of one weapon, one armor (or none) and two
+    // different rings (or none)
+    let loadouts = build_loadouts(&item_list);
+
+    parallel::map_reduce(parallel::threads(), 0..loadouts.len(), identity, |i| {
+        let [weapon, armor, ring1, ring2] = loadouts[i];
+        if debug { println!("weapon: {}, armor: {}, ring1: {}, ring2: {}", weapon.name, armor.name, ring1.name, ring2.name); }
+
+        let p_damage = weapon.damage + armor.damage + ring1.damage + ring2.damage;
+        let p_armor = weapon.armor + armor.armor + ring1.armor + ring2.armor;
+        let cost = weapon.cost + armor.cost + ring1.cost + ring2.cost;
+        let won = run_game(debug, player_hp, p_damage, p_armor, boss_hp, boss_damage, boss_armor);
+        if debug { println!("{}", if won { "You WIN!" } else { "You LOST!" }); }
+
+        keep(won, cost)
+    }, combine)
+}
+
+// Fight the boss, player first, and return true if the player wins
+pub fn run_game(
+    debug: bool,
+    mut player_hp: isize,
+    player_damage: isize,
+    player_armor: isize,
+    mut boss_hp: isize,
+    boss_damage: isize,
+    boss_armor: isize
+)-> bool {
+
+    loop {
+        boss_hp -= (player_damage - boss_armor).max(1_isize);
+        if debug { println!("Player deals {} damage; boss hp {}", (player_damage - boss_armor).max(1_isize), boss_hp); }
+
+        player_hp -= (boss_damage - player_armor).max(1_isize);
+        if debug { println!("Boss deals {} damage; player hp {}", (boss_damage - player_armor).max(1_isize), player_hp); }
+
+        if player_hp <= 0 || boss_hp <= 0 {
+            break;
+        }
+    }
+
+    boss_hp <= 0
+}
+
+// Parse the boss's hit points, damage and armor. Stats that aren't a whole
+// number of at least 0 are rejected.
+pub fn parse(_debug: bool, input: &str) -> (isize, isize, isize) {
+    let mut hp: isize = 0;
+    let mut damage: isize = 0;
+    let mut armor: isize = 0;
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() { continue; }
+
+        for (sstring, stat) in [("Hit Points:", &mut hp), ("Damage:", &mut damage), ("Armor:", &mut armor)] {
+            let parts = match line.split_once(sstring) {
+                Some(s) => s,
+                None => { continue; },
+            };
+            let value = parts.1.trim();
+            match value.parse::<isize>() {
+                Ok(n) if n >= 0 => *stat = n,
+                _ => validate::reject(i + 1, validate::column(line, value), value, "expected a whole number of at least 0"),
+            }
+        }
+    }
+
+    (hp, damage, armor)
+}
+
+// Build every combination of weapon, armor and two rings. Armor and rings
+// include a "None" item, and the same ring can't be worn twice.
+pub fn build_loadouts(item_list: &[Item]) -> Vec<[&Item; 4]> {
+    let mut loadouts: Vec<[&Item; 4]> = Vec::new();
+
+    for weapon in item_list {
+        if weapon.t != ItemType::WEAPON { continue; }
+        for armor in item_list {
+            if armor.t != ItemType::ARMOR { continue; }
+            for ring1 in item_list {
+                if ring1.t != ItemType::RING { continue; }
+                for ring2 in item_list {
+                    if ring2.t != ItemType::RING || (ring2.name == ring1.name && ring1.name != "None") { continue; }
+                    loadouts.push([weapon, armor, ring1, ring2]);
+                }
+            }
+        }
+    }
+
+    loadouts
+}
+
+// Every item in the shop, with a "None" armor and ring for going without
+pub fn build_item_list() -> Vec<Item> {
+    let item_list = vec![
+        // WEAPONS
+        Item {
+            t: ItemType::WEAPON,
+            name: "Dagger".to_string(),
+            cost: 8,
+            damage: 4,
+            armor: 0,
+        },
+        Item {
+            t: ItemType::WEAPON,
+            name: "Shortsword".to_string(),
+            cost: 10,
+            damage: 5,
+            armor: 0,
+        },
+        Item {
+            t: ItemType::WEAPON,
+            name: "Warhammer".to_string(),
+            cost: 25,
+            damage: 6,
+            armor: 0,
+        },
+        Item {
+            t: ItemType::WEAPON,
+            name: "Longsword".to_string(),
+            cost: 40,
+            damage: 7,
+            armor: 0,
+        },
+        Item {
+            t: ItemType::WEAPON,
+            name: "Greataxe".to_string(),
+            cost: 74,
+            damage: 8,
+            armor: 0,
+        },
+        // ARMOR
+        Item {
+            t: ItemType::ARMOR,
+            name: "None".to_string(),
+            cost: 0,
+            damage: 0,
+            armor: 0,
+        },
+        Item {
+            t: ItemType::ARMOR,
+            name: "Leather".to_string(),
+            cost: 13,
+            damage: 0,
+            armor: 1,
+        },
+        Item {
+            t: ItemType::ARMOR,
+            name: "Chainmail".to_string(),
+            cost: 31,
+            damage: 0,
+            armor: 2,
+        },
+        Item {
+            t: ItemType::ARMOR,
+            name: "Splintmail".to_string(),
+            cost: 53,
+            damage: 0,
+            armor: 3,
+        },
+        Item {
+            t: ItemType::ARMOR,
+            name: "Bandedmail".to_string(),
+            cost: 75,
+            damage: 0,
+            armor: 4,
+        },
+        Item {
+            t: ItemType::ARMOR,
+            name: "Platemail".to_string(),
+            cost: 102,
+            damage: 0,
+            armor: 5,
+        },
+        // RINGS
+        Item {
+            t: ItemType::RING,
+            name: "None".to_string(),
+            cost: 0,
+            damage: 0,
+            armor: 0,
+        },
+        Item {
+            t: ItemType::RING,
+            name: "Damage+1".to_string(),
+            cost: 25,
+            damage: 1,
+            armor: 0,
+        },
+        Item {
+            t: ItemType::RING,
+            name: "Damage+2".to_string(),
+            cost: 50,
+            damage: 2,
+            armor: 0,
+        },
+        Item {
+            t: ItemType::RING,
+            name: "Damage+3".to_string(),
+            cost: 100,
+            damage: 3,
+            armor: 0,
+        },
+        Item {
+            t: ItemType::RING,
+            name: "Defense+1".to_string(),
+            cost: 20,
+            damage: 0,
+            armor: 1,
+        },
+        Item {
+            t: ItemType::RING,
+            name: "Defense+2".to_string(),
+            cost: 40,
+            damage: 0,
+            armor: 2,
+        },
+        Item {
+            t: ItemType::RING,
+            name: "Defense+3".to_string(),
+            cost: 80,
+            damage: 0,
+            armor: 3,
+        },
+    ];
+
+    item_list
+}
+
+#[derive(PartialEq)]
+pub enum ItemType {
+    WEAPON,
+    ARMOR,
+    RING,
+}
+
+// An item from the shop and what it adds to the player
+pub struct Item {
+    pub t: ItemType,
+    pub name: String,
+    pub cost: usize,
+    pub damage: isize,
+    pub armor: isize,
+}
@@ -0,0 +1,32 @@
+use shared::*;
+use aoc_2015_21_part1_2::Day21;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = collect_args();
+    let mut debug: bool = false;
+    let mut file_name: String = String::new();
+    let mut i: usize = 1;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--debug" => {
+                debug = true;
+            },
+            "-f" => {
+                if i+1 >= args.len() {
+                    eprintln!("Not enough arguments provided for file name");
+                    process::exit(1);
+                }
+                file_name = args[i+1].to_string();
+                i += 1;
+            },
+            _ => {
+                eprintln!("Usage: {} [-f <filename>] [--debug] [--part <1|2|both>] [--threads <n>]", args[0]);
+                process::exit(1);
+            }
+        }
+        i += 1;
+    }
+
+    solution::run_file(&Day21 { debug }, &file_name);
+}
@@ -0,0 +1,28 @@
+use shared::snapshot;
+
+const SNAPSHOTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots");
+const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../example-input.txt");
+
+// Every loadout is played for both parts, which is far too much to keep, so
+// only the fights with a dagger and no armor are kept. That still covers
+// every pair of rings. The answers are kept too.
+#[test]
+fn example_dagger_fights() {
+    let output = snapshot::run_binary(
+        env!("CARGO_BIN_EXE_aoc-2015-21-part1-2"),
+        &["-f", EXAMPLE, "--debug", "--threads", "1"],
+    );
+
+    let mut kept: Vec<&str> = Vec::new();
+    let mut keeping = false;
+    for line in output.lines() {
+        if line.starts_with("weapon: ") {
+            keeping = line.starts_with("weapon: Dagger, armor: None,");
+        }
+        if keeping || line.starts_with("The ") {
+            kept.push(line);
+        }
+    }
+
+    snapshot::assert_snapshot(SNAPSHOTS, "example-dagger-fights", &(kept.join("\n") + "\n"));
+}
@@ -1,41 +1,49 @@
-use shared::*;
-use clap::Parser;
-use std::process;
-
-/// Advent Of Code 2015 Day 22 Part 2 solution
-#[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
-struct Args {
-    /// Input file containing boss stats
-    #[arg(short, long)]
-    filename: Option<String>,
-
-    /// Toggle example stats
-    #[arg(short, default_value_t = false)]
-    example: bool,
-
-    /// Toggle debug messages
-    #[arg(long, default_value_t = false)]
-    debug: bool,
+// Advent of Code 2015 day 22: Wizard Simulator 20XX
+
+use shared::{
+    *,
+    solution::Solution,
+};
+
+// With example set the player and boss have the stats from the puzzle's example
+// game. An empty input leaves the boss with the stats hard coded in build_game.
+#[derive(Default)]
+pub struct Day22 {
+    pub debug: bool,
+    pub example: bool,
 }
 
-fn main() {
-    // Parse args
-    let args = Args::parse();
+impl Solution for Day22 {
+    // The boss's hit points and damage, the player's hit points and mana, and
+    // the spells
+    type Input = (usize, usize, usize, usize, Vec<Spell>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let file_contents = (!input.trim().is_empty()).then(|| input.to_string());
+        build_game(self.debug, self.example, &file_contents)
+    }
 
-    // Load input file if provided.
-    let mut file_contents: Option<String> = None;
-    if let Some(filename) = args.filename {
-        file_contents = Some(load_input_file(&filename));
+    fn part1(&self, (boss_hp, boss_damage, player_hp, player_mana, spellbook): &Self::Input) -> Result<Answer, AocError> {
+        part1(self.debug, *boss_hp, *boss_damage, *player_hp, *player_mana, spellbook)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::Unsolvable { reason: "we never won a game".to_string() })
     }
 
-    let (boss_hp, boss_damage, player_hp, player_mana, spellbook) = build_game(args.debug, args.example, &file_contents);
+    fn part2(&self, (boss_hp, boss_damage, player_hp, player_mana, spellbook): &Self::Input) -> Result<Answer, AocError> {
+        part2(self.debug, *boss_hp, *boss_damage, *player_hp, *player_mana, spellbook)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::Unsolvable { reason: "we never won a game".to_string() })
+    }
 
-    find_lowest_mana_win(args.debug, boss_hp, boss_damage, player_hp, player_mana, &spellbook);
+    fn describe(&self, _part: u8, answer: &Answer) -> String {
+        format!("The lowest mana cost to win is {answer}")
+    }
 }
 
 // DFS search the game options to find the min cost to win.
-// Each function iteration represents one turn
+// Each function iteration represents one turn. On hard difficulty the player
+// loses 1 hit point at the start of each of their turns.
+#[allow(clippy::too_many_arguments)]
 fn run_game_dfs(
     debug: bool,
     mut boss_hp: usize,
@@ -46,9 +54,11 @@ fn run_game_dfs(
     cost: usize,
     mut effects: Vec<Effect>,
     spellbook: &Vec<Spell>,
+    hard: bool,
     min_cost: &mut usize,
     player_turn: bool,
 ) {
+    progress::tick();
 
     // Prune search tree if current path is already more expensive than a known win
     if cost >= *min_cost {
@@ -62,7 +72,7 @@ fn run_game_dfs(
         println!("- Boss has {boss_hp} hit points");
     }
 
-    if player_turn {
+    if hard && player_turn {
         player_hp = match player_hp.checked_sub(1) {
             Some(n) => {
                 if n == 0 {
@@ -80,58 +90,60 @@ fn run_game_dfs(
     }
 
     // Loop through effects and apply or expire.
-    for i in 0..effects.len() {
+    for effect in &mut effects {
         // We should not encounter effects with timer 0 here. Skip them if we do
-        if effects[i].timer == 0 {
+        if effect.timer == 0 {
             continue;
         }
 
         // This effect deals damage
-        if let Some(d) = effects[i].damage {
+        if let Some(d) = effect.damage {
 
             // boss_hp will either return a non-zero usize, or you killed him and win
             boss_hp = match boss_hp.checked_sub(d) {
                 Some(n) => {
                     if n == 0 {
-                        if debug { println!("{} deals {} damage. Boss dies. You Win!", effects[i].name, d); }
+                        if debug { println!("{} deals {} damage. Boss dies. You Win!", effect.name, d); }
                         if cost < *min_cost {
                             *min_cost = cost;
+                            progress::best(cost);
                         }
                         return;
                     }
                     n
                 },
                 None => {
-                    if debug { println!("{} deals {} damage. Boss dies. You Win!", effects[i].name, d); }
+                    if debug { println!("{} deals {} damage. Boss dies. You Win!", effect.name, d); }
                     if cost < *min_cost {
                         *min_cost = cost;
+                        progress::best(cost);
                     }
                     return;
                 }
             };
 
-            if debug { print!("{} deals {} damage; ", effects[i].name, d); }
+            if debug { print!("{} deals {} damage; ", effect.name, d); }
         }
 
         // This effect grants mana
-        if let Some(m) = effects[i].mana {
+        if let Some(m) = effect.mana {
 
             player_mana += m;
 
-            if debug { print!("{} provides {} mana; ", effects[i].name, m); }
+            if debug { print!("{} provides {} mana; ", effect.name, m); }
         }
 
-        if debug { if &effects[i].name == "Shield" { print!("Shield ")} }
+        if debug && &effect.name == "Shield" { print!("Shield ")}
 
-        effects[i].timer = effects[i].timer.checked_sub(1).unwrap_or(0);
+        effect.timer = effect.timer.saturating_sub(1);
 
-        if debug { println!("timer is now {}", effects[i].timer); }
+        if debug { println!("timer is now {}", effect.timer); }
 
-        if effects[i].timer == 0 {
-            if debug { println!("{} wears off", effects[i].name); }
+        if effect.timer == 0 {
+            if debug { println!("{} wears off", effect.name); }
             // Remove armor effect if expired
-            if let Some(a) = effects[i].armor {
-                player_armor = player_armor.checked_sub(a).unwrap_or(0);
+            if let Some(a) = effect.armor {
+                player_armor = player_armor.saturating_sub(a);
             }
         }
     }
@@ -177,6 +189,7 @@ fn run_game_dfs(
                                 if debug { println!(". {} deals {} damage. Boss dies. You Win!", spell.effect.name, d); }
                                 if cost_clone < *min_cost {
                                     *min_cost = cost_clone;
+                                    progress::best(cost_clone);
                                 }
                                 return;
                             }
@@ -186,6 +199,7 @@ fn run_game_dfs(
                             if debug { println!(". {} deals {} damage. Boss dies. You Win!", spell.effect.name, d); }
                             if cost_clone < *min_cost {
                                 *min_cost = cost_clone;
+                                progress::best(cost_clone);
                             }
                             return;
                         }
@@ -206,11 +220,10 @@ fn run_game_dfs(
                 effects_clone.push(spell.effect.clone());
             }
 
-            run_game_dfs(debug, boss_hp_clone, boss_damage, player_hp_clone, player_mana_clone, player_armor_clone, cost_clone, effects_clone, spellbook, min_cost, !player_turn);
+            run_game_dfs(debug, boss_hp_clone, boss_damage, player_hp_clone, player_mana_clone, player_armor_clone, cost_clone, effects_clone, spellbook, hard, min_cost, !player_turn);
         }
 
         if debug { println!("Not able to cast a spell! You lost!"); }
-        return;
 
     } else {
         // Boss does damage
@@ -242,12 +255,45 @@ fn run_game_dfs(
 
         if debug { println!("Boss attacks for {} - {} = {} damage!", boss_damage, player_armor, damage); }
 
-        run_game_dfs(debug, boss_hp, boss_damage, player_hp, player_mana, player_armor, cost, effects, spellbook, min_cost, !player_turn);
+        run_game_dfs(debug, boss_hp, boss_damage, player_hp, player_mana, player_armor, cost, effects, spellbook, hard, min_cost, !player_turn);
     }
 }
 
 // Using the provided stats, run games iterating on each possible spell
-// usage to find the lowest possible mana cost to win.
+// usage to find the lowest possible mana cost to win. None if the boss can't
+// be beaten.
+pub fn part1(
+    debug: bool,
+    boss_hp: usize,
+    boss_damage: usize,
+    player_hp: usize,
+    player_mana: usize,
+    spellbook: &Vec<Spell>,
+) -> Option<usize> {
+    progress::start("Day 22 part 1", !debug);
+    let min_cost = find_lowest_mana_win(debug, boss_hp, boss_damage, player_hp, player_mana, spellbook, false);
+    progress::finish();
+
+    min_cost
+}
+
+// The same as part 1 on hard difficulty, where the player loses 1 hit point at
+// the start of each of their turns.
+pub fn part2(
+    debug: bool,
+    boss_hp: usize,
+    boss_damage: usize,
+    player_hp: usize,
+    player_mana: usize,
+    spellbook: &Vec<Spell>,
+) -> Option<usize> {
+    progress::start("Day 22 part 2", !debug);
+    let min_cost = find_lowest_mana_win(debug, boss_hp, boss_damage, player_hp, player_mana, spellbook, true);
+    progress::finish();
+
+    min_cost
+}
+
 fn find_lowest_mana_win(
     debug: bool,
     boss_hp: usize,
@@ -255,26 +301,29 @@ fn find_lowest_mana_win(
     player_hp: usize,
     player_mana: usize,
     spellbook: &Vec<Spell>,
-) {
+    hard: bool,
+) -> Option<usize> {
     let mut min_cost: usize = usize::MAX;
 
-    run_game_dfs(debug, boss_hp, boss_damage, player_hp, player_mana, 0, 0, vec![], spellbook, &mut min_cost, true);
+    run_game_dfs(debug, boss_hp, boss_damage, player_hp, player_mana, 0, 0, vec![], spellbook, hard, &mut min_cost, true);
 
     if min_cost < usize::MAX {
-        println!("The lowest mana cost to win is {min_cost}");
+        Some(min_cost)
     } else {
-        println!("We never won a game!");
+        None
     }
 }
 
 // Build the game data.
 // Load boss stats from input file if provided or use defaults.
 // Prepare the player data and spells.
-fn build_game(
+//
+// Returns an AocError if the input file doesn't have the boss stats
+pub fn build_game(
     debug: bool,
     example: bool,
     file_contents: &Option<String>,
-) -> (usize, usize, usize, usize, Vec<Spell>) {
+) -> Result<(usize, usize, usize, usize, Vec<Spell>), AocError> {
 
     // If the input file is provided, load the boss stats.
     // Use hard coded defaults if file is not provided or cannot be read.
@@ -282,7 +331,7 @@ fn build_game(
     let mut boss_damage: usize = 8;
     if let Some(input) = file_contents {
         if debug { println!("Parsing input file"); }
-        (boss_hp, boss_damage) = parse_input(debug, input);
+        (boss_hp, boss_damage) = parse(debug, input)?;
     }
 
     let mut player_hp: usize = 50;
@@ -297,62 +346,45 @@ fn build_game(
 
     let spellbook = build_spellbook();
 
-    (boss_hp, boss_damage, player_hp, player_mana, spellbook)
+    Ok((boss_hp, boss_damage, player_hp, player_mana, spellbook))
 }
 
-// Parse the input file to retrieve boss stats.
+// Parse the input file to retrieve boss stats. Stats that aren't a whole
+// number are rejected.
 //
-// Exits(1)
-// This function will exit if the boss data cannot be parsed, or if the data is
-// set to 0.
-fn parse_input(debug: bool, input: &str) -> (usize, usize) {
+// Returns an AocError if the hit points or damage are missing or 0
+pub fn parse(debug: bool, input: &str) -> Result<(usize, usize), AocError> {
     let mut boss_hp: usize = 0;
     let mut boss_damage: usize = 0;
 
-    for line in input.lines() {
+    for (i, line) in input.lines().enumerate() {
         if debug { println!("Parsing: {line}"); }
         if line.is_empty() { continue; }
 
-        for stat_string in vec!["Hit Points:", "Damage:"] {
+        for (stat_string, stat) in [("Hit Points:", &mut boss_hp), ("Damage:", &mut boss_damage)] {
             let parts = match line.split_once(stat_string) {
                 Some(s) => s,
                 None => { continue; },
             };
-
-            match stat_string {
-                "Hit Points:" => {
-                    boss_hp = match parts.1.trim().parse::<usize>() {
-                        Ok(n) => n,
-                        Err(_) => {
-                            eprintln!("Could not parse boss hit points!");
-                            process::exit(1);
-                        }
-                    }
-                },
-                "Damage:" => {
-                    boss_damage = match parts.1.trim().parse::<usize>() {
-                        Ok(n) => n,
-                        Err(_) => {
-                            eprintln!("Could not parse boss damage!");
-                            process::exit(1);
-                        }
-                    }
-                },
-                // This catch-all should never be reached, since the Vec is hard coded
-                _ => { }
+            let value = parts.1.trim();
+            match value.parse::<usize>() {
+                Ok(n) => *stat = n,
+                Err(_) => validate::reject(i + 1, validate::column(line, value), value, "expected a whole number"),
             }
         }
     }
 
     if boss_hp == 0 || boss_damage == 0 {
-        eprintln!("Boss damage or hit-points not set! Check the file to make sure they aren't 0.");
-        process::exit(1);
+        return Err(AocError::Unsolvable {
+            reason: "the boss's hit points and damage must both be set, and not 0".to_string(),
+        });
     }
     
-    (boss_hp, boss_damage)
+    Ok((boss_hp, boss_damage))
 }
 
-fn build_spellbook() -> Vec<Spell> {
+// The spells the player can cast
+pub fn build_spellbook() -> Vec<Spell> {
     vec![
         Spell {
             name: "Magic Missile".to_string(),
@@ -419,19 +451,22 @@ fn build_spellbook() -> Vec<Spell> {
     ]
 }
 
+// A spell, its cost and the effect it has when cast
 #[derive(Debug)]
-struct Spell {
-    name: String,
-    cost: usize,
-    effect: Effect,
+pub struct Spell {
+    pub name: String,
+    pub cost: usize,
+    pub effect: Effect,
 }
 
+// What a spell does each turn while its timer runs. A timer of 0 means it
+// happens once, as soon as the spell is cast.
 #[derive(Debug, Clone)]
-struct Effect {
-    name: String,
-    damage: Option<usize>,
-    armor: Option<usize>,
-    mana: Option<usize>,
-    hp: Option<usize>,
-    timer: usize,
-}
\ No newline at end of file
+pub struct Effect {
+    pub name: String,
+    pub damage: Option<usize>,
+    pub armor: Option<usize>,
+    pub mana: Option<usize>,
+    pub hp: Option<usize>,
+    pub timer: usize,
+}
@@ -0,0 +1,32 @@
+use shared::*;
+use aoc_2015_22_part1_2::Day22;
+use clap::Parser;
+
+/// Advent Of Code 2015 Day 22 solution
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    /// Input file containing boss stats
+    #[arg(short, long)]
+    filename: Option<String>,
+
+    /// Toggle example stats
+    #[arg(short, default_value_t = false)]
+    example: bool,
+
+    /// Toggle debug messages
+    #[arg(long, default_value_t = false)]
+    debug: bool,
+}
+
+fn main() {
+    // Parse args
+    let args = Args::parse_from(collect_args());
+
+    let day = Day22 { debug: args.debug, example: args.example };
+    match args.filename {
+        Some(filename) => solution::run_file(&day, &filename),
+        // Without an input file the boss has the hard coded stats
+        None => solution::run_input(&day, ""),
+    }
+}
@@ -0,0 +1,11 @@
+use shared::snapshot;
+
+const SNAPSHOTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots");
+
+// The example game from the puzzle followed by both searches on the example
+// boss, turn by turn
+#[test]
+fn example_games() {
+    let output = snapshot::run_binary(env!("CARGO_BIN_EXE_aoc-2015-22-part1-2"), &["-e", "--debug"]);
+    snapshot::assert_snapshot(SNAPSHOTS, "example-games", &output);
+}
@@ -0,0 +1,316 @@
+// Advent of Code 2015 day 23: Opening the Turing Lock
+
+use shared::{
+    Answer,
+    AocError,
+    arith,
+    error::Span,
+    solution::Solution,
+    validate,
+};
+
+// Part 1 runs the program from a = 0 and part 2 from a = 1. The answer is
+// what ends up in b.
+#[derive(Default)]
+pub struct Day23 {
+    pub debug: bool,
+}
+
+impl Day23 {
+    fn b_after(&self, a: usize, instructions: &[(usize, String)]) -> Result<Answer, AocError> {
+        let mut computer = Computer::new();
+        computer.a = a;
+        computer.run(self.debug, instructions)?;
+        if self.debug { println!("Register a: {}, Register b: {}", computer.a, computer.b); }
+        Ok(computer.b.into())
+    }
+}
+
+impl Solution for Day23 {
+    // Each instruction with the line it is on
+    type Input = Vec<(usize, String)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse(input).into_iter().map(|(line, instruction)| (line, instruction.to_string())).collect())
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Result<Answer, AocError> {
+        self.b_after(0, instructions)
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> Result<Answer, AocError> {
+        self.b_after(1, instructions)
+    }
+
+    fn describe(&self, _part: u8, answer: &Answer) -> String {
+        format!("Register b: {answer}")
+    }
+}
+
+// The program, one instruction per line along with the line it is on. Malformed
+// instructions are rejected and left out.
+pub fn parse(input: &str) -> Vec<(usize, &str)> {
+    let mut instructions: Vec<(usize, &str)> = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let instruction = line.trim();
+        if instruction.is_empty() { continue; }
+        match check_instruction(i + 1, instruction) {
+            Ok(()) => instructions.push((i + 1, instruction)),
+            Err(err) => validate::reject_error(err),
+        }
+    }
+
+    instructions
+}
+
+// Check that an instruction is one the computer knows with the operands it
+// needs
+fn check_instruction(line: usize, instruction: &str) -> Result<(), AocError> {
+    let parts: Vec<&str> = instruction.split(' ').collect();
+    let register = |part: &str| {
+        if matches!(part.trim_end_matches(','), "a" | "b") { Ok(()) } else { Err(no_register(line, instruction, part)) }
+    };
+    let offset = |part: &str| {
+        match part.parse::<isize>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(fault(line, instruction, part, "invalid instruction offset", Some("a signed number like +2 or -7"), None)),
+        }
+    };
+    let operands = |count: usize, expected: &str| {
+        if parts.len() == count + 1 {
+            Ok(())
+        } else {
+            Err(fault(line, instruction, instruction, "wrong number of operands", Some(expected), None))
+        }
+    };
+
+    match parts[0] {
+        "hlf" | "tpl" | "inc" => {
+            operands(1, "a register")?;
+            register(parts[1])
+        },
+        "jmp" => {
+            operands(1, "an offset")?;
+            offset(parts[1])
+        },
+        "jie" | "jio" => {
+            operands(2, "a register and an offset")?;
+            register(parts[1])?;
+            offset(parts[2])
+        },
+        _ => Err(fault(line, instruction, parts[0], "unknown instruction", Some("hlf, tpl, inc, jmp, jie or jio"), None)),
+    }
+}
+
+// Execute the instruction at current_instruction and return the index of the
+// next instruction to run
+//
+// Returns an AocError pointing at the instruction if it is malformed or jumps
+// before the first instruction, or if a register overflows
+pub fn execute_instruction(debug: bool, computer: &mut Computer, current_instruction: usize, instructions: &[(usize, impl AsRef<str>)]) -> Result<usize, AocError> {
+    let (line, instruction) = &instructions[current_instruction];
+    let (line, instruction) = (*line, instruction.as_ref());
+
+    let parts: Vec<&str> = instruction.split(" ").collect();
+    if parts.len() < 2 {
+        return Err(fault(line, instruction, instruction, "malformed instruction", Some("an instruction and its operands"), None));
+    }
+
+    let mut next_instruction = current_instruction + 1;
+    match parts[0].trim() {
+        "hlf" => {
+            match parts[1] {
+                "a" => {
+                    if debug { println!("halving a"); }
+                    computer.a /= 2;
+                },
+                "b" => {
+                    if debug { println!("halving b"); }
+                    computer.b /= 2;
+                },
+                _ => return Err(no_register(line, instruction, parts[1])),
+            }
+        },
+        "tpl" => {
+            match parts[1] {
+                "a" => {
+                    if debug { println!("trippling a"); }
+                    computer.a = arith::mul(computer.a, 3)?;
+                },
+                "b" => {
+                    if debug { println!("trippling b"); }
+                    computer.b = arith::mul(computer.b, 3)?;
+                },
+                _ => return Err(no_register(line, instruction, parts[1])),
+            }
+        },
+        "inc" => {
+            match parts[1] {
+                "a" => {
+                    if debug { println!("incrementing a"); }
+                    computer.a = arith::add(computer.a, 1)?;
+                },
+                "b" => {
+                    if debug { println!("incrementing b"); }
+                    computer.b = arith::add(computer.b, 1)?;
+                },
+                _ => return Err(no_register(line, instruction, parts[1])),
+            }
+        },
+        "jmp" => {
+            next_instruction = jump(current_instruction, line, instruction, parts[1])?;
+            if debug { println!("jumping to {}", next_instruction); }
+        },
+        "jie" | "jio" => {
+            let register = match parts[1].replace(",", "").as_str() {
+                "a" => computer.a,
+                "b" => computer.b,
+                _ => return Err(no_register(line, instruction, parts[1])),
+            };
+            let name = parts[1].replace(",", "");
+            let test = if parts[0] == "jie" { "even" } else { "one" };
+            let jumps = if parts[0] == "jie" { register.is_multiple_of(2) } else { register == 1 };
+            if !jumps {
+                if debug { println!("{name} is not {test} "); }
+                return Ok(next_instruction);
+            }
+            if debug { print!("{name} is {test} "); }
+
+            if parts.len() != 3 {
+                return Err(fault(line, instruction, instruction, "malformed instruction", Some("a register and an offset"), None));
+            }
+
+            next_instruction = jump(current_instruction, line, instruction, parts[2])?;
+            if debug { println!("jumping to {}", next_instruction); }
+        },
+        _ => return Err(fault(line, instruction, parts[0], "unknown instruction", Some("hlf, tpl, inc, jmp, jie or jio"), None)),
+    }
+
+    Ok(next_instruction)
+}
+
+// The index of the instruction offset away from current_instruction
+fn jump(current_instruction: usize, line: usize, instruction: &str, offset: &str) -> Result<usize, AocError> {
+    let n = match offset.parse::<isize>() {
+        Ok(n) => n,
+        Err(_) => return Err(fault(line, instruction, offset, "invalid instruction offset", Some("a signed number like +2 or -7"), None)),
+    };
+    let target = if n < 0 {
+        current_instruction.checked_sub(n.unsigned_abs())
+    } else {
+        current_instruction.checked_add(n as usize)
+    };
+
+    target.ok_or_else(|| fault(line, instruction, offset, "instruction overflow", None, Some("this jumps before the first instruction")))
+}
+
+fn no_register(line: usize, instruction: &str, register: &str) -> AocError {
+    fault(line, instruction, register, "register does not exist", Some("a or b"), Some("the computer only has registers a and b"))
+}
+
+// An error pointing at part of the instruction on the given line of the input
+fn fault(line: usize, instruction: &str, part: &str, message: &str, expected: Option<&str>, hint: Option<&str>) -> AocError {
+    AocError::Input {
+        span: Box::new(Span::of(line, instruction, part)),
+        message: message.to_string(),
+        expected: expected.map(str::to_string),
+        hint: hint.map(str::to_string),
+    }
+}
+
+// The most instructions a program may execute before it is given up on. A
+// program that keeps counting up never repeats a state, so the cycle check
+// alone would let it run until a register overflows
+pub const MAX_STEPS: usize = 10_000_000;
+
+// The two registers of the computer
+pub struct Computer {
+    pub a: usize,
+    pub b: usize,
+}
+
+impl Default for Computer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Computer {
+    pub fn new() -> Computer {
+        let a: usize = 0;
+        let b: usize = 0;
+
+        Computer{ a, b }
+    }
+
+    // Run the program from the first instruction until it jumps outside of it
+    //
+    // Returns an AocError if an instruction can't be executed, if the program
+    // gets back to a state it was in before, as it would then loop forever, or
+    // if it is still running after MAX_STEPS instructions
+    pub fn run(&mut self, debug: bool, instructions: &[(usize, impl AsRef<str>)]) -> Result<(), AocError> {
+        let mut current_instruction: usize = 0;
+        // Brent's cycle detection: compare each state against one saved at
+        // every power of two steps, so a loop is caught without keeping every
+        // state
+        let mut saved = (current_instruction, self.a, self.b);
+        let mut steps: usize = 0;
+        let mut power: usize = 1;
+        for executed in 0.. {
+
+            if current_instruction >= instructions.len() {
+                eprintln!("Instruction pointer out of scope!");
+                break;
+            }
+
+            if executed == MAX_STEPS {
+                return Err(AocError::Unsolvable {
+                    reason: format!("the program is still running after {MAX_STEPS} instructions, at instruction {} with a={} b={}", current_instruction + 1, self.a, self.b),
+                });
+            }
+
+            if debug { println!("i:{current_instruction}, a:{} b:{}", self.a, self.b); }
+
+            current_instruction = execute_instruction(debug, self, current_instruction, instructions)?;
+
+            let state = (current_instruction, self.a, self.b);
+            if state == saved {
+                return Err(AocError::Unsolvable {
+                    reason: format!("the program loops forever from instruction {} with a={} b={}", current_instruction + 1, self.a, self.b),
+                });
+            }
+            steps += 1;
+            if steps == power {
+                saved = state;
+                steps = 0;
+                power = power.saturating_mul(2);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::property::{self, Config};
+
+    const FORWARD: &[&str] = &["inc a", "inc b", "tpl a", "hlf b", "jmp {n}", "jie a, {n}", "jio b, {n}"];
+
+    #[test]
+    fn programs_without_backward_jumps_halt() {
+        let config = Config { cases: 200, max_size: 20, seed: 1 };
+        property::check("programs without backward jumps halt", config, property::instructions(FORWARD, 1..=3), |input| {
+            let instructions = parse(input);
+            Computer::new().run(false, &instructions).map_err(|err| err.to_string())
+        });
+    }
+
+    #[test]
+    fn programs_that_count_forever_are_cut_off() {
+        let instructions = parse("inc a\njmp -1\n");
+        let err = Computer::new().run(false, &instructions).unwrap_err();
+        assert!(matches!(err, AocError::Unsolvable { .. }), "{err}");
+    }
+}
@@ -0,0 +1,18 @@
+use shared::snapshot;
+
+const SNAPSHOTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots");
+const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../example-input.txt");
+
+// The registers before every instruction of the example program, for part 1
+#[test]
+fn example_register_trace() {
+    let output = snapshot::run_binary(env!("CARGO_BIN_EXE_aoc-2015-23-part1"), &["-f", EXAMPLE, "--part", "1", "--debug"]);
+    snapshot::assert_snapshot(SNAPSHOTS, "example-register-trace", &output);
+}
+
+// Starting with a = 1, as part 2 does
+#[test]
+fn example_register_trace_from_a_1() {
+    let output = snapshot::run_binary(env!("CARGO_BIN_EXE_aoc-2015-23-part1"), &["-f", EXAMPLE, "-a", "1", "--debug"]);
+    snapshot::assert_snapshot(SNAPSHOTS, "example-register-trace-from-a-1", &output);
+}
@@ -0,0 +1,316 @@
+// Advent of Code 2015 day 24: It Hangs in the Balance
+
+use shared::{
+    *,
+    solution::Solution,
+};
+
+// The puzzle's groups: three for part 1 and four for part 2
+pub const GROUPS: [usize; 2] = [3, 4];
+
+#[derive(Default)]
+pub struct Day24 {
+    pub debug: bool,
+}
+
+impl Day24 {
+    fn best_qe(&self, package_weights: &[usize], groups: usize) -> Result<Answer, AocError> {
+        match balance(self.debug, package_weights.to_vec(), groups)? {
+            Some(best_qe) => Ok(best_qe.into()),
+            None => Err(AocError::Unsolvable { reason: format!("no valid grouping into {groups} groups was found") }),
+        }
+    }
+}
+
+impl Solution for Day24 {
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse(input))
+    }
+
+    fn part1(&self, package_weights: &Self::Input) -> Result<Answer, AocError> {
+        self.best_qe(package_weights, GROUPS[0])
+    }
+
+    fn part2(&self, package_weights: &Self::Input) -> Result<Answer, AocError> {
+        self.best_qe(package_weights, GROUPS[1])
+    }
+
+    fn describe(&self, _part: u8, answer: &Answer) -> String {
+        format!("Best QE found: {answer}")
+    }
+}
+
+// The package weights, sorted largest to smallest as larger packages are more
+// likely to result in smaller groups. Lines that aren't a weight are rejected.
+pub fn parse(input: &str) -> Vec<usize> {
+    let mut package_weights: Vec<usize> = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let weight = line.trim();
+        if weight.is_empty() { continue; }
+        match weight.parse::<usize>() {
+            Ok(n) => package_weights.push(n),
+            Err(_) => validate::reject(i + 1, validate::column(line, weight), weight, "expected a package weight"),
+        }
+    }
+    package_weights.sort_by(|a, b| b.cmp(a));
+
+    package_weights
+}
+
+// Split the packages into the number of groups with equal weights, and return
+// the quantum entanglement of the best first group: the one with the fewest
+// packages, and then the smallest quantum entanglement. None if there is no
+// valid grouping.
+//
+// Returns an AocError if the weights overflow, if there are no packages, or if
+// their total weight can't be split into the number of groups
+pub fn balance(debug: bool, package_weights: Vec<usize>, groups: usize) -> Result<Option<u128>, AocError> {
+    match best_group(debug, package_weights, groups)? {
+        Some(group) => Ok(Some(arith::product(group.iter().map(|&w| w as u128))?)),
+        None => Ok(None),
+    }
+}
+
+// The weights in the best first group, as for balance. The other packages can
+// always be split evenly into the rest of the groups. None if there is no
+// valid grouping.
+//
+// Returns an AocError if the weights overflow, if there are no packages, or if
+// their total weight can't be split into the number of groups
+pub fn best_group(debug: bool, package_weights: Vec<usize>, groups: usize) -> Result<Option<Vec<usize>>, AocError> {
+    // Count packages and get total sum
+    let total_count: usize = package_weights.len();
+    if debug { println!("Total package count: {}", total_count); }
+    if total_count == 0 {
+        return Err(AocError::Unsolvable { reason: "there are no packages".to_string() });
+    }
+    let total_sum: usize = arith::sum(package_weights.iter().copied())?;
+    if debug { println!("Total package weights: {}", total_sum); }
+
+    // Confirm possibility.  Total sum must be divisible by number of groups.
+    if groups == 0 || !total_sum.is_multiple_of(groups) {
+        return Err(AocError::Unsolvable {
+            reason: format!("these packages cannot be split into {groups} groups"),
+        });
+    }
+
+    let target_sum: usize = total_sum / groups;
+    if debug { println!("Target group sum: {}", target_sum); }
+
+    // The maximum group size should be smaller than 1/n of the total packages
+    let max_size: usize = total_count.div_ceil(groups);
+    if debug { println!("Max group size: {}", max_size); }
+
+    // A first group only counts if the packages left over can be shared out
+    // between the other groups
+    let all_weights = package_weights.clone();
+    let rest_splits = |group: &[usize]| split(&without(&all_weights, group), groups - 1).is_some();
+
+    let mut smallest_group = usize::MAX;
+    let mut best: Option<(u128, Vec<usize>)> = None;
+
+    progress::start("Day 24", !debug);
+    let found = find_smallest_group(debug, &target_sum, &max_size, &rest_splits, package_weights, vec![], &mut smallest_group, &mut best);
+    progress::finish();
+    found?;
+
+    Ok(best.map(|(_, group)| group))
+}
+
+// Split the packages into the number of groups with equal weights, or None if
+// they can't be
+pub fn split(package_weights: &[usize], groups: usize) -> Option<Vec<Vec<usize>>> {
+    if groups == 0 {
+        return package_weights.is_empty().then(Vec::new);
+    }
+    let total_sum: usize = package_weights.iter().try_fold(0_usize, |sum, &w| sum.checked_add(w))?;
+    if !total_sum.is_multiple_of(groups) {
+        return None;
+    }
+
+    // Placing the largest packages first finds dead ends sooner
+    let mut weights = package_weights.to_vec();
+    weights.sort_by(|a, b| b.cmp(a));
+
+    let mut split: Vec<Vec<usize>> = vec![Vec::new(); groups];
+    let mut sums: Vec<usize> = vec![0; groups];
+    fill_groups(&weights, total_sum / groups, &mut split, &mut sums).then_some(split)
+}
+
+// Put each package into a group it fits in, backtracking when one doesn't fit
+// anywhere. As the total is a multiple of the target, once every package is
+// placed every group is full.
+fn fill_groups(weights: &[usize], target_sum: usize, split: &mut [Vec<usize>], sums: &mut [usize]) -> bool {
+    let Some((&weight, rest)) = weights.split_first() else { return true; };
+
+    for g in 0..split.len() {
+        if weight > target_sum - sums[g] { continue; }
+
+        sums[g] += weight;
+        split[g].push(weight);
+        if fill_groups(rest, target_sum, split, sums) {
+            return true;
+        }
+        sums[g] -= weight;
+        split[g].pop();
+
+        // Every empty group is the same, so trying the next one would only
+        // repeat this
+        if split[g].is_empty() { break; }
+    }
+
+    false
+}
+
+// The weights with one package of each weight in the group taken out
+fn without(package_weights: &[usize], group: &[usize]) -> Vec<usize> {
+    let mut rest = package_weights.to_vec();
+    for weight in group {
+        if let Some(at) = rest.iter().position(|w| w == weight) {
+            rest.remove(at);
+        }
+    }
+
+    rest
+}
+
+// Recursively try all possible combinations of packages to find the smallest group
+// that makes the target sum.
+//
+// The quantum entanglement is a product of weights, so it is kept in a u128 and
+// an overflow is reported as an error rather than wrapping.
+#[allow(clippy::too_many_arguments)]
+fn find_smallest_group(
+    debug: bool,
+    target_sum: &usize,
+    max_size: &usize,
+    rest_splits: &dyn Fn(&[usize]) -> bool,
+    remaining_package_weights: Vec<usize>,
+    current_group: Vec<usize>,
+    smallest_group: &mut usize,
+    best: &mut Option<(u128, Vec<usize>)>,
+) -> Result<(), AocError> {
+    progress::tick();
+
+    if debug { println!("Remaining packages: {:?}", remaining_package_weights); }
+
+    // Loop over each possible package weights
+    for weight in remaining_package_weights.iter() {
+
+        let mut current_group_clone = current_group.clone();
+
+        // Try adding the current weight to the current group
+        if debug { println!("Trying to add {} to {:?}", weight, current_group_clone); }
+        current_group_clone.push(*weight);
+
+        // If this pushes us over the target weight, or if we've already found a smaller group, skip to the next package.
+        let sum: usize = current_group_clone.iter().sum();
+        let count: usize = current_group_clone.len();
+        if sum > *target_sum || count > *smallest_group || count > *max_size {
+            if debug { println!("Adding {} to {:?} makes it too big! Skipping...", weight, current_group); }
+            continue;
+        } else if sum == *target_sum {
+            if debug { println!("Found valid group! {:?}", current_group_clone); }
+
+            // If the valid group is smaller than the current smallest group it wins
+            // If they're equal, then the smallest QE group wins
+            let qe: u128 = arith::product(current_group_clone.iter().map(|&w| w as u128))?;
+            let better = if count < *smallest_group {
+                if debug { println!("New group is smaller than {}", smallest_group); }
+                true
+            } else if count == *smallest_group {
+                if debug { println!("New group is as small as {}", smallest_group); }
+                best.as_ref().is_none_or(|(best_qe, _)| qe < *best_qe)
+            } else {
+                if debug { println!("New group is bigger than {}", smallest_group); }
+                false
+            };
+
+            if better {
+                if rest_splits(&current_group_clone) {
+                    if debug { println!("New QE {} is the best so far", qe); }
+                    *smallest_group = count;
+                    *best = Some((qe, current_group_clone));
+                    progress::best(qe);
+                } else if debug {
+                    println!("The other packages can't be split evenly, skipping...");
+                }
+            }
+            continue;
+        }
+
+        // Remove current weight from remaining weights. Only one package is
+        // taken out, as another package may have the same weight.
+        let remaining_package_weights_clone = without(&remaining_package_weights, &[*weight]);
+
+        find_smallest_group(debug, target_sum, max_size, rest_splits, remaining_package_weights_clone, current_group_clone, smallest_group, best)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::property::{self, Config};
+
+    // Taking a package out of the remaining ones used to take out every
+    // package of the same weight, so no group could hold two of them
+    #[test]
+    fn packages_can_share_a_weight() {
+        assert_eq!(balance(false, vec![5, 5, 5, 5], 2), Ok(Some(25)));
+        assert_eq!(balance(false, vec![2, 2, 2, 3, 3], 2), Ok(Some(9)));
+    }
+
+    // The group of 1, 11 and 20 has the lowest quantum entanglement of the
+    // groups of three, but 2, 8, 10, 13, 15 and 16 can't be split into two
+    // groups of 32, so 1, 15 and 16 is the answer
+    #[test]
+    fn the_other_packages_must_split_evenly() {
+        let package_weights = vec![1, 2, 8, 10, 11, 13, 15, 16, 20];
+        assert_eq!(split(&without(&package_weights, &[1, 11, 20]), 2), None);
+        assert_eq!(balance(false, package_weights, 3), Ok(Some(240)));
+    }
+
+    #[test]
+    fn groups_partition_the_packages() {
+        let config = Config { cases: 100, max_size: 9, seed: 1 };
+        property::check("the groups partition the packages", config, property::numbers(1..=30), |input| {
+            let package_weights = parse(input);
+            let total_sum: usize = package_weights.iter().sum();
+
+            for groups in 2..=4 {
+                if !total_sum.is_multiple_of(groups) { continue; }
+
+                let Some(first) = best_group(false, package_weights.clone(), groups).map_err(|err| err.to_string())? else {
+                    if let Some(arrangement) = split(&package_weights, groups) {
+                        return Err(format!("no first group into {groups} was found, but {arrangement:?} works"));
+                    }
+                    continue;
+                };
+                let Some(others) = split(&without(&package_weights, &first), groups - 1) else {
+                    return Err(format!("the packages left after {first:?} can't be split into {} groups", groups - 1));
+                };
+
+                let mut arrangement = vec![first.clone()];
+                arrangement.extend(others);
+                if arrangement.iter().any(|group| group.iter().sum::<usize>() != total_sum / groups) {
+                    return Err(format!("{arrangement:?} has a group that isn't a {groups}th of the weight"));
+                }
+                if arrangement.iter().any(|group| group.len() < first.len()) {
+                    return Err(format!("{arrangement:?} has a group smaller than the first"));
+                }
+
+                let mut packed: Vec<usize> = arrangement.concat();
+                packed.sort_by(|a, b| b.cmp(a));
+                if packed != package_weights {
+                    return Err(format!("{arrangement:?} doesn't hold exactly the packages"));
+                }
+            }
+
+            Ok(())
+        });
+    }
+}
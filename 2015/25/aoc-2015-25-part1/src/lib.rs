@@ -0,0 +1,61 @@
+// Advent of Code 2015 day 25: Let It Snow
+
+use shared::{
+    Answer,
+    AocError,
+    arith,
+    math,
+    solution::Solution,
+};
+
+// There is only one part. The input is where the code is, the first two numbers
+// in it being the row and the column.
+#[derive(Default)]
+pub struct Day25 {
+    pub debug: bool,
+}
+
+impl Solution for Day25 {
+    type Input = (usize, usize);
+
+    const PARTS: &'static [u8] = &[1];
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let mut numbers = input.split(|c: char| !c.is_ascii_digit()).filter_map(|number| number.parse::<usize>().ok());
+        match (numbers.next(), numbers.next()) {
+            (Some(row), Some(column)) => Ok((row, column)),
+            _ => Err(AocError::Unsolvable { reason: "the input needs a row and a column".to_string() }),
+        }
+    }
+
+    fn part1(&self, &(row, column): &Self::Input) -> Result<Answer, AocError> {
+        part1(self.debug, row, column).map(Answer::from)
+    }
+
+    fn describe(&self, _part: u8, answer: &Answer) -> String {
+        format!("The code is {answer}")
+    }
+}
+
+// The code at (row, column) of the manual's grid.
+//
+// Codes are filled in diagonally, so the position of (row, column) in the
+// sequence is the number of cells in all of the earlier diagonals plus the
+// column. Each code is the last one multiplied by 252533 modulo 33554393, so the
+// code at that position is a single modular exponentiation.
+//
+// Returns an AocError if the row or column is 0, as the grid starts at 1, or if
+// the position overflows
+pub fn part1(debug: bool, row: usize, column: usize) -> Result<u64, AocError> {
+    if row == 0 || column == 0 {
+        return Err(AocError::Unsolvable {
+            reason: format!("the grid starts at row 1 column 1, so there is no row {row} column {column}"),
+        });
+    }
+
+    let diagonal = arith::add(row as u64, column as u64 - 1)?;
+    let position = arith::add(arith::mul(diagonal, diagonal - 1)? / 2, column as u64 - 1)?;
+    if debug { println!("Row {row} column {column} is code number {}", position as u128 + 1); }
+
+    Ok(math::mul_mod(20151125, math::mod_pow(252533, position, 33554393), 33554393))
+}
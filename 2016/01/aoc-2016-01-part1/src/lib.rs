@@ -0,0 +1,266 @@
+// Advent of Code 2016 day 1: No Time for a Taxicab
+
+use shared::{
+    Answer,
+    AocError,
+    arith,
+    solution::Solution,
+    validate,
+};
+
+// Both parts follow the same walk, each from the start
+#[derive(Default)]
+pub struct Day01 {
+    pub debug: bool,
+}
+
+impl Solution for Day01 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let instructions = parse(self.debug, input);
+        if self.debug { println!("Instructions: {:?}", instructions); }
+        Ok(instructions)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Result<Answer, AocError> {
+        let walk = find_hq(self.debug, instructions)?;
+        if self.debug { println!("Finished at {},{} facing {:?}", walk.end.0, walk.end.1, walk.direction); }
+        part1(&walk).map(Answer::from)
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> Result<Answer, AocError> {
+        let walk = find_hq(self.debug, instructions)?;
+        if self.debug && let Some((x, y)) = walk.first_double_visit {
+            println!("First double visited location is {x},{y}");
+        }
+        match part2(&walk)? {
+            Some(distance) => Ok(distance.into()),
+            None => Err(AocError::Unsolvable { reason: "no location is visited twice".to_string() }),
+        }
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        match part {
+            1 => format!("Total distance away from start is {answer}"),
+            _ => format!("First double visited location distance away from start is {answer}"),
+        }
+    }
+}
+
+// Follow the instructions from the start, facing north, and record where the
+// walk ends and the first location that is visited twice
+//
+// Returns an AocError if the walk goes further than an isize can hold
+pub fn find_hq(
+    debug: bool,
+    instructions: &Vec<Instruction>
+) -> Result<Walk, AocError> {
+    let mut direction: Direction = Direction::NORTH;
+    let mut x: isize = 0;
+    let mut y: isize = 0;
+    if debug { println!("Starting at {},{} facing {:?}", x, y, direction); }
+    // Each straight stretch walked so far, from where it started to where it
+    // ended. The start is a stretch of its own, so coming back to it counts.
+    let mut walked: Vec<((isize, isize), (isize, isize))> = vec![((x, y), (x, y))];
+    let mut first_double_visit: Option<(isize, isize)> = None;
+
+    for instruction in instructions {
+
+        // Find our new direction.
+        match instruction.turn {
+            Turn::LEFT => {
+                if debug { println!("Turning left"); }
+                match direction {
+                    Direction::NORTH => {
+                        direction = Direction::WEST;
+                    },
+                    Direction::EAST => {
+                        direction = Direction::NORTH;
+                    },
+                    Direction::SOUTH => {
+                        direction = Direction::EAST;
+                    },
+                    Direction::WEST => {
+                        direction = Direction::SOUTH;
+                    },
+                }
+            },
+            Turn::RIGHT => {
+                if debug { println!("Turning right"); }
+                match direction {
+                    Direction::NORTH => {
+                        direction = Direction::EAST;
+                    },
+                    Direction::EAST => {
+                        direction = Direction::SOUTH;
+                    },
+                    Direction::SOUTH => {
+                        direction = Direction::WEST;
+                    },
+                    Direction::WEST => {
+                        direction = Direction::NORTH;
+                    },
+                }
+            },
+        }
+
+        // Walk the whole distance at once. Distances can be far too long to
+        // step through, so the first location visited twice is found by
+        // checking the stretch against each of the earlier ones.
+        if debug { println!("Moving {} units {:?}", instruction.distance, direction); }
+        let (dx, dy): (isize, isize) = match direction {
+            Direction::NORTH => (0, 1),
+            Direction::EAST => (1, 0),
+            Direction::SOUTH => (0, -1),
+            Direction::WEST => (-1, 0),
+        };
+        let distance = instruction.distance as isize;
+        let from = (x, y);
+        x = arith::add(x, dx * distance)?;
+        y = arith::add(y, dy * distance)?;
+
+        if first_double_visit.is_none()
+            && let Some(steps) = walked.iter().filter_map(|&stretch| first_crossing(from, (dx, dy), distance, stretch)).min()
+        {
+            if debug { println!("We've been here before!"); }
+            first_double_visit = Some((from.0 + dx * steps, from.1 + dy * steps));
+        }
+        walked.push((from, (x, y)));
+    }
+
+    Ok(Walk { end: (x, y), direction, first_double_visit })
+}
+
+// The fewest steps, at least 1 and at most distance, from the start going in
+// the direction (dx, dy) that lands on the stretch, if any do
+fn first_crossing(
+    start: (isize, isize),
+    (dx, dy): (isize, isize),
+    distance: isize,
+    (from, to): ((isize, isize), (isize, isize)),
+) -> Option<isize> {
+    // The steps that land inside the stretch on one axis. Stretches are
+    // straight, so landing on both axes at once is landing on the stretch.
+    let steps_within = |start: isize, step: isize, a: isize, b: isize| -> Option<(i128, i128)> {
+        let (low, high) = (a.min(b) as i128, a.max(b) as i128);
+        let start = start as i128;
+        match step {
+            0 if (low..=high).contains(&start) => Some((i128::MIN, i128::MAX)),
+            0 => None,
+            _ => {
+                let (first, last) = ((low - start) * step as i128, (high - start) * step as i128);
+                Some((first.min(last), first.max(last)))
+            },
+        }
+    };
+
+    let (x_first, x_last) = steps_within(start.0, dx, from.0, to.0)?;
+    let (y_first, y_last) = steps_within(start.1, dy, from.1, to.1)?;
+    let first = x_first.max(y_first).max(1);
+    let last = x_last.min(y_last).min(distance as i128);
+
+    (first <= last).then_some(first as isize)
+}
+
+// The distance from the start to where the walk ended
+//
+// Returns an AocError if the distance overflows
+pub fn part1(walk: &Walk) -> Result<usize, AocError> {
+    distance(walk.end)
+}
+
+// The distance from the start to the first location visited twice, if there
+// is one
+//
+// Returns an AocError if the distance overflows
+pub fn part2(walk: &Walk) -> Result<Option<usize>, AocError> {
+    walk.first_double_visit.map(distance).transpose()
+}
+
+// The number of blocks from the start to the location
+fn distance((x, y): (isize, isize)) -> Result<usize, AocError> {
+    arith::add(x.unsigned_abs(), y.unsigned_abs())
+}
+
+// Parse the comma separated turns and distances
+pub fn parse(
+    _debug: bool,
+    input: &str,
+) -> Vec<Instruction> {
+    let mut instructions: Vec<Instruction> = Vec::new();
+
+    // Get instructions from each line. They should be all on one line,
+    // but we will check all ust in case.
+    for (i, raw) in input.lines().enumerate() {
+        // Trim whitespace and skip empty lines.
+        let line = raw.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        // Split each line on commas to get each instruction in a Vec<&str>
+        let instructs: Vec<&str> = line.split(",").collect();
+
+        // For each instruction, check that it starts with R or L, chop that off,
+        // and convert the rest to a number.
+        for instruct in instructs {
+            // Remove whitespace
+            let instruct = instruct.trim();
+
+            // We either start with R or L, or are invalid
+            let turn: Turn = if instruct.starts_with("R") {
+                Turn::RIGHT
+            } else if instruct.starts_with("L") {
+                Turn::LEFT
+            } else {
+                validate::reject(i + 1, validate::column(raw, instruct), instruct, "instructions must start with R or L");
+                continue;
+            };
+
+            // Convert remaining to usize for distance, small enough to be a
+            // position
+            let distance = match instruct.replace("R", "").replace("L", "").parse::<usize>() {
+                Ok(n) if isize::try_from(n).is_ok() => n,
+                _ => {
+                    validate::reject(i + 1, validate::column(raw, instruct), instruct, "the turn must be followed by a distance");
+                    continue;
+                }
+            };
+
+            let instruction = Instruction{ turn, distance };
+
+            instructions.push(instruction);
+        }
+    }
+
+    instructions
+}
+
+// Where a walk ended, the way it was facing, and the first location that was
+// visited twice
+#[derive(Debug)]
+pub struct Walk {
+    pub end: (isize, isize),
+    pub direction: Direction,
+    pub first_double_visit: Option<(isize, isize)>,
+}
+
+// Turn, then walk forward the distance
+#[derive(Debug)]
+pub struct Instruction {
+    pub turn: Turn,
+    pub distance: usize,
+}
+#[derive(Debug)]
+pub enum Turn {
+    LEFT,
+    RIGHT,
+}
+#[derive(Debug)]
+pub enum Direction {
+    NORTH,
+    EAST,
+    SOUTH,
+    WEST,
+}
@@ -0,0 +1,352 @@
+// Building the solvers and running them as child processes.
+//
+// Every solver runs in its own process with a wall-clock timeout and a memory
+// ceiling, so one that never finishes or tries to allocate gigabytes is
+// reported as TIMEOUT or OOM and the rest of the batch carries on.
+use std::{
+    env,
+    fmt,
+    io::{self, BufRead, BufReader, Read, Write},
+    path::{Path, PathBuf},
+    process::{Child, Command, ExitStatus, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+// How often a running solver is checked on
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    // Wall-clock time before the solver is killed
+    pub timeout: Option<Duration>,
+    // Memory ceiling in bytes
+    pub memory: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    // Exited with an error code or crashed
+    Failed(String),
+    Timeout,
+    OutOfMemory,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "OK"),
+            Status::Failed(_) => write!(f, "FAIL"),
+            Status::Timeout => write!(f, "TIMEOUT"),
+            Status::OutOfMemory => write!(f, "OOM"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub status: Status,
+    pub elapsed: Duration,
+    pub stdout: String,
+    pub stderr: String,
+    // The most memory the solver had in use at once, in bytes, where the
+    // platform reports it
+    pub peak_memory: Option<u64>,
+}
+
+// The root of the workspace, where the year directories live
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+// Build the packages in release mode, so the timeouts measure the solver rather
+// than the compiler
+pub fn build(root: &Path, packages: &[&str]) -> Result<(), String> {
+    build_with(root, packages, false)
+}
+
+// Build like build, but keep cargo's output off the terminal. If the build
+// fails the error has the end of what cargo printed.
+pub fn build_quietly(root: &Path, packages: &[&str]) -> Result<(), String> {
+    build_with(root, packages, true)
+}
+
+fn build_with(root: &Path, packages: &[&str], quiet: bool) -> Result<(), String> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    let mut command = Command::new(cargo);
+    command.current_dir(root).args(["build", "--release", "--quiet"]);
+    for package in packages {
+        command.args(["-p", package]);
+    }
+
+    if quiet {
+        return match command.output() {
+            Ok(output) if output.status.success() => Ok(()),
+            Ok(output) => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let lines: Vec<&str> = stderr.lines().collect();
+                Err(format!("cargo build failed ({})\n{}", output.status, lines[lines.len().saturating_sub(20)..].join("\n")))
+            },
+            Err(err) => Err(format!("Unable to run cargo: {err}")),
+        };
+    }
+
+    match command.status() {
+        Ok(status) if status.success() => Ok(()),
+        Ok(status) => Err(format!("cargo build failed ({status})")),
+        Err(err) => Err(format!("Unable to run cargo: {err}")),
+    }
+}
+
+// The path of a package's release binary
+pub fn binary_path(root: &Path, package: &str) -> PathBuf {
+    let target_dir = match env::var_os("CARGO_TARGET_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => root.join("target"),
+    };
+
+    target_dir.join("release").join(format!("{package}{}", env::consts::EXE_SUFFIX))
+}
+
+// Run the binary to completion, or until it runs out of time, passing each
+// line it prints on stdout or stderr to on_line as soon as it is printed
+pub fn run_streaming(binary: &Path, args: &[String], limits: Limits, on_line: &(dyn Fn(&str) + Sync)) -> Result<Outcome, String> {
+    let mut command = Command::new(binary);
+    command.args(args);
+
+    run_command(command, None, limits, on_line)
+}
+
+// Run the command like run_streaming, writing input to its stdin if there is
+// any. Otherwise stdin is closed from the start.
+pub fn run_command(mut command: Command, input: Option<String>, limits: Limits, on_line: &(dyn Fn(&str) + Sync)) -> Result<Outcome, String> {
+    let binary = PathBuf::from(command.get_program());
+    command.stdin(if input.is_some() { Stdio::piped() } else { Stdio::null() })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(bytes) = limits.memory {
+        limit_memory(&mut command, bytes)?;
+    }
+
+    let start = Instant::now();
+    let mut child = command.spawn()
+        .map_err(|err| format!("Unable to start {}: {err}", binary.display()))?;
+    let (stdin, stdout, stderr) = (child.stdin.take(), child.stdout.take(), child.stderr.take());
+
+    thread::scope(|scope| {
+        // A solver that exits without reading all of its input closes the
+        // pipe, which is up to it
+        if let (Some(mut stdin), Some(input)) = (stdin, input) {
+            scope.spawn(move || {
+                let _ = stdin.write_all(input.as_bytes());
+            });
+        }
+
+        // Drain the pipes as the solver runs so it never blocks on a full pipe
+        let stdout = scope.spawn(|| read_lines(stdout, on_line));
+        let stderr = scope.spawn(|| read_lines(stderr, on_line));
+
+        let (exit, timed_out, peak_memory) = wait_with_timeout(&mut child, limits.timeout).map_err(|err| {
+            // The readers only finish once the child is gone
+            let _ = child.kill();
+            format!("Unable to wait for {}: {err}", binary.display())
+        })?;
+        let elapsed = start.elapsed();
+
+        let stdout = stdout.join().unwrap_or_default();
+        let stderr = stderr.join().unwrap_or_default();
+
+        let status = if timed_out {
+            Status::Timeout
+        } else if limits.memory.is_some_and(|limit| ran_out_of_memory(&exit, &stderr, peak_memory, limit)) {
+            Status::OutOfMemory
+        } else if exit.success() {
+            Status::Ok
+        } else {
+            Status::Failed(exit.to_string())
+        };
+
+        Ok(Outcome { status, elapsed, stdout, stderr, peak_memory })
+    })
+}
+
+// Everything read from the pipe, passing each line to on_line as it arrives
+fn read_lines<R: Read>(pipe: Option<R>, on_line: &(dyn Fn(&str) + Sync)) -> String {
+    let mut output = String::new();
+    let Some(pipe) = pipe else {
+        return output;
+    };
+
+    let mut reader = BufReader::new(pipe);
+    let mut bytes: Vec<u8> = Vec::new();
+    loop {
+        bytes.clear();
+        match reader.read_until(b'\n', &mut bytes) {
+            Ok(0) | Err(_) => return output,
+            Ok(_) => {
+                let line = String::from_utf8_lossy(&bytes);
+                on_line(line.trim_end_matches(['\r', '\n']));
+                output += &line;
+            },
+        }
+    }
+}
+
+// Wait for the child to exit, killing it if the timeout passes first. Returns
+// the exit status, whether it was killed and its peak memory use.
+#[cfg(unix)]
+fn wait_with_timeout(child: &mut Child, timeout: Option<Duration>) -> io::Result<(ExitStatus, bool, Option<u64>)> {
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let mut timed_out = false;
+    loop {
+        let block = deadline.is_none() || timed_out;
+        if let Some((status, peak_memory)) = reap(child, block)? {
+            return Ok((status, timed_out, Some(peak_memory)));
+        }
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            // It may have exited since reap, in which case kill fails and the
+            // next reap still collects it
+            let _ = child.kill();
+            timed_out = true;
+        } else {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+// Collect the child's exit status with wait4, which unlike Child::wait also
+// gives its peak resident memory in bytes. Returns None if it is still running
+// and block is false.
+#[cfg(unix)]
+fn reap(child: &Child, block: bool) -> io::Result<Option<(ExitStatus, u64)>> {
+    use std::os::unix::process::ExitStatusExt;
+
+    let mut status: libc::c_int = 0;
+    // SAFETY: rusage is plain data that wait4 fills in
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    let flags = if block { 0 } else { libc::WNOHANG };
+    loop {
+        match unsafe { libc::wait4(child.id() as libc::pid_t, &mut status, flags, &mut usage) } {
+            0 => return Ok(None),
+            -1 => {
+                let err = io::Error::last_os_error();
+                if err.kind() != io::ErrorKind::Interrupted {
+                    return Err(err);
+                }
+            },
+            _ => break,
+        }
+    }
+
+    // ru_maxrss is in bytes on macOS and kilobytes everywhere else
+    let scale: u64 = if cfg!(target_os = "macos") { 1 } else { 1024 };
+
+    Ok(Some((ExitStatus::from_raw(status), usage.ru_maxrss as u64 * scale)))
+}
+
+#[cfg(not(unix))]
+fn wait_with_timeout(child: &mut Child, timeout: Option<Duration>) -> io::Result<(ExitStatus, bool, Option<u64>)> {
+    let Some(timeout) = timeout else {
+        return Ok((child.wait()?, false, None));
+    };
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok((status, false, None));
+        }
+        if Instant::now() >= deadline {
+            // It may have exited since try_wait, in which case kill fails and
+            // wait still reaps it
+            let _ = child.kill();
+            return Ok((child.wait()?, true, None));
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+// Whether a failed run was stopped by the memory limit. That is only known
+// when the solver got as far as the limit, or when a Rust solver's allocation
+// failed ("memory allocation of N bytes failed") for more than was left under
+// it. Other failures, including being killed by something else, stay failures.
+fn ran_out_of_memory(exit: &ExitStatus, stderr: &str, peak_memory: Option<u64>, limit: u64) -> bool {
+    if exit.success() {
+        return false;
+    }
+    let peak_memory = peak_memory.unwrap_or(0);
+    if peak_memory >= limit {
+        return true;
+    }
+
+    stderr.lines()
+        .filter_map(|line| line.trim().strip_prefix("memory allocation of ")?.strip_suffix(" bytes failed"))
+        .filter_map(|bytes| bytes.parse::<u64>().ok())
+        .any(|bytes| peak_memory.saturating_add(bytes) > limit)
+}
+
+// Set the memory ceiling in the child before it starts. On Linux RLIMIT_DATA
+// covers the heap and anonymous mappings without counting the address space
+// that thread stacks and malloc arenas reserve, so parallel solvers aren't
+// penalised for the number of cores. Elsewhere RLIMIT_AS is the closest match.
+#[cfg(unix)]
+fn limit_memory(command: &mut Command, bytes: u64) -> Result<(), String> {
+    use std::os::unix::process::CommandExt;
+
+    #[cfg(target_os = "linux")]
+    let resource = libc::RLIMIT_DATA;
+    #[cfg(not(target_os = "linux"))]
+    let resource = libc::RLIMIT_AS;
+
+    let limit = libc::rlimit {
+        rlim_cur: bytes as libc::rlim_t,
+        rlim_max: bytes as libc::rlim_t,
+    };
+
+    // SAFETY: the closure runs in the forked child before exec and only makes
+    // the async-signal-safe setrlimit call
+    unsafe {
+        command.pre_exec(move || {
+            if libc::setrlimit(resource, &limit) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_command: &mut Command, _bytes: u64) -> Result<(), String> {
+    Err("Memory limits are only supported on Unix, pass --memory 0 to run without one".to_string())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::process::ExitStatusExt;
+
+    const MB: u64 = 1 << 20;
+
+    #[test]
+    fn only_reports_oom_when_the_limit_was_hit() {
+        let aborted = ExitStatus::from_raw(libc::SIGABRT);
+        let killed = ExitStatus::from_raw(libc::SIGKILL);
+        let failed = ExitStatus::from_raw(1 << 8);
+        let allocation = format!("memory allocation of {} bytes failed\n", 48 * MB);
+
+        // An allocation that would have gone over the limit
+        assert!(ran_out_of_memory(&aborted, &allocation, Some(20 * MB), 64 * MB));
+        // Reaching the limit before failing
+        assert!(ran_out_of_memory(&failed, "", Some(64 * MB), 64 * MB));
+
+        // A failed allocation that would have fit is something else
+        assert!(!ran_out_of_memory(&aborted, &allocation, Some(MB), 64 * MB));
+        // Killed or failed by something other than the limit
+        assert!(!ran_out_of_memory(&killed, "", Some(MB), 64 * MB));
+        assert!(!ran_out_of_memory(&failed, "Error: out of memory budget\n", Some(MB), 64 * MB));
+        assert!(!ran_out_of_memory(&ExitStatus::from_raw(0), "", Some(64 * MB), 64 * MB));
+    }
+}
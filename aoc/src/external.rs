@@ -0,0 +1,385 @@
+// Solutions written in other languages.
+//
+// A solution outside the Cargo workspace lives in its own directory next to the
+// day's inputs, e.g. 2016/02/python/, with a solution.json manifest saying how
+// to run it:
+//
+//   {
+//     "language": "python",
+//     "command": "python3",
+//     "args": ["solve.py"],
+//     "dir": ".",
+//     "parts": "both"
+//   }
+//
+// Only command is required. dir is the working directory relative to the
+// manifest and parts is "1", "2" or "both". The runner writes one JSON request
+// to the solution's stdin:
+//
+//   {"year": 2016, "day": 2, "parts": [1, 2], "input": "...", "options": []}
+//
+// and reads one JSON object back from its stdout, either the answers by part
+// or an error:
+//
+//   {"answers": {"1": "1985", "2": 5}}
+//   {"error": "line 3 isn't a direction"}
+//
+// A part that has no answer for this input can give its reason under errors
+// instead, and the other parts still count:
+//
+//   {"answers": {"1": -1}, "errors": {"2": "Santa never enters the basement"}}
+//
+// Anything printed on stderr is passed on like a Rust solver's diagnostics.
+// The answers are turned into "Part 1: 1985" lines, so from there on an
+// external solution is verified, timed and compared like any other. A part's
+// error becomes a "No answer to part 2: ..." line, the way a Rust day prints
+// why it has no answer and carries on.
+use crate::{
+    exec::{self, Limits, Outcome, Status},
+    registry::{Part, Solver},
+    answers_in,
+};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+pub const MANIFEST: &str = "solution.json";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct External {
+    pub language: String,
+    pub command: String,
+    pub args: Vec<String>,
+    // The working directory to run the command in
+    pub dir: PathBuf,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    command: String,
+    #[serde(default)]
+    args: Vec<String>,
+    dir: Option<PathBuf>,
+    parts: Option<String>,
+    language: Option<String>,
+}
+
+// Every external solution under the year and day directories of the root,
+// with a message for each manifest that can't be used
+pub fn discover(root: &Path) -> (Vec<Solver>, Vec<String>) {
+    let mut solvers: Vec<Solver> = Vec::new();
+    let mut problems: Vec<String> = Vec::new();
+
+    for (year, year_dir) in numbered_dirs(root, 4) {
+        for (day, day_dir) in numbered_dirs(&year_dir, 2) {
+            let (Ok(year), Ok(day)) = (u16::try_from(year), u8::try_from(day)) else { continue; };
+            let Ok(entries) = fs::read_dir(&day_dir) else { continue; };
+
+            let mut manifests: Vec<PathBuf> = entries.filter_map(|entry| entry.ok())
+                .map(|entry| entry.path().join(MANIFEST))
+                .filter(|path| path.is_file())
+                .collect();
+            manifests.sort();
+
+            for path in manifests {
+                match load(&path, year, day) {
+                    Ok(solver) => solvers.push(solver),
+                    Err(err) => problems.push(format!("Skipping {}: {err}", path.display())),
+                }
+            }
+        }
+    }
+
+    (solvers, problems)
+}
+
+// Subdirectories named with a number of the given width, in order
+fn numbered_dirs(dir: &Path, width: usize) -> Vec<(u32, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut dirs: Vec<(u32, PathBuf)> = entries.filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            if name.len() != width || !name.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            Some((name.parse().ok()?, entry.path()))
+        })
+        .collect();
+    dirs.sort();
+
+    dirs
+}
+
+fn load(path: &Path, year: u16, day: u8) -> Result<Solver, String> {
+    let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let manifest: Manifest = serde_json::from_str(&contents).map_err(|err| err.to_string())?;
+
+    let part = match manifest.parts.as_deref() {
+        None | Some("both") => Part::Both,
+        Some("1") => Part::One,
+        Some("2") => Part::Two,
+        Some(other) => return Err(format!("parts should be \"1\", \"2\" or \"both\", not {other:?}")),
+    };
+
+    let solution_dir = path.parent().unwrap_or(Path::new("."));
+    let dir = match &manifest.dir {
+        Some(dir) => solution_dir.join(dir),
+        None => solution_dir.to_path_buf(),
+    };
+    if !dir.is_dir() {
+        return Err(format!("the working directory {} doesn't exist", dir.display()));
+    }
+
+    // The solution is known by its directory, like a crate by its package.
+    // Solvers live for the whole run, so the name is leaked to match the
+    // built-in table's &'static str.
+    let name = solution_dir.file_name().unwrap_or_default().to_string_lossy().into_owned();
+    let language = manifest.language.unwrap_or_else(|| name.clone());
+
+    Ok(Solver {
+        year,
+        day,
+        part,
+        package: name.leak(),
+        args: &[],
+        external: Some(External { language, command: manifest.command, args: manifest.args, dir }),
+        solution: None,
+    })
+}
+
+// The parts to ask for and the options to pass on. A --part in extra narrows
+// the parts, anything else is an option.
+fn parts_and_options<'a>(solver: &Solver, extra: &'a [String]) -> (Vec<u8>, Vec<&'a str>) {
+    let mut parts: Vec<u8> = solver.part.numbers().to_vec();
+    let mut options: Vec<&str> = Vec::new();
+    let mut extra = extra.iter();
+    while let Some(arg) = extra.next() {
+        match (arg.as_str(), extra.clone().next().and_then(|part| part.parse::<u8>().ok())) {
+            ("--part", Some(part)) => {
+                parts = vec![part];
+                extra.next();
+            },
+            (arg, _) => options.push(arg),
+        }
+    }
+
+    (parts, options)
+}
+
+// The request written to the solution's stdin
+fn request(solver: &Solver, input: &str, parts: &[u8], options: &[&str]) -> String {
+    json!({ "year": solver.year, "day": solver.day, "parts": parts, "input": input, "options": options }).to_string()
+}
+
+// The answers in the solution's response, as "Part N: answer" lines
+fn render_response(stdout: &str, parts: &[u8]) -> Result<String, String> {
+    let response: Value = serde_json::from_str(stdout.trim())
+        .map_err(|err| format!("expected a JSON response on stdout: {err}"))?;
+
+    if let Some(error) = response.get("error") {
+        return Err(error.as_str().map(str::to_string).unwrap_or_else(|| error.to_string()));
+    }
+    let Some(answers) = response.get("answers").and_then(Value::as_object) else {
+        return Err("expected an \"answers\" object in the response".to_string());
+    };
+    let no_errors = serde_json::Map::new();
+    let errors = match response.get("errors") {
+        Some(errors) => errors.as_object().ok_or("expected \"errors\" to be an object in the response")?,
+        None => &no_errors,
+    };
+
+    let mut rendered = String::new();
+    for part in parts {
+        let answer = match (answers.get(&part.to_string()), errors.get(&part.to_string())) {
+            (Some(Value::String(answer)), _) => answer.clone(),
+            (Some(Value::Number(answer)), _) => answer.to_string(),
+            (Some(other), _) => return Err(format!("the answer to part {part} should be a string or a number, not {other}")),
+            (None, Some(error)) => {
+                let error = error.as_str().map(str::to_string).unwrap_or_else(|| error.to_string());
+                rendered += &format!("No answer to part {part}: {error}\n");
+                continue;
+            },
+            (None, None) => return Err(format!("there is no answer to part {part} in the response")),
+        };
+        rendered += &format!("Part {part}: {answer}\n");
+    }
+
+    Ok(rendered)
+}
+
+// Run the external solution on the input, the same way a binary is run
+pub fn run(solver: &Solver, external: &External, input: &Path, extra: &[String], limits: Limits, on_line: &(dyn Fn(&str) + Sync)) -> Result<Outcome, String> {
+    let contents = fs::read_to_string(input)
+        .map_err(|err| format!("Unable to read {}: {err}", input.display()))?;
+    let (parts, options) = parts_and_options(solver, extra);
+    let request = request(solver, &contents, &parts, &options);
+
+    // A command like ./solve is relative to the solution, not to where aoc runs
+    let program = if external.command.starts_with("./") || external.command.starts_with("../") {
+        external.dir.join(&external.command).display().to_string()
+    } else {
+        external.command.clone()
+    };
+    let mut command = Command::new(program);
+    command.args(&external.args).current_dir(&external.dir);
+
+    let mut outcome = exec::run_command(command, Some(request), limits, on_line)?;
+    if outcome.status == Status::Ok {
+        match render_response(&outcome.stdout, &parts) {
+            Ok(rendered) => outcome.stdout = rendered,
+            Err(err) => outcome.status = Status::Failed(err),
+        }
+    }
+
+    Ok(outcome)
+}
+
+// Where solvers for the same day disagree. Each answer an external solution
+// gave has to appear in the output of every other solver for that part.
+pub fn disagreements(ran: &[(&Solver, &Outcome)]) -> Vec<String> {
+    let mut found: Vec<String> = Vec::new();
+
+    for (solver, outcome) in ran {
+        if solver.external.is_none() || outcome.status != Status::Ok {
+            continue;
+        }
+        for (part, answer) in answers_in(solver, &outcome.stdout) {
+            for (other, other_outcome) in ran {
+                let same = (other.year, other.day, other.package) == (solver.year, solver.day, solver.package);
+                if same || (other.year, other.day) != (solver.year, solver.day) || !other.part.covers(part) || other_outcome.status != Status::Ok {
+                    continue;
+                }
+                if answers_in(other, &other_outcome.stdout).get(&part) != Some(&answer) {
+                    found.push(format!(
+                        "{} {:02} part {part}: {} says {answer}, {} doesn't",
+                        solver.year, solver.day, solver.package, other.package,
+                    ));
+                }
+            }
+        }
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{collections::BTreeMap, time::Duration};
+
+    fn solver(package: &'static str, external: bool) -> Solver {
+        Solver {
+            year: 2015,
+            day: 1,
+            part: Part::Both,
+            package,
+            args: &[],
+            external: external.then(|| External {
+                language: "python".to_string(),
+                command: "python3".to_string(),
+                args: vec![],
+                dir: PathBuf::from("."),
+            }),
+            solution: None,
+        }
+    }
+
+    fn outcome(stdout: &str) -> Outcome {
+        Outcome {
+            status: Status::Ok,
+            elapsed: Duration::from_millis(1),
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            peak_memory: None,
+        }
+    }
+
+    #[test]
+    fn finds_manifests_next_to_the_inputs() {
+        let root = std::env::temp_dir().join(format!("aoc-external-test-{}", std::process::id()));
+        for dir in ["2016/02/python", "2016/02/go", "2016/03/broken", "notes/04/python"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("2016/02/python/solution.json"), r#"{"command": "python3", "args": ["solve.py"], "parts": "1"}"#).unwrap();
+        fs::write(root.join("2016/02/go/solution.json"), r#"{"language": "Go", "command": "go", "args": ["run", "."]}"#).unwrap();
+        fs::write(root.join("2016/03/broken/solution.json"), r#"{"cmd": "ruby"}"#).unwrap();
+        fs::write(root.join("notes/04/python/solution.json"), r#"{"command": "python3"}"#).unwrap();
+
+        let (solvers, problems) = discover(&root);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(solvers.len(), 2);
+        assert_eq!((solvers[0].year, solvers[0].day, solvers[0].package, solvers[0].part), (2016, 2, "go", Part::Both));
+        assert_eq!(solvers[0].external.as_ref().unwrap().language, "Go");
+        assert_eq!(solvers[1].package, "python");
+        assert_eq!(solvers[1].part, Part::One);
+        assert_eq!(solvers[1].external.as_ref().unwrap().args, ["solve.py"]);
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("2016/03/broken/solution.json: unknown field `cmd`"), "{}", problems[0]);
+    }
+
+    #[test]
+    fn asks_for_the_parts_and_passes_on_options() {
+        let solver = solver("python", true);
+        let extra: Vec<String> = ["--check", "--part", "2"].iter().map(|s| s.to_string()).collect();
+
+        let (parts, options) = parts_and_options(&solver, &extra);
+        assert_eq!(
+            request(&solver, "((", &parts, &options),
+            r#"{"day":1,"input":"((","options":["--check"],"parts":[2],"year":2015}"#,
+        );
+    }
+
+    #[test]
+    fn reads_the_answers_from_the_response() {
+        assert_eq!(render_response(r#"{"answers": {"1": "-3", "2": 1}}"#, &[1, 2]).unwrap(), "Part 1: -3\nPart 2: 1\n");
+        assert_eq!(
+            answers_in(&solver("python", true), "Part 1: -3\nPart 2: 1\n"),
+            BTreeMap::from([(1, "-3".to_string()), (2, "1".to_string())]),
+        );
+
+        assert_eq!(render_response(r#"{"error": "bad line 3"}"#, &[1]).unwrap_err(), "bad line 3");
+        assert_eq!(render_response(r#"{"answers": {"1": 5}}"#, &[1, 2]).unwrap_err(), "there is no answer to part 2 in the response");
+        assert!(render_response("-3\n1\n", &[1]).unwrap_err().starts_with("expected a JSON response on stdout"));
+    }
+
+    #[test]
+    fn a_part_can_fail_on_its_own() {
+        let response = r#"{"answers": {"1": 1}, "errors": {"2": "Santa never enters the basement"}}"#;
+        let rendered = render_response(response, &[1, 2]).unwrap();
+        assert_eq!(rendered, "Part 1: 1\nNo answer to part 2: Santa never enters the basement\n");
+        assert_eq!(answers_in(&solver("python", true), &rendered), BTreeMap::from([(1, "1".to_string())]));
+
+        assert_eq!(render_response(r#"{"answers": {}, "errors": ["2"]}"#, &[2]).unwrap_err(), "expected \"errors\" to be an object in the response");
+    }
+
+    #[test]
+    fn compares_answers_across_languages() {
+        let rust = solver("aoc-2015-01-part1-2", false);
+        let python = solver("python", true);
+        let agreeing = outcome("Santa ended up on floor -3\nPart 1: -3\nSanta ended up in the basement in position 1\nPart 2: 1\n");
+        let answered = outcome("Part 1: -3\nPart 2: 5\n");
+
+        assert_eq!(
+            disagreements(&[(&rust, &agreeing), (&python, &answered)]),
+            ["2015 01 part 2: python says 5, aoc-2015-01-part1-2 doesn't"],
+        );
+
+        // Only each part's own answer counts, not the same number elsewhere
+        let answered = outcome("Part 1: 1\nPart 2: 1\n");
+        assert_eq!(
+            disagreements(&[(&rust, &agreeing), (&python, &answered)]),
+            ["2015 01 part 1: python says 1, aoc-2015-01-part1-2 doesn't"],
+        );
+    }
+}
@@ -0,0 +1,370 @@
+// `aoc leaderboard`: reading a private leaderboard's JSON export.
+//
+// The export (the [API] link on the leaderboard page) has every member's
+// stars with the time each one was earned. From that this works out when each
+// member finished each part, how long part 2 took after part 1, and how long
+// after the puzzle unlocked each star came. It also scores the board again
+// under a few different rules. Everything works from the saved file, so it
+// never touches adventofcode.com.
+use clap::ValueEnum;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write as _,
+};
+
+// Days whose points AoC took back after a problem with the puzzle
+const UNSCORED: &[(u16, u8)] = &[(2018, 6), (2020, 1)];
+
+// Puzzles unlock at midnight US Eastern time, 05:00 UTC
+const UNLOCK_HOUR: i64 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Scoring {
+    /// AoC's local score: with n members, the first to get a star earns n
+    /// points, the next n - 1 and so on
+    Standard,
+    /// Like standard, but out of the members who got the star rather than
+    /// everyone on the board, so inactive members don't pad the scores
+    Solvers,
+    /// One point per star
+    Stars,
+    /// Each star earns a point for every hour of the first day it was left
+    /// over, whoever else got it first
+    Speed,
+}
+
+impl Scoring {
+    const ALL: [Scoring; 4] = [Scoring::Standard, Scoring::Solvers, Scoring::Stars, Scoring::Speed];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Scoring::Standard => "standard",
+            Scoring::Solvers => "solvers",
+            Scoring::Stars => "stars",
+            Scoring::Speed => "speed",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Board {
+    pub year: u16,
+    pub owner: u64,
+    // In order of id
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub stars: u32,
+    // The local score in the export
+    pub local_score: i64,
+    // When each star was earned, by day and part, in seconds since the epoch
+    pub solved: BTreeMap<(u8, u8), i64>,
+}
+
+// The export as AoC writes it. Older exports have their ids and timestamps as
+// strings, so those are read from either.
+#[derive(Deserialize)]
+struct Export {
+    event: String,
+    #[serde(deserialize_with = "number")]
+    owner_id: i64,
+    members: HashMap<String, ExportMember>,
+}
+
+#[derive(Deserialize)]
+struct ExportMember {
+    #[serde(deserialize_with = "number")]
+    id: i64,
+    name: Option<String>,
+    stars: u32,
+    local_score: i64,
+    #[serde(default)]
+    completion_day_level: HashMap<String, HashMap<String, ExportStar>>,
+}
+
+#[derive(Deserialize)]
+struct ExportStar {
+    #[serde(deserialize_with = "number")]
+    get_star_ts: i64,
+}
+
+fn number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::Number(n) => n.as_i64().ok_or_else(|| serde::de::Error::custom(format!("{n} is not a whole number"))),
+        Value::String(s) => s.parse().map_err(|_| serde::de::Error::custom(format!("{s:?} is not a number"))),
+        other => Err(serde::de::Error::custom(format!("expected a number, found {other}"))),
+    }
+}
+
+// Read the JSON export of a private leaderboard
+pub fn parse(json: &str) -> Result<Board, String> {
+    let export: Export = serde_json::from_str(json).map_err(|err| format!("This isn't a leaderboard export: {err}"))?;
+    let year: u16 = export.event.parse()
+        .map_err(|_| format!("The event should be a year, not {:?}", export.event))?;
+
+    let mut members: Vec<Member> = Vec::new();
+    for member in export.members.into_values() {
+        let mut solved: BTreeMap<(u8, u8), i64> = BTreeMap::new();
+        for (day, parts) in member.completion_day_level {
+            let day: u8 = day.parse().ok().filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| format!("Member {} has stars for day {day:?}, which isn't a day of the month", member.id))?;
+            for (part, star) in parts {
+                let part: u8 = part.parse().ok().filter(|part| (1..=2).contains(part))
+                    .ok_or_else(|| format!("Member {} has a star for part {part:?} of day {day}", member.id))?;
+                solved.insert((day, part), star.get_star_ts);
+            }
+        }
+
+        let id = member.id as u64;
+        members.push(Member {
+            id,
+            name: member.name.unwrap_or_else(|| format!("(anonymous user #{id})")),
+            stars: member.stars,
+            local_score: member.local_score,
+            solved,
+        });
+    }
+    members.sort_by_key(|member| member.id);
+
+    Ok(Board { year, owner: export.owner_id as u64, members })
+}
+
+// When the day's puzzle unlocked, in seconds since the epoch
+pub fn unlock(year: u16, day: u8) -> i64 {
+    days_from_civil(year as i64, 12, day as i64) * 86400 + UNLOCK_HOUR * 3600
+}
+
+// Days since 1970-01-01 of the date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+// The date and time in UTC as "MM-DD hh:mm:ss"
+fn utc(timestamp: i64) -> String {
+    let days = timestamp.div_euclid(86400);
+    let secs = timestamp.rem_euclid(86400);
+
+    // The inverse of days_from_civil
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+
+    format!("{month:02}-{day:02} {:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+// A length of time as "h:mm:ss", with days in front when there are any
+fn span(secs: i64) -> String {
+    let sign = if secs < 0 { "-" } else { "" };
+    let secs = secs.abs();
+    let clock = format!("{}:{:02}:{:02}", secs / 3600 % 24, secs / 60 % 60, secs % 60);
+
+    match secs / 86400 {
+        0 => format!("{sign}{clock}"),
+        days => format!("{sign}{days}d {clock}"),
+    }
+}
+
+// Each member's score under the rule, by member id
+pub fn scores(board: &Board, scoring: Scoring) -> HashMap<u64, i64> {
+    let mut scores: HashMap<u64, i64> = board.members.iter().map(|member| (member.id, 0)).collect();
+    let members = board.members.len() as i64;
+
+    for day in 1..=25 {
+        for part in 1..=2 {
+            // Who got the star, first to last. Ties go to the lower id, as
+            // they do on the site.
+            let mut solvers: Vec<(i64, u64)> = board.members.iter()
+                .filter_map(|member| Some((*member.solved.get(&(day, part))?, member.id)))
+                .collect();
+            solvers.sort();
+            let count = solvers.len() as i64;
+
+            for (rank, (timestamp, id)) in solvers.into_iter().enumerate() {
+                let rank = rank as i64;
+                let points = match scoring {
+                    Scoring::Standard if UNSCORED.contains(&(board.year, day)) => 0,
+                    Scoring::Standard => members - rank,
+                    Scoring::Solvers => count - rank,
+                    Scoring::Stars => 1,
+                    Scoring::Speed => (24 - (timestamp - unlock(board.year, day)) / 3600).max(0),
+                };
+                *scores.entry(id).or_default() += points;
+            }
+        }
+    }
+
+    scores
+}
+
+// The standings under every rule, ordered by the chosen one, then each
+// member's stars: when they got them, how long after the unlock that was, and
+// how long part 2 took after part 1. day narrows the stars down to one day.
+pub fn report(board: &Board, scoring: Scoring, day: Option<u8>) -> String {
+    let all: Vec<HashMap<u64, i64>> = Scoring::ALL.iter().map(|&rule| scores(board, rule)).collect();
+    let chosen = scores(board, scoring);
+
+    let mut members: Vec<&Member> = board.members.iter().collect();
+    members.sort_by_key(|member| (-chosen[&member.id], -(member.stars as i64), member.id));
+
+    let mut text = String::new();
+    let plural = if members.len() == 1 { "" } else { "s" };
+    let _ = writeln!(text, "{} private leaderboard, {} member{plural}, ordered by {} score", board.year, members.len(), scoring.name());
+    let _ = writeln!(text);
+    let _ = write!(text, "{:>4}  {:<24}{:>6}{:>8}", "", "member", "stars", "local");
+    for rule in Scoring::ALL {
+        let _ = write!(text, "{:>10}", rule.name());
+    }
+    let _ = writeln!(text);
+    for (rank, member) in members.iter().enumerate() {
+        let _ = write!(text, "{:>3})  {:<24}{:>6}{:>8}", rank + 1, member.name, member.stars, member.local_score);
+        for scores in &all {
+            let _ = write!(text, "{:>10}", scores[&member.id]);
+        }
+        let _ = writeln!(text);
+    }
+
+    for member in members {
+        let days: Vec<u8> = (1..=25)
+            .filter(|&d| day.is_none_or(|day| day == d))
+            .filter(|&d| member.solved.contains_key(&(d, 1)))
+            .collect();
+        if days.is_empty() {
+            continue;
+        }
+
+        let _ = writeln!(text);
+        let _ = writeln!(text, "{}", member.name);
+        let _ = writeln!(text, "{:>5}  {:<32}{:<32}part 1 to 2", "day", "part 1 (after unlock)", "part 2 (after unlock)");
+        for d in days {
+            let unlocked = unlock(board.year, d);
+            let star = |part: u8| -> String {
+                member.solved.get(&(d, part))
+                    .map(|&ts| format!("{} (+{})", utc(ts), span(ts - unlocked)))
+                    .unwrap_or_default()
+            };
+            let between = match (member.solved.get(&(d, 1)), member.solved.get(&(d, 2))) {
+                (Some(one), Some(two)) => span(two - one),
+                _ => String::new(),
+            };
+            let line = format!("{d:>5}  {:<32}{:<32}{between}", star(1), star(2));
+            let _ = writeln!(text, "{}", line.trim_end());
+        }
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = include_str!("../tests/fixtures/leaderboard.json");
+    const STRINGS: &str = include_str!("../tests/fixtures/leaderboard-strings.json");
+
+    #[test]
+    fn parses_an_export() {
+        let board = parse(EXPORT).unwrap();
+
+        assert_eq!((board.year, board.owner), (2015, 101));
+        let names: Vec<&str> = board.members.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["Alice", "Bob", "(anonymous user #303)", "Carol"]);
+
+        let alice = &board.members[0];
+        assert_eq!((alice.stars, alice.local_score), (3, 11));
+        assert_eq!(alice.solved, BTreeMap::from([((1, 1), 1448946800), ((1, 2), 1448947200), ((2, 1), 1449032700)]));
+        assert!(board.members[3].solved.is_empty());
+    }
+
+    #[test]
+    fn parses_ids_and_timestamps_written_as_strings() {
+        let board = parse(STRINGS).unwrap();
+
+        assert_eq!((board.year, board.owner), (2016, 7));
+        assert_eq!(board.members[0].id, 7);
+        assert_eq!(board.members[0].solved, BTreeMap::from([((1, 1), 1480571000), ((1, 2), 1480572000)]));
+    }
+
+    #[test]
+    fn rejects_what_isnt_an_export() {
+        assert!(parse("[]").unwrap_err().starts_with("This isn't a leaderboard export"));
+        assert_eq!(
+            parse(&EXPORT.replace("\"event\": \"2015\"", "\"event\": \"AoC\"")).unwrap_err(),
+            "The event should be a year, not \"AoC\"",
+        );
+        assert_eq!(
+            parse(&EXPORT.replace("\"2\": {\n          \"1\"", "\"26\": {\n          \"1\"")).unwrap_err(),
+            "Member 101 has stars for day \"26\", which isn't a day of the month",
+        );
+    }
+
+    #[test]
+    fn unlocks_at_midnight_eastern() {
+        assert_eq!(unlock(2015, 1), 1448946000);
+        assert_eq!(utc(unlock(2016, 25)), "12-25 05:00:00");
+        assert_eq!(span(800), "0:13:20");
+        assert_eq!(span(154000), "1d 18:46:40");
+    }
+
+    #[test]
+    fn recomputes_the_local_scores() {
+        let board = parse(EXPORT).unwrap();
+
+        // The standard rule gives back the scores in the export
+        let standard = scores(&board, Scoring::Standard);
+        for member in &board.members {
+            assert_eq!(standard[&member.id], member.local_score, "{}", member.name);
+        }
+
+        let score = |scoring| {
+            let scores = scores(&board, scoring);
+            board.members.iter().map(|m| scores[&m.id]).collect::<Vec<i64>>()
+        };
+        assert_eq!(score(Scoring::Solvers), [5, 4, 1, 0]);
+        assert_eq!(score(Scoring::Stars), [3, 2, 1, 0]);
+        assert_eq!(score(Scoring::Speed), [72, 47, 0, 0]);
+    }
+
+    #[test]
+    fn leaves_out_days_that_were_not_scored() {
+        let board = parse(&EXPORT.replace("\"event\": \"2015\"", "\"event\": \"2020\"")).unwrap();
+        let scores = scores(&board, Scoring::Standard);
+
+        // Only Alice's day 2 star counts
+        assert_eq!(scores[&101], 4);
+        assert_eq!(scores[&202], 0);
+    }
+
+    #[test]
+    fn reports_the_standings_and_each_star() {
+        let report = report(&parse(EXPORT).unwrap(), Scoring::Solvers, None);
+        let lines: Vec<&str> = report.lines().collect();
+
+        assert_eq!(lines[0], "2015 private leaderboard, 4 members, ordered by solvers score");
+        assert_eq!(lines[2], "      member                   stars   local  standard   solvers     stars     speed");
+        assert_eq!(lines[3], "  1)  Alice                        3      11        11         5         3        72");
+        assert_eq!(lines[4], "  2)  Bob                          2       7         7         4         2        47");
+        assert_eq!(lines[6], "  4)  Carol                        0       0         0         0         0         0");
+        assert_eq!(lines[8], "Alice");
+        assert_eq!(lines[10], "    1  12-01 05:13:20 (+0:13:20)       12-01 05:20:00 (+0:20:00)       0:06:40");
+        assert_eq!(lines[11], "    2  12-02 05:05:00 (+0:05:00)");
+        assert!(report.contains("\n    1  12-02 23:46:40 (+1d 18:46:40)\n"));
+        assert!(!report.contains("\nCarol\n"));
+    }
+}
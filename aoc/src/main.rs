@@ -0,0 +1,726 @@
+mod exec;
+mod external;
+mod leaderboard;
+mod registry;
+mod report;
+mod serve;
+mod term;
+mod tui;
+mod visual;
+mod watch;
+
+use clap::{Args as ClapArgs, Parser, Subcommand};
+use exec::{Limits, Outcome, Status};
+use registry::{Part, Solver};
+use shared::{generate, snapshot};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::{self, IsTerminal},
+    net::TcpListener,
+    path::{Path, PathBuf},
+    process,
+    thread,
+    time::{Duration, Instant},
+};
+use watch::Watcher;
+
+// How often watch checks for changes
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+// How long to wait after a change for the rest of a save to land, so an editor
+// writing several files only triggers one rebuild
+const WATCH_SETTLE: Duration = Duration::from_millis(200);
+
+// How long a client of serve has to send its whole request
+const SERVE_READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+// How many requests serve handles at once
+const SERVE_MAX_CONNECTIONS: usize = 16;
+
+/// Advent Of Code runner
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List every solver the runner knows about
+    List,
+
+    /// Run the solvers for a day and show their output
+    Run {
+        year: u16,
+        day: u8,
+
+        /// Only run this part
+        #[arg(short, long)]
+        part: Option<u8>,
+
+        /// Input to run on: real, example, or the path to a file
+        #[arg(short, long, default_value = "real")]
+        input: String,
+
+        /// Only parse the input and list any lines that can't be used
+        #[arg(long)]
+        check: bool,
+
+        /// Skip lines that can't be used instead of stopping
+        #[arg(long)]
+        lenient: bool,
+
+        #[command(flatten)]
+        limits: LimitArgs,
+    },
+
+    /// Run every solver for a year on its real input and report the results
+    Verify {
+        year: u16,
+
+        /// Only verify this day
+        day: Option<u8>,
+
+        #[command(flatten)]
+        limits: LimitArgs,
+    },
+
+    /// Write a random input for a day
+    Gen {
+        year: u16,
+        day: u8,
+
+        /// How big an input to make. What this counts depends on the day.
+        #[arg(short, long)]
+        size: Option<usize>,
+
+        /// Seed for the random numbers. The same seed and size always give the
+        /// same input.
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+
+        /// File to write to, instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Accept the new output from failed snapshot tests as their snapshots
+    Accept,
+
+    /// Rebuild and rerun a day whenever its source or inputs change
+    Watch {
+        year: u16,
+        day: u8,
+
+        /// Input to run on: real, example, or the path to a file. Uses the
+        /// example while there is no real input.
+        #[arg(short, long, default_value = "real")]
+        input: String,
+
+        #[command(flatten)]
+        limits: LimitArgs,
+    },
+
+    /// Serve the solvers over HTTP on localhost
+    Serve {
+        /// Port to listen on
+        #[arg(long, default_value_t = 3025)]
+        port: u16,
+
+        /// Largest input accepted, in kilobytes
+        #[arg(long, default_value_t = 1024)]
+        max_input: usize,
+
+        #[command(flatten)]
+        limits: LimitArgs,
+    },
+
+    /// Write a static HTML site with every day's puzzle, source, timings and
+    /// verification status
+    Report {
+        /// Directory to write the site into
+        #[arg(long)]
+        html: PathBuf,
+
+        /// Times to run each solver, keeping the best time
+        #[arg(long, default_value_t = 3)]
+        runs: usize,
+
+        #[command(flatten)]
+        limits: LimitArgs,
+    },
+
+    /// Analyse a private leaderboard from its saved JSON export
+    Leaderboard {
+        /// The JSON export, saved from the leaderboard's [API] link
+        file: PathBuf,
+
+        /// Rule to order the standings by
+        #[arg(long, value_enum, default_value_t = leaderboard::Scoring::Standard)]
+        scoring: leaderboard::Scoring,
+
+        /// Only show the stars for this day
+        #[arg(long)]
+        day: Option<u8>,
+    },
+
+    /// Browse the days and run them in a terminal dashboard
+    Tui {
+        #[command(flatten)]
+        limits: LimitArgs,
+    },
+}
+
+#[derive(ClapArgs, Debug)]
+struct LimitArgs {
+    /// Seconds a solver may run before it is killed, 0 for no limit
+    #[arg(long, default_value_t = 60)]
+    timeout: u64,
+
+    /// Megabytes of memory a solver may use, 0 for no limit
+    #[arg(long, default_value_t = 4096)]
+    memory: u64,
+}
+
+impl LimitArgs {
+    fn limits(&self) -> Limits {
+        Limits {
+            timeout: (self.timeout > 0).then(|| Duration::from_secs(self.timeout)),
+            memory: (self.memory > 0).then(|| self.memory * 1024 * 1024),
+        }
+    }
+}
+
+fn main() {
+    let args = Args::parse();
+    let root = exec::workspace_root();
+    for problem in registry::problems() {
+        eprintln!("{problem}");
+    }
+
+    let ok = match args.command {
+        Command::List => {
+            list(&root);
+            true
+        },
+        Command::Run { year, day, part, input, check, lenient, limits } => {
+            let flags: Vec<&str> = [(check, "--check"), (lenient, "--lenient")].into_iter()
+                .filter_map(|(on, flag)| on.then_some(flag))
+                .collect();
+            run(&root, year, day, part, &input, &flags, limits.limits())
+        },
+        Command::Verify { year, day, limits } => verify(&root, year, day, limits.limits()),
+        Command::Gen { year, day, size, seed, output } => gen_input(year, day, size, seed, output.as_deref()),
+        Command::Accept => accept_snapshots(&root),
+        Command::Watch { year, day, input, limits } => watch(&root, year, day, &input, limits.limits()),
+        Command::Serve { port, max_input, limits } => serve(&root, port, max_input * 1024, limits.limits()),
+        Command::Report { html, runs, limits } => report(&root, &html, runs.max(1), limits.limits()),
+        Command::Leaderboard { file, scoring, day } => show_leaderboard(&file, scoring, day),
+        Command::Tui { limits } => tui::run(&root, limits.limits()),
+    };
+
+    if !ok {
+        process::exit(1);
+    }
+}
+
+fn list(root: &Path) {
+    println!("{:<6}{:<5}{:<6}{:<22}inputs", "year", "day", "part", "package");
+    for solver in registry::all() {
+        let dir = solver.day_dir(root);
+        let inputs: Vec<&str> = ["example", "real"].into_iter()
+            .filter(|name| dir.join(format!("{name}-input.txt")).is_file())
+            .collect();
+
+        println!("{:<6}{:<5}{:<6}{:<22}{}", solver.year, solver.day, solver.part.to_string(), solver.package, inputs.join(", "));
+    }
+}
+
+// flags are passed on to every solver that runs
+fn run(root: &Path, year: u16, day: u8, part: Option<u8>, input: &str, flags: &[&str], limits: Limits) -> bool {
+    let solvers: Vec<&Solver> = registry::select(year, Some(day)).into_iter()
+        .filter(|s| part.is_none_or(|p| s.part.covers(p)))
+        .collect();
+    if solvers.is_empty() {
+        eprintln!("There is no solver for {year} day {day}{}", part.map(|p| format!(" part {p}")).unwrap_or_default());
+        return false;
+    }
+
+    let input = match input {
+        "real" | "example" => solvers[0].day_dir(root).join(format!("{input}-input.txt")),
+        path => PathBuf::from(path),
+    };
+    if !input.is_file() {
+        eprintln!("The input file {} doesn't exist", input.display());
+        return false;
+    }
+
+    if let Err(err) = build_solvers(root, &solvers) {
+        eprintln!("{err}");
+        return false;
+    }
+
+    let mut ok = true;
+    for solver in solvers {
+        let shown = part.map(|p| p.to_string()).unwrap_or_else(|| solver.part.to_string());
+        let language = solver.external.as_ref().map(|external| format!(" in {}", external.language)).unwrap_or_default();
+        println!("== {} day {} part {shown}{language}", solver.year, solver.day);
+        // A solver that covers both parts is told which one was asked for
+        let mut extra: Vec<String> = flags.iter().map(|flag| flag.to_string()).collect();
+        if let Some(part) = part
+            && solver.part == Part::Both
+        {
+            extra.extend(["--part".to_string(), part.to_string()]);
+        }
+        match run_solver(root, solver, &input, &extra, limits) {
+            Ok(outcome) => {
+                print!("{}", outcome.stdout);
+                eprint!("{}", outcome.stderr);
+                if outcome.status != Status::Ok {
+                    println!("{} after {:.2}s{}", outcome.status, outcome.elapsed.as_secs_f64(), failure_detail(&outcome.status));
+                    ok = false;
+                }
+            },
+            Err(err) => {
+                eprintln!("{err}");
+                ok = false;
+            },
+        }
+    }
+
+    ok
+}
+
+fn verify(root: &Path, year: u16, day: Option<u8>, limits: Limits) -> bool {
+    let solvers = registry::select(year, day);
+    if solvers.is_empty() {
+        eprintln!("There are no solvers for {year}");
+        return false;
+    }
+
+    if let Err(err) = build_solvers(root, &solvers) {
+        eprintln!("{err}");
+        return false;
+    }
+
+    let mut failures: usize = 0;
+    let mut ran: Vec<(&Solver, Outcome)> = Vec::new();
+    for solver in &solvers {
+        let label = format!("{} {:02} part {:<3}", solver.year, solver.day, solver.part.to_string());
+
+        let dir = solver.day_dir(root);
+        let input = dir.join("real-input.txt");
+        if !input.is_file() {
+            println!("{label}  {:<8}", "NO INPUT");
+            continue;
+        }
+
+        let mut outcome = match run_solver(root, solver, &input, &[], limits) {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                println!("{label}  {:<8}{err}", "FAIL");
+                failures += 1;
+                continue;
+            }
+        };
+
+        check_answers(&load_answers(&dir.join("answers.txt")), solver, &mut outcome);
+
+        let mut summary = match &outcome.status {
+            Status::Ok => last_line(&outcome.stdout).to_string(),
+            status => failure_detail(status).trim_start_matches(": ").to_string(),
+        };
+        if let Some(external) = &solver.external {
+            summary = format!("[{}] {summary}", external.language);
+        }
+        println!("{label}  {:<8}{:>8.2}s  {summary}", outcome.status.to_string(), outcome.elapsed.as_secs_f64());
+
+        if outcome.status != Status::Ok {
+            failures += 1;
+        }
+        ran.push((solver, outcome));
+    }
+
+    // Solutions in different languages have to agree, answers.txt or not
+    let ran: Vec<(&Solver, &Outcome)> = ran.iter().map(|(solver, outcome)| (*solver, outcome)).collect();
+    for disagreement in external::disagreements(&ran) {
+        println!("{disagreement}");
+        failures += 1;
+    }
+
+    if failures > 0 {
+        println!("{failures} of {} solvers failed", solvers.len());
+    }
+
+    failures == 0
+}
+
+fn report(root: &Path, out: &Path, runs: usize, limits: Limits) -> bool {
+    let solvers: Vec<&Solver> = registry::all().iter().collect();
+    if let Err(err) = build_solvers(root, &solvers) {
+        eprintln!("{err}");
+        return false;
+    }
+
+    let mut days: Vec<report::Day> = Vec::new();
+    for solver in solvers {
+        let label = format!("{} {:02} part {:<3}", solver.year, solver.day, solver.part.to_string());
+        let measured = measure(root, solver, runs, limits);
+        let time = measured.best.map(|best| format!("{:.2}s", best.as_secs_f64())).unwrap_or_default();
+        println!("{label}  {:<10}{time:>8}  {}", measured.verdict.label(), measured.detail);
+
+        match days.last_mut() {
+            Some(day) if (day.year, day.day) == (solver.year, solver.day) => day.add(root, solver, measured),
+            _ => days.push(report::Day::load(root, solver, measured)),
+        }
+    }
+
+    match report::write_site(out, &days, runs) {
+        Ok(pages) => {
+            println!("Wrote {pages} pages, open {}", out.join("index.html").display());
+            true
+        },
+        Err(err) => {
+            eprintln!("Unable to write the report to {}: {err}", out.display());
+            false
+        },
+    }
+}
+
+// Run the solver on the real input up to runs times, stopping at the first
+// failure, and check its answers
+fn measure(root: &Path, solver: &Solver, runs: usize, limits: Limits) -> report::Measured {
+    let dir = solver.day_dir(root);
+    let input = dir.join("real-input.txt");
+    let mut measured = report::Measured {
+        solution: solver.package,
+        part: solver.part,
+        verdict: report::Verdict::NoInput,
+        detail: String::new(),
+        best: None,
+        peak_memory: None,
+        verified: Vec::new(),
+    };
+    if !input.is_file() {
+        return measured;
+    }
+
+    let answers = load_answers(&dir.join("answers.txt"));
+    for _ in 0..runs {
+        let mut outcome = match run_solver(root, solver, &input, &[], limits) {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                measured.verdict = report::Verdict::Failed;
+                measured.detail = err;
+                break;
+            },
+        };
+
+        measured.best = Some(measured.best.map_or(outcome.elapsed, |best| best.min(outcome.elapsed)));
+        measured.peak_memory = measured.peak_memory.max(outcome.peak_memory);
+        measured.verified = check_answers(&answers, solver, &mut outcome);
+        measured.verdict = match &outcome.status {
+            Status::Ok if measured.verified.len() == solver.part.numbers().len() => report::Verdict::Verified,
+            Status::Ok => report::Verdict::Unchecked,
+            Status::Failed(reason) if reason.starts_with("expected") => report::Verdict::Wrong,
+            _ => report::Verdict::Failed,
+        };
+        measured.detail = match &outcome.status {
+            Status::Ok => last_line(&outcome.stdout).to_string(),
+            status => format!("{status}{}", failure_detail(status)),
+        };
+        if outcome.status != Status::Ok {
+            break;
+        }
+    }
+
+    measured
+}
+
+fn show_leaderboard(file: &Path, scoring: leaderboard::Scoring, day: Option<u8>) -> bool {
+    let board = match fs::read_to_string(file) {
+        Ok(json) => leaderboard::parse(&json),
+        Err(err) => Err(format!("Unable to read {}: {err}", file.display())),
+    };
+
+    match board {
+        Ok(board) => {
+            print!("{}", leaderboard::report(&board, scoring, day));
+            true
+        },
+        Err(err) => {
+            eprintln!("{err}");
+            false
+        },
+    }
+}
+
+fn gen_input(year: u16, day: u8, size: Option<usize>, seed: u64, output: Option<&Path>) -> bool {
+    let Some(generator) = generate::find(year, day) else {
+        eprintln!("There is no input generator for {year} day {day}");
+        return false;
+    };
+
+    let size = size.unwrap_or(generator.default_size);
+    let input = generator.generate(size, seed);
+
+    match output {
+        Some(path) => match fs::write(path, input) {
+            Ok(()) => {
+                eprintln!("Wrote {year} day {day} input with {size} {} (seed {seed}) to {}", generator.size, path.display());
+                true
+            },
+            Err(err) => {
+                eprintln!("Unable to write {}: {err}", path.display());
+                false
+            },
+        },
+        None => {
+            print!("{input}");
+            true
+        },
+    }
+}
+
+// Move every pending snapshot in the workspace over the snapshot it was
+// compared against
+fn accept_snapshots(root: &Path) -> bool {
+    let pending = snapshot::pending(root);
+    if pending.is_empty() {
+        println!("There are no new snapshots to accept");
+        return true;
+    }
+
+    let mut ok = true;
+    for path in pending {
+        match snapshot::accept(&path) {
+            Ok(accepted) => println!("Accepted {}", accepted.strip_prefix(root).unwrap_or(&accepted).display()),
+            Err(err) => {
+                eprintln!("Unable to accept {}: {err}", path.display());
+                ok = false;
+            },
+        }
+    }
+
+    ok
+}
+
+fn watch(root: &Path, year: u16, day: u8, input: &str, limits: Limits) -> bool {
+    let solvers = registry::select(year, Some(day));
+    if solvers.is_empty() {
+        eprintln!("There is no solver for {year} day {day}");
+        return false;
+    }
+
+    let dir = solvers[0].day_dir(root);
+    let mut paths: Vec<PathBuf> = solvers.iter().map(|s| dir.join(s.package)).collect();
+    paths.dedup();
+    paths.push(dir.join("example-input.txt"));
+    paths.push(dir.join("real-input.txt"));
+    if !matches!(input, "real" | "example") {
+        paths.push(PathBuf::from(input));
+    }
+
+    let color = io::stdout().is_terminal();
+    let mut watcher = Watcher::new(paths);
+    let mut previous: HashMap<usize, String> = HashMap::new();
+    loop {
+        watch_run(root, &solvers, input, limits, color, &mut previous);
+        println!("Watching for changes, Ctrl-C to stop");
+
+        loop {
+            thread::sleep(WATCH_INTERVAL);
+            let mut changed = watcher.poll();
+            if !changed.is_empty() {
+                thread::sleep(WATCH_SETTLE);
+                changed.extend(watcher.poll());
+                for path in changed {
+                    println!("Changed: {}", path.strip_prefix(root).unwrap_or(&path).display());
+                }
+                break;
+            }
+        }
+    }
+}
+
+// Build the solvers that run as child processes, then answer requests until
+// the server fails
+fn serve(root: &Path, port: u16, max_input: usize, limits: Limits) -> bool {
+    let solvers: Vec<&Solver> = registry::all().iter().collect();
+    let children: Vec<&Solver> = solvers.iter().copied().filter(|solver| solver.solution.is_none()).collect();
+    if let Err(err) = build_solvers(root, &children) {
+        eprintln!("{err}");
+        return false;
+    }
+
+    let listener = match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("Unable to listen on port {port}: {err}");
+            return false;
+        },
+    };
+    println!("Serving {} solvers on http://127.0.0.1:{port}, Ctrl-C to stop", solvers.len());
+
+    let config = serve::Config {
+        root: root.to_path_buf(),
+        max_input,
+        read_timeout: SERVE_READ_TIMEOUT,
+        max_connections: SERVE_MAX_CONNECTIONS,
+    };
+    let run = |solver: &Solver, input: &Path, extra: &[String]| run_solver(root, solver, input, extra, limits);
+    if let Err(err) = serve::serve(&listener, &config, &run) {
+        eprintln!("The server stopped: {err}");
+    }
+
+    false
+}
+
+// One round of watch: rebuild, run every solver for the day and print the
+// answers, along with the previous answer wherever it changed
+fn watch_run(root: &Path, solvers: &[&Solver], input: &str, limits: Limits, color: bool, previous: &mut HashMap<usize, String>) {
+    let dir = solvers[0].day_dir(root);
+    let input = match input {
+        "real" if !dir.join("real-input.txt").is_file() => dir.join("example-input.txt"),
+        "real" | "example" => dir.join(format!("{input}-input.txt")),
+        path => PathBuf::from(path),
+    };
+
+    let start = Instant::now();
+    if let Err(err) = build_solvers(root, solvers) {
+        eprintln!("{err}");
+        return;
+    }
+    println!("== Built in {:.2}s, running on {}", start.elapsed().as_secs_f64(), input.strip_prefix(root).unwrap_or(&input).display());
+
+    for (i, solver) in solvers.iter().enumerate() {
+        let answer = match run_solver(root, solver, &input, &[], limits) {
+            Ok(outcome) if outcome.status == Status::Ok => last_line(&outcome.stdout).to_string(),
+            Ok(outcome) => {
+                eprint!("{}", outcome.stderr);
+                format!("{}{}", outcome.status, failure_detail(&outcome.status))
+            },
+            Err(err) => err,
+        };
+
+        let label = format!("part {:<3}", solver.part.to_string());
+        match previous.get(&i) {
+            Some(old) if *old != answer => {
+                if color {
+                    println!("{label}  \x1B[1;33m{answer}\x1B[0m  (was: {old})");
+                } else {
+                    println!("{label}  {answer}  (was: {old})");
+                }
+            },
+            _ => println!("{label}  {answer}"),
+        }
+
+        previous.insert(i, answer);
+    }
+}
+
+// Build each package once, even if several solvers share it
+fn build_solvers(root: &Path, solvers: &[&Solver]) -> Result<(), String> {
+    let packages = cargo_packages(solvers);
+    if packages.is_empty() {
+        return Ok(());
+    }
+
+    exec::build(root, &packages)
+}
+
+// The Cargo packages among the solvers, once each. External solutions aren't
+// built by the runner.
+fn cargo_packages(solvers: &[&Solver]) -> Vec<&'static str> {
+    let mut packages: Vec<&str> = solvers.iter()
+        .filter(|s| s.external.is_none())
+        .map(|s| s.package)
+        .collect();
+    packages.dedup();
+
+    packages
+}
+
+// extra arguments are added after the ones the solver is registered with
+fn run_solver(root: &Path, solver: &Solver, input: &Path, extra: &[String], limits: Limits) -> Result<Outcome, String> {
+    run_solver_streaming(root, solver, input, extra, limits, &|_| {})
+}
+
+// Run like run_solver, passing each line of output to on_line as it's printed
+fn run_solver_streaming(root: &Path, solver: &Solver, input: &Path, extra: &[String], limits: Limits, on_line: &(dyn Fn(&str) + Sync)) -> Result<Outcome, String> {
+    if let Some(external) = &solver.external {
+        return external::run(solver, external, input, extra, limits, on_line);
+    }
+
+    let mut args = solver.command_args(input)?;
+    args.extend_from_slice(extra);
+    // Ask for the answers on lines of their own, and tell the solvers that run
+    // one part at a time which part they're solving
+    args.push("--answers".to_string());
+    if let [part] = solver.part.numbers() {
+        args.extend(["--part".to_string(), part.to_string()]);
+    }
+    let binary = exec::binary_path(root, solver.package);
+
+    exec::run_streaming(&binary, &args, limits, on_line)
+}
+
+fn failure_detail(status: &Status) -> String {
+    match status {
+        Status::Failed(reason) => format!(": {reason}"),
+        Status::Timeout => ": killed after running out of time".to_string(),
+        Status::OutOfMemory => ": went over the memory limit".to_string(),
+        Status::Ok => String::new(),
+    }
+}
+
+// Check the output against the recorded answers, if there are any, failing the
+// outcome on a wrong one. Returns the parts whose answers were found.
+fn check_answers(answers: &HashMap<u8, String>, solver: &Solver, outcome: &mut Outcome) -> Vec<u8> {
+    let mut verified: Vec<u8> = Vec::new();
+    if outcome.status != Status::Ok {
+        return verified;
+    }
+
+    let found = answers_in(solver, &outcome.stdout);
+    for part in solver.part.numbers() {
+        let Some(expected) = answers.get(part) else { continue; };
+        match found.get(part) {
+            Some(answer) if answer == expected => verified.push(*part),
+            Some(answer) => outcome.status = Status::Failed(format!("expected {expected} for part {part}, got {answer}")),
+            None => outcome.status = Status::Failed(format!("expected {expected} for part {part}, got no answer")),
+        }
+    }
+
+    verified
+}
+
+// Recorded answers for a day, one "<part>: <answer>" per line. Like the real
+// inputs these are personal, so they aren't checked in.
+fn load_answers(path: &Path) -> HashMap<u8, String> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+
+    contents.lines()
+        .filter_map(|line| line.split_once(':'))
+        .filter_map(|(part, answer)| Some((part.trim().parse::<u8>().ok()?, answer.trim().to_string())))
+        .collect()
+}
+
+// The answers to the parts the solver covers, from the "Part <n>: <answer>"
+// lines in its output. Rust solvers print these when run with --answers, and
+// external ones have theirs rendered that way.
+fn answers_in(solver: &Solver, output: &str) -> BTreeMap<u8, String> {
+    output.lines()
+        .filter_map(|line| line.strip_prefix("Part ")?.split_once(": "))
+        .filter_map(|(part, answer)| Some((part.parse::<u8>().ok()?, answer.trim().to_string())))
+        .filter(|(part, _)| solver.part.covers(*part))
+        .collect()
+}
+
+fn last_line(output: &str) -> &str {
+    output.lines().rev().find(|line| !line.trim().is_empty()).unwrap_or("")
+}
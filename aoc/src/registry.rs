@@ -0,0 +1,195 @@
+// The table of every solver the runner knows about, and how to call each one.
+//
+// The days don't share a command line, so each entry lists the arguments to
+// pass with placeholders for the input. Most days take the input file, but a
+// few take numbers out of the puzzle input instead (day 20's target, day 25's
+// row and column).
+//
+// Days whose work is set by the size of their input also carry their Solution,
+// so aoc serve can answer them in process. The rest (searches, sieves and
+// simulations that the numbers in the input can make as long or as large as
+// they like) are always run as a child, where the time and memory limits can
+// stop them.
+//
+// Solutions in other languages aren't in the table. They are found at startup
+// from their manifests (see external) and listed after the Rust solver for
+// the same day.
+use crate::{
+    exec,
+    external::{self, External},
+};
+use shared::solution::Solve;
+use std::{
+    fmt,
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    // Solvers that print both answers in one run
+    Both,
+}
+
+impl Part {
+    // Whether running this solver gives the answer to the given part
+    pub fn covers(&self, part: u8) -> bool {
+        matches!((self, part), (Part::One, 1) | (Part::Two, 2) | (Part::Both, 1 | 2))
+    }
+
+    pub fn numbers(&self) -> &'static [u8] {
+        match self {
+            Part::One => &[1],
+            Part::Two => &[2],
+            Part::Both => &[1, 2],
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+            Part::Both => write!(f, "1+2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Arg {
+    // Passed as is
+    Lit(&'static str),
+    // The path to the input file
+    Input,
+    // The nth unsigned number found in the input file
+    Number(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    // The Cargo package, or the directory of an external solution
+    pub package: &'static str,
+    pub args: &'static [Arg],
+    // How to run a solution written in another language
+    pub external: Option<External>,
+    // The solution itself, for the days that are safe to run in process
+    pub solution: Option<&'static dyn Solve>,
+}
+
+use Arg::{Input, Lit, Number};
+
+const fn solver(year: u16, day: u8, part: Part, package: &'static str, args: &'static [Arg]) -> Solver {
+    Solver { year, day, part, package, args, external: None, solution: None }
+}
+
+const fn in_process(year: u16, day: u8, part: Part, package: &'static str, args: &'static [Arg], solution: &'static dyn Solve) -> Solver {
+    Solver { year, day, part, package, args, external: None, solution: Some(solution) }
+}
+
+static SOLVERS: &[Solver] = &[
+    in_process(2015, 1, Part::Both, "aoc-2015-01-part1-2", &[Input], &aoc_2015_01_part1_2::Day01),
+    in_process(2015, 2, Part::Both, "aoc-2015-02-part1-2", &[Input], &aoc_2015_02_part1_2::Day02),
+    in_process(2015, 3, Part::Both, "aoc-2015-03-part1-2", &[Input], &aoc_2015_03_part1_2::Day03),
+    solver(2015, 4, Part::Both, "aoc-2015-04-part1-2", &[Input]),
+    in_process(2015, 5, Part::Both, "aoc-2015-05-part1-2", &[Input], &aoc_2015_05_part1_2::Day05),
+    solver(2015, 6, Part::Both, "aoc-2015-06-part1-2", &[Input]),
+    solver(2015, 7, Part::Both, "aoc-2015-07-part1-2", &[Input]),
+    in_process(2015, 8, Part::Both, "aoc-2015-08-part1-2", &[Input], &aoc_2015_08_part1_2::Day08),
+    solver(2015, 9, Part::Both, "aoc-2015-09-part1-2", &[Input]),
+    solver(2015, 10, Part::Both, "aoc-2015-10-part1-2", &[Input]),
+    solver(2015, 11, Part::Both, "aoc-2015-11-part1-2", &[Input]),
+    in_process(2015, 12, Part::Both, "aoc-2015-12-part1-2", &[Input], &aoc_2015_12_part1_2::Day12 { debug: false }),
+    solver(2015, 13, Part::Both, "aoc-2015-13-part1-2", &[Input]),
+    in_process(2015, 14, Part::Both, "aoc-2015-14-part1-2", &[Input], &aoc_2015_14_part1_2::Day14 { debug: false, seconds: aoc_2015_14_part1_2::RACE_SECONDS }),
+    solver(2015, 15, Part::Both, "aoc-2015-15-part1-2", &[Input]),
+    in_process(2015, 16, Part::Both, "aoc-2015-16-part1-2", &[Input], &aoc_2015_16_part1_2::Day16 { debug: false }),
+    in_process(2015, 17, Part::Both, "aoc-2015-17-part1-2", &[Input], &aoc_2015_17_part1_2::Day17 { debug: false, max: aoc_2015_17_part1_2::EGGNOG }),
+    solver(2015, 18, Part::Both, "aoc-2015-18-part1-2", &[Input]),
+    solver(2015, 19, Part::Both, "aoc-2015-19-part1-2", &[Input]),
+    solver(2015, 20, Part::Both, "aoc-2015-20-part1-2", &[Lit("-i"), Number(0)]),
+    in_process(2015, 21, Part::Both, "aoc-2015-21-part1-2", &[Lit("-f"), Input], &aoc_2015_21_part1_2::Day21 { debug: false }),
+    solver(2015, 22, Part::Both, "aoc-2015-22-part1-2", &[Lit("-f"), Input]),
+    in_process(2015, 23, Part::Both, "aoc-2015-23-part1", &[Lit("-f"), Input], &aoc_2015_23_part1::Day23 { debug: false }),
+    solver(2015, 24, Part::Both, "aoc-2015-24-part1", &[Lit("-f"), Input]),
+    in_process(2015, 25, Part::One, "aoc-2015-25-part1", &[Lit("-r"), Number(0), Lit("-c"), Number(1)], &aoc_2015_25_part1::Day25 { debug: false }),
+    solver(2016, 1, Part::Both, "aoc-2016-01-part1", &[Lit("-f"), Input]),
+];
+
+// Every solver, Rust and external, by year and day
+pub fn all() -> &'static [Solver] {
+    &loaded().0
+}
+
+// Why any external solutions were left out
+pub fn problems() -> &'static [String] {
+    &loaded().1
+}
+
+fn loaded() -> &'static (Vec<Solver>, Vec<String>) {
+    static LOADED: OnceLock<(Vec<Solver>, Vec<String>)> = OnceLock::new();
+
+    LOADED.get_or_init(|| {
+        let (external, problems) = external::discover(&exec::workspace_root());
+        let mut solvers: Vec<Solver> = SOLVERS.to_vec();
+        solvers.extend(external);
+        // Stable, so each day's Rust solvers stay first
+        solvers.sort_by_key(|solver| (solver.year, solver.day));
+
+        (solvers, problems)
+    })
+}
+
+// Every solver for the year, or for a single day of it
+pub fn select(year: u16, day: Option<u8>) -> Vec<&'static Solver> {
+    all().iter()
+        .filter(|s| s.year == year && day.is_none_or(|d| s.day == d))
+        .collect()
+}
+
+impl Solver {
+    // The directory holding the day's inputs, e.g. 2015/07
+    pub fn day_dir(&self, root: &Path) -> PathBuf {
+        root.join(self.year.to_string()).join(format!("{:02}", self.day))
+    }
+
+    // The command line arguments for running on the given input file
+    pub fn command_args(&self, input: &Path) -> Result<Vec<String>, String> {
+        let mut numbers: Option<Vec<String>> = None;
+
+        let mut args: Vec<String> = Vec::new();
+        for arg in self.args {
+            match arg {
+                Lit(s) => args.push(s.to_string()),
+                Input => args.push(input.display().to_string()),
+                Number(n) => {
+                    if numbers.is_none() {
+                        let contents = fs::read_to_string(input)
+                            .map_err(|err| format!("Unable to read {}: {err}", input.display()))?;
+                        numbers = Some(numbers_in(&contents));
+                    }
+                    match numbers.as_ref().and_then(|numbers| numbers.get(*n)) {
+                        Some(number) => args.push(number.clone()),
+                        None => return Err(format!("{} does not contain {} numbers", input.display(), n + 1)),
+                    }
+                },
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+// Every run of digits in the text
+fn numbers_in(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_ascii_digit())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect()
+}
@@ -0,0 +1,400 @@
+// `aoc report`: a static HTML site about every solver.
+//
+// The site has a calendar for each year and a page for every day with the
+// puzzle text from the crate's README, the crate's source, the best time and
+// peak memory of each solver on the real input, and whether its answers match
+// the recorded ones. Pages have their styles inline and only link to each
+// other (and to the puzzles on adventofcode.com), so the site can be opened
+// straight from disk.
+use crate::registry::{Part, Solver};
+use shared::{alloc::human_bytes, stats::human_duration};
+use std::{
+    fmt::Write as _,
+    fs,
+    io,
+    path::Path,
+    time::Duration,
+};
+
+const STYLE: &str = "
+body { font-family: sans-serif; max-width: 60em; margin: 2em auto; padding: 0 1em; background: #0f0f23; color: #ccc; }
+a { color: #090; text-decoration: none; }
+a:hover { color: #9f9; }
+h1, h2, h3 { color: #fff; }
+pre, code { font-family: monospace; background: #10101a; }
+pre { padding: 0.5em; overflow-x: auto; border: 1px solid #333; }
+table { border-collapse: collapse; }
+td, th { padding: 0.2em 0.8em; text-align: left; border-bottom: 1px solid #333; }
+.calendar { display: grid; grid-template-columns: repeat(5, 4em); gap: 0.3em; margin-bottom: 0.5em; }
+.day { display: block; padding: 0.4em; border: 1px solid #333; text-align: right; font-family: monospace; }
+.none { color: #444; }
+.gold { color: #ff0; }
+.silver { color: #9999cc; }
+.bad { color: #f33; }
+.dim { color: #666; }
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    // Every answer matched the recorded one
+    Verified,
+    // Solved, but not every part has a recorded answer to check against
+    Unchecked,
+    // An answer didn't match the recorded one
+    Wrong,
+    // Failed, timed out or ran out of memory
+    Failed,
+    // There is no real input to run on
+    NoInput,
+}
+
+impl Verdict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Verified => "verified",
+            Verdict::Unchecked => "unchecked",
+            Verdict::Wrong => "wrong",
+            Verdict::Failed => "failed",
+            Verdict::NoInput => "no input",
+        }
+    }
+}
+
+// How one solver did on the real input
+pub struct Measured {
+    // The package or external solution that was run
+    pub solution: &'static str,
+    pub part: Part,
+    pub verdict: Verdict,
+    // The answer it printed last, or why it failed
+    pub detail: String,
+    // The fastest of the runs
+    pub best: Option<Duration>,
+    pub peak_memory: Option<u64>,
+    // The parts whose recorded answers were in the output
+    pub verified: Vec<u8>,
+}
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub package: &'static str,
+    // The README up to its usage notes
+    pub puzzle: Option<String>,
+    // The source files of each solution, by path within the day
+    pub sources: Vec<(String, String)>,
+    pub results: Vec<Measured>,
+}
+
+impl Day {
+    // The README and source of the day's first solution, with how it did
+    pub fn load(root: &Path, solver: &Solver, measured: Measured) -> Day {
+        let dir = solver.day_dir(root).join(solver.package);
+        let puzzle = fs::read_to_string(dir.join("README.md")).ok()
+            .map(|readme| readme.split("### Usage").next().unwrap_or_default().trim().to_string());
+
+        Day {
+            year: solver.year,
+            day: solver.day,
+            package: solver.package,
+            puzzle,
+            sources: sources(root, solver),
+            results: vec![measured],
+        }
+    }
+
+    // Add another solver for the day, and its source if it's a new solution
+    pub fn add(&mut self, root: &Path, solver: &Solver, measured: Measured) {
+        if self.results.iter().all(|result| result.solution != solver.package) {
+            self.sources.extend(sources(root, solver));
+        }
+        self.results.push(measured);
+    }
+
+    // Parts with a verified answer, at most two
+    fn stars(&self) -> usize {
+        let mut parts: Vec<u8> = self.results.iter().flat_map(|r| r.verified.iter().copied()).collect();
+        parts.sort();
+        parts.dedup();
+
+        parts.len().min(2)
+    }
+
+    fn failed(&self) -> bool {
+        self.results.iter().any(|r| matches!(r.verdict, Verdict::Wrong | Verdict::Failed))
+    }
+
+    fn page_name(&self) -> String {
+        format!("{}/{:02}.html", self.year, self.day)
+    }
+
+    fn source_name(&self) -> String {
+        format!("{}/{:02}-source.html", self.year, self.day)
+    }
+}
+
+// The crate's manifest and code, or every file of an external solution
+fn sources(root: &Path, solver: &Solver) -> Vec<(String, String)> {
+    let dir = solver.day_dir(root).join(solver.package);
+    let names: Vec<String> = if solver.external.is_some() {
+        let mut names: Vec<String> = fs::read_dir(&dir).into_iter().flatten()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .collect();
+        names.sort();
+        names
+    } else {
+        ["Cargo.toml", "src/lib.rs", "src/main.rs"].map(str::to_string).to_vec()
+    };
+
+    names.into_iter()
+        .filter_map(|name| Some((format!("{}/{name}", solver.package), fs::read_to_string(dir.join(&name)).ok()?)))
+        .collect()
+}
+
+// Write the site into out, with index.html as its front page. runs is how many
+// times each solver was run for its best time. Returns the number of pages.
+pub fn write_site(out: &Path, days: &[Day], runs: usize) -> io::Result<usize> {
+    fs::create_dir_all(out)?;
+    fs::write(out.join("index.html"), index_page(days))?;
+
+    for day in days {
+        fs::create_dir_all(out.join(day.year.to_string()))?;
+        fs::write(out.join(day.page_name()), day_page(day, runs))?;
+        fs::write(out.join(day.source_name()), source_page(day))?;
+    }
+
+    Ok(1 + days.len() * 2)
+}
+
+fn index_page(days: &[Day]) -> String {
+    let mut years: Vec<u16> = days.iter().map(|day| day.year).collect();
+    years.dedup();
+
+    let mut body = String::from("<h1>Advent of Code</h1>\n");
+    for year in years {
+        let days: Vec<&Day> = days.iter().filter(|day| day.year == year).collect();
+        let stars: usize = days.iter().map(|day| day.stars()).sum();
+        let total: Duration = days.iter().flat_map(|day| &day.results).filter_map(|r| r.best).sum();
+
+        let _ = writeln!(body, "<h2>{year}</h2>\n<div class=\"calendar\">");
+        for number in 1..=25 {
+            match days.iter().find(|day| day.day == number) {
+                Some(day) => {
+                    let (stars, class) = match day.stars() {
+                        _ if day.failed() => ("*".repeat(day.stars()), "bad"),
+                        0 => (String::new(), "dim"),
+                        1 => ("*".to_string(), "silver"),
+                        _ => ("**".to_string(), "gold"),
+                    };
+                    let _ = writeln!(body, "<a class=\"day\" href=\"{}\">{number} <span class=\"{class}\">{stars:<2}</span></a>", day.page_name());
+                },
+                None => {
+                    let _ = writeln!(body, "<span class=\"day none\">{number}</span>");
+                },
+            }
+        }
+        let _ = writeln!(body, "</div>\n<p>Stars verified: {stars} &middot; Days: {} &middot; Total time: {}</p>", days.len(), human_duration(total));
+    }
+
+    page("Advent of Code", "", &body)
+}
+
+fn day_page(day: &Day, runs: usize) -> String {
+    let mut body = String::new();
+    let _ = writeln!(body, "<p><a href=\"../index.html\">&larr; All years</a></p>");
+    let _ = writeln!(body, "<h1>{} day {}</h1>", day.year, day.day);
+    let _ = writeln!(
+        body,
+        "<p><a href=\"{:02}-source.html\">Source</a> of {} &middot; <a href=\"https://adventofcode.com/{}/day/{}\">Puzzle on adventofcode.com</a></p>",
+        day.day, day.package, day.year, day.day,
+    );
+
+    let _ = writeln!(body, "<h2>Real input</h2>\n<table>");
+    let best_of = if runs > 1 { format!(" (best of {runs})") } else { String::new() };
+    let _ = writeln!(body, "<tr><th>Solution</th><th>Part</th><th>Status</th><th>Time{best_of}</th><th>Peak memory</th><th>Output</th></tr>");
+    for result in &day.results {
+        let (status, class) = match result.verdict {
+            verdict @ Verdict::Verified => (verdict.label().to_string(), "gold"),
+            Verdict::Unchecked if result.verified.is_empty() => ("no recorded answers".to_string(), "dim"),
+            Verdict::Unchecked => (format!("verified part {}", join(&result.verified)), "silver"),
+            Verdict::Wrong => ("wrong answer".to_string(), "bad"),
+            verdict @ Verdict::Failed => (verdict.label().to_string(), "bad"),
+            verdict @ Verdict::NoInput => (verdict.label().to_string(), "dim"),
+        };
+        let _ = writeln!(
+            body,
+            "<tr><td>{}</td><td>{}</td><td class=\"{class}\">{status}</td><td>{}</td><td>{}</td><td><code>{}</code></td></tr>",
+            result.solution,
+            result.part,
+            result.best.map(human_duration).unwrap_or_default(),
+            result.peak_memory.map(human_bytes).unwrap_or_default(),
+            escape(&result.detail),
+        );
+    }
+    let _ = writeln!(body, "</table>");
+
+    if let Some(puzzle) = &day.puzzle {
+        let _ = writeln!(body, "<h2>Puzzle</h2>\n{}", markdown(puzzle));
+    }
+
+    page(&format!("{} day {}", day.year, day.day), "../", &body)
+}
+
+fn source_page(day: &Day) -> String {
+    let mut body = String::new();
+    let _ = writeln!(body, "<p><a href=\"{:02}.html\">&larr; {} day {}</a></p>", day.day, day.year, day.day);
+    let _ = writeln!(body, "<h1>{} day {} source</h1>", day.year, day.day);
+    for (name, source) in &day.sources {
+        let _ = writeln!(body, "<h2>{name}</h2>\n<pre>{}</pre>", escape(source));
+    }
+
+    page(&format!("{} day {} source", day.year, day.day), "../", &body)
+}
+
+// A whole page. to_root is the relative path back to the top of the site.
+fn page(title: &str, to_root: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<link rel=\"start\" href=\"{to_root}index.html\">\n<style>{STYLE}</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        escape(title),
+    )
+}
+
+fn join(parts: &[u8]) -> String {
+    parts.iter().map(|part| part.to_string()).collect::<Vec<String>>().join(" and ")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// Just enough Markdown for the puzzle texts: paragraphs, headings, the
+// "--- Part Two ---" headers, code blocks and inline code
+fn markdown(text: &str) -> String {
+    let mut html = String::new();
+    let mut paragraph: Vec<String> = Vec::new();
+    let mut code: Option<Vec<&str>> = None;
+
+    let flush = |paragraph: &mut Vec<String>, html: &mut String| {
+        if !paragraph.is_empty() {
+            let _ = writeln!(html, "<p>{}</p>", paragraph.join("<br>\n"));
+            paragraph.clear();
+        }
+    };
+
+    for line in text.lines() {
+        if let Some(lines) = &mut code {
+            if line.starts_with("```") {
+                let _ = writeln!(html, "<pre>{}</pre>", escape(&lines.join("\n")));
+                code = None;
+            } else {
+                lines.push(line);
+            }
+            continue;
+        }
+
+        let trimmed = line.trim();
+        if line.starts_with("```") {
+            flush(&mut paragraph, &mut html);
+            code = Some(Vec::new());
+        } else if trimmed.is_empty() {
+            flush(&mut paragraph, &mut html);
+        } else if let Some(title) = trimmed.strip_prefix("---").and_then(|rest| rest.strip_suffix("---")) {
+            flush(&mut paragraph, &mut html);
+            let _ = writeln!(html, "<h3>{}</h3>", escape(title.trim()));
+        } else if trimmed.starts_with('#') {
+            flush(&mut paragraph, &mut html);
+            let _ = writeln!(html, "<h3>{}</h3>", escape(trimmed.trim_start_matches('#').trim()));
+        } else {
+            paragraph.push(inline_code(trimmed));
+        }
+    }
+    // An unclosed code block runs to the end
+    if let Some(lines) = code {
+        let _ = writeln!(html, "<pre>{}</pre>", escape(&lines.join("\n")));
+    }
+    flush(&mut paragraph, &mut html);
+
+    html
+}
+
+// Escape the line, turning `backticked` parts into code
+fn inline_code(line: &str) -> String {
+    line.split('`').enumerate()
+        .map(|(i, part)| if i % 2 == 1 { format!("<code>{}</code>", escape(part)) } else { escape(part) })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measured(part: Part, verdict: Verdict, verified: &[u8]) -> Measured {
+        Measured {
+            solution: "aoc-2015-07-part1-2",
+            part,
+            verdict,
+            detail: "Wire a is <46065>".to_string(),
+            best: Some(Duration::from_millis(12)),
+            peak_memory: Some(3 << 20),
+            verified: verified.to_vec(),
+        }
+    }
+
+    fn day(day: u8, results: Vec<Measured>) -> Day {
+        Day {
+            year: 2015,
+            day,
+            package: "aoc-2015-07-part1-2",
+            puzzle: Some("Bobby's `x AND y -> z` gates.\n\n--- Part Two ---\n\nOverride wire b.".to_string()),
+            sources: vec![("aoc-2015-07-part1-2/src/lib.rs".to_string(), "fn main() { if a < b {} }".to_string())],
+            results,
+        }
+    }
+
+    #[test]
+    fn renders_the_puzzle_text() {
+        let html = markdown("One\n    two & three\n\n--- Part Two ---\n\n### Usage\n```bash\ncargo run -- \"<input file>\"\n```");
+
+        assert_eq!(
+            html,
+            "<p>One<br>\ntwo &amp; three</p>\n<h3>Part Two</h3>\n<h3>Usage</h3>\n<pre>cargo run -- &quot;&lt;input file&gt;&quot;</pre>\n",
+        );
+        assert_eq!(inline_code("a `<b>` c"), "a <code>&lt;b&gt;</code> c");
+    }
+
+    #[test]
+    fn marks_the_calendar_with_stars() {
+        let days = [
+            day(7, vec![measured(Part::Both, Verdict::Verified, &[1, 2])]),
+            day(8, vec![measured(Part::Both, Verdict::Unchecked, &[1])]),
+            day(9, vec![measured(Part::Both, Verdict::Wrong, &[1])]),
+        ];
+        let html = index_page(&days);
+
+        assert!(html.contains("<a class=\"day\" href=\"2015/07.html\">7 <span class=\"gold\">**</span></a>"));
+        assert!(html.contains("<a class=\"day\" href=\"2015/08.html\">8 <span class=\"silver\">* </span></a>"));
+        assert!(html.contains("<a class=\"day\" href=\"2015/09.html\">9 <span class=\"bad\">* </span></a>"));
+        assert!(html.contains("<span class=\"day none\">10</span>"));
+        assert!(html.contains("Stars verified: 4 &middot; Days: 3 &middot; Total time: 36.00 ms"));
+    }
+
+    #[test]
+    fn writes_a_page_for_every_day() {
+        let out = std::env::temp_dir().join(format!("aoc-report-test-{}", std::process::id()));
+        let days = [day(7, vec![measured(Part::Both, Verdict::Verified, &[1, 2])])];
+
+        assert_eq!(write_site(&out, &days, 3).unwrap(), 3);
+        let page = fs::read_to_string(out.join("2015/07.html")).unwrap();
+        assert!(page.contains("<a href=\"../index.html\">"));
+        assert!(page.contains("<th>Time (best of 3)</th>"));
+        assert!(page.contains("<td>aoc-2015-07-part1-2</td><td>1+2</td><td class=\"gold\">verified</td><td>12.00 ms</td><td>3.0 MiB</td><td><code>Wire a is &lt;46065&gt;</code></td>"));
+        assert!(page.contains("<p>Bobby's <code>x AND y -&gt; z</code> gates.</p>\n<h3>Part Two</h3>"));
+
+        let source = fs::read_to_string(out.join("2015/07-source.html")).unwrap();
+        assert!(source.contains("<pre>fn main() { if a &lt; b {} }</pre>"));
+
+        fs::remove_dir_all(&out).unwrap();
+    }
+}
@@ -0,0 +1,629 @@
+// A small HTTP/JSON server for `aoc serve`, so other tools can call the solvers
+// without shelling out to each binary.
+//
+//   GET  /days                        every day with its parts and inputs
+//   POST /solve/<year>/<day>/<part>   solve the input in the request body
+//
+// Solving goes through the same registry as `aoc run`. Days the registry marks
+// as safe are answered in process from their Solution, without a file or a
+// process per request. The others run as child processes, the same way as
+// `aoc run`, so the runner's timeout and memory limits can stop them. The
+// server only
+// listens on localhost and answers one request per connection. Each request
+// has to arrive in full before a deadline and under a size limit, and only so
+// many connections are handled at once, so slow or oversized uploads are
+// turned away rather than tying up threads. Connections past the limit wait to
+// be accepted until one of the others is done.
+//
+// There is no HTTP library in the dependencies and the protocol needed here is
+// tiny: a request line, headers, and a body with a Content-Length.
+use crate::{
+    answers_in,
+    exec::{Outcome, Status},
+    failure_detail,
+    registry::{self, Part, Solver},
+};
+use serde_json::{Value, json};
+use shared::{
+    AocError,
+    solution::Solve,
+};
+use std::{
+    collections::BTreeMap,
+    env,
+    fs,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::{
+        Condvar,
+        Mutex,
+        PoisonError,
+        atomic::{AtomicU64, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+// The most bytes of request line and headers read before giving up
+const MAX_HEADER: usize = 16 * 1024;
+
+// Numbers the temporary input files, so requests running at the same time
+// don't share one
+static NEXT_INPUT: AtomicU64 = AtomicU64::new(0);
+
+// Runs a solver on an input file with extra arguments and reports how it went
+pub type Run<'a> = dyn Fn(&Solver, &Path, &[String]) -> Result<Outcome, String> + Sync + 'a;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    // Where the year directories live, for listing the inputs of each day
+    pub root: PathBuf,
+    // The largest request body accepted, in bytes
+    pub max_input: usize,
+    // How long a client has to send its whole request
+    pub read_timeout: Duration,
+    // The most connections handled at once
+    pub max_connections: usize,
+}
+
+// A parsed request. Only the parts the routes look at are kept.
+#[derive(Debug)]
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+// How solving an input went, however it was solved
+#[derive(Debug)]
+struct Answered {
+    status: Status,
+    answer: Option<Value>,
+    elapsed: Duration,
+    output: String,
+    diagnostics: Vec<String>,
+}
+
+#[derive(Debug)]
+struct Response {
+    status: u16,
+    body: Value,
+}
+
+impl Response {
+    fn error(status: u16, message: impl Into<String>) -> Response {
+        Response { status, body: json!({ "error": message.into() }) }
+    }
+}
+
+// Accept connections until the listener fails, answering each one on its own
+// thread, up to max_connections at a time
+pub fn serve(listener: &TcpListener, config: &Config, run: &Run<'_>) -> io::Result<()> {
+    let open: Mutex<usize> = Mutex::new(0);
+    let closed = Condvar::new();
+
+    thread::scope(|scope| {
+        for stream in listener.incoming() {
+            let stream = stream?;
+            // A client that won't read its response can't hold a thread either
+            stream.set_write_timeout(Some(config.read_timeout))?;
+
+            let mut count = closed.wait_while(open.lock().unwrap(), |count| *count >= config.max_connections).unwrap();
+            *count += 1;
+            drop(count);
+
+            let slot = Slot { open: &open, closed: &closed };
+            scope.spawn(move || {
+                let _slot = slot;
+                if let Err(err) = handle(stream, config, run) {
+                    eprintln!("Unable to answer a request: {err}");
+                }
+            });
+        }
+        Ok(())
+    })
+}
+
+// A connection being handled. Dropping it frees its place for the next
+// connection, even if answering the request panicked.
+struct Slot<'a> {
+    open: &'a Mutex<usize>,
+    closed: &'a Condvar,
+}
+
+impl Drop for Slot<'_> {
+    fn drop(&mut self) {
+        *self.open.lock().unwrap_or_else(PoisonError::into_inner) -= 1;
+        self.closed.notify_one();
+    }
+}
+
+fn handle(mut stream: TcpStream, config: &Config, run: &Run<'_>) -> io::Result<()> {
+    let deadline = Instant::now() + config.read_timeout;
+    let response = match read_request(Deadline { stream: &stream, deadline }, config.max_input) {
+        Ok(request) => route(&request, config, run),
+        Err(response) => response,
+    };
+
+    write_response(&mut stream, &response)
+}
+
+// Reads from the stream until the deadline. A read timeout on its own only
+// limits each read, so a client sending a byte at a time could take forever.
+struct Deadline<'a> {
+    stream: &'a TcpStream,
+    deadline: Instant,
+}
+
+impl Read for Deadline<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let left = self.deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+        self.stream.set_read_timeout(Some(left))?;
+        self.stream.read(buf)
+    }
+}
+
+// Read the request line, headers and body, or the error response to send
+// instead
+fn read_request(stream: Deadline<'_>, max_input: usize) -> Result<Request, Response> {
+    let timed_out = |err: io::Error| match err.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => Response::error(408, "the request took too long to arrive"),
+        _ => Response::error(400, format!("unable to read the request: {err}")),
+    };
+
+    let mut reader = BufReader::new(stream);
+    let mut head: Vec<String> = Vec::new();
+    let mut head_len: usize = 0;
+    loop {
+        let mut line = String::new();
+        let read = reader.by_ref().take((MAX_HEADER + 1 - head_len) as u64).read_line(&mut line).map_err(timed_out)?;
+        head_len += read;
+        if head_len > MAX_HEADER {
+            return Err(Response::error(431, format!("the request headers are over {MAX_HEADER} bytes")));
+        }
+        if read == 0 {
+            return Err(Response::error(400, "the request ended before its headers did"));
+        }
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() { break; }
+        head.push(line.to_string());
+    }
+
+    let Some(request_line) = head.first() else {
+        return Err(Response::error(400, "the request line is empty"));
+    };
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(Response::error(400, format!("malformed request line: {request_line}")));
+    };
+    let path = target.split('?').next().unwrap_or(target);
+
+    let mut content_length: usize = 0;
+    for header in &head[1..] {
+        let Some((name, value)) = header.split_once(':') else { continue; };
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.trim().parse()
+                .map_err(|_| Response::error(400, format!("bad Content-Length: {}", value.trim())))?;
+        } else if name.eq_ignore_ascii_case("transfer-encoding") {
+            return Err(Response::error(411, "send the input with a Content-Length"));
+        }
+    }
+    if content_length > max_input {
+        return Err(Response::error(413, format!("the input is {content_length} bytes, the limit is {max_input}")));
+    }
+
+    let mut body: Vec<u8> = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(timed_out)?;
+
+    Ok(Request { method: method.to_string(), path: path.to_string(), body })
+}
+
+fn route(request: &Request, config: &Config, run: &Run<'_>) -> Response {
+    let segments: Vec<&str> = request.path.split('/').filter(|s| !s.is_empty()).collect();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["days"]) => days(&config.root),
+        ("POST", ["solve", year, day, part]) => solve(year, day, part, &request.body, run),
+        (_, ["days"]) => Response::error(405, "use GET for /days"),
+        (_, ["solve", _, _, _]) => Response::error(405, "use POST for /solve"),
+        _ => Response::error(404, format!("there is nothing at {}", request.path)),
+    }
+}
+
+// Every day with a solver, the parts it can solve and the inputs it has
+fn days(root: &Path) -> Response {
+    let mut days: BTreeMap<(u16, u8), Vec<u8>> = BTreeMap::new();
+    for solver in registry::all() {
+        let parts = days.entry((solver.year, solver.day)).or_default();
+        parts.extend(solver.part.numbers());
+        parts.sort();
+        parts.dedup();
+    }
+
+    let days: Vec<Value> = days.into_iter()
+        .map(|((year, day), parts)| {
+            let dir = root.join(year.to_string()).join(format!("{day:02}"));
+            let inputs: Vec<&str> = ["example", "real"].into_iter()
+                .filter(|name| dir.join(format!("{name}-input.txt")).is_file())
+                .collect();
+            json!({ "year": year, "day": day, "parts": parts, "inputs": inputs })
+        })
+        .collect();
+
+    Response { status: 200, body: json!({ "days": days }) }
+}
+
+// Solve the part for the body. The answer is given as a number where it fits
+// in 64 bits and as a string otherwise. The output is there too, and problems
+// with the input come back as diagnostics.
+fn solve(year: &str, day: &str, part: &str, body: &[u8], run: &Run<'_>) -> Response {
+    let (Ok(year), Ok(day), Ok(part)) = (year.parse::<u16>(), day.parse::<u8>(), part.parse::<u8>()) else {
+        return Response::error(400, "expected /solve/<year>/<day>/<part> with numbers");
+    };
+    let Some(solver) = registry::select(year, Some(day)).into_iter().find(|s| s.part.covers(part)) else {
+        return Response::error(404, format!("there is no solver for {year} day {day} part {part}"));
+    };
+    let Ok(input) = str::from_utf8(body) else {
+        return Response::error(400, "the input is not UTF-8 text");
+    };
+
+    let answered = match solver.solution {
+        Some(solution) => in_process(solution, input, part),
+        None => in_child(solver, input, part, run),
+    };
+    let answered = match answered {
+        Ok(answered) => answered,
+        Err(err) => return Response::error(500, err),
+    };
+
+    let status = match answered.status {
+        Status::Ok => 200,
+        Status::Failed(_) => 422,
+        Status::Timeout => 504,
+        Status::OutOfMemory => 507,
+    };
+    let detail = failure_detail(&answered.status);
+
+    Response {
+        status,
+        body: json!({
+            "year": year,
+            "day": day,
+            "part": part,
+            "status": answered.status.to_string(),
+            "detail": detail.trim_start_matches(": "),
+            "answer": answered.answer,
+            "elapsed_secs": answered.elapsed.as_secs_f64(),
+            "output": answered.output,
+            "diagnostics": answered.diagnostics,
+        }),
+    }
+}
+
+// Solve the part with the day's own Solution. A part with no answer is
+// reported the way the day's binary prints it, and a panic is caught here
+// rather than taking the connection with it.
+fn in_process(solution: &dyn Solve, input: &str, part: u8) -> Result<Answered, String> {
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| solution.solve_part(input, part)))
+        .map_err(|_| format!("the solution for part {part} panicked"))?;
+
+    let (status, answer, output, diagnostics) = match solved.answer {
+        Ok((answer, line)) => (Status::Ok, Some(answer_value(&answer.to_string())), format!("{line}\n"), Vec::new()),
+        Err(problems) => match problems.as_slice() {
+            [AocError::Unsolvable { reason }] => (Status::Ok, None, format!("No answer to part {part}: {reason}\n"), Vec::new()),
+            [problem] => (Status::Failed(problem.to_string()), None, String::new(), vec![problem.to_string()]),
+            _ => {
+                let reason = format!("{} problems with the input", problems.len());
+                (Status::Failed(reason), None, String::new(), problems.iter().map(AocError::to_string).collect())
+            },
+        },
+    };
+
+    Ok(Answered { status, answer, elapsed: solved.stats.total(), output, diagnostics })
+}
+
+// Run the solver's binary on the input, saved to a temporary file, and read
+// the answer for the part from what it prints. Anything it printed on stderr
+// comes back as diagnostics.
+fn in_child(solver: &Solver, input: &str, part: u8, run: &Run<'_>) -> Result<Answered, String> {
+    let path = env::temp_dir().join(format!("aoc-serve-{}-{}.txt", std::process::id(), NEXT_INPUT.fetch_add(1, Ordering::Relaxed)));
+    fs::write(&path, input).map_err(|err| format!("unable to save the input: {err}"))?;
+    let extra: Vec<String> = match solver.part {
+        Part::Both => vec!["--part".to_string(), part.to_string()],
+        _ => Vec::new(),
+    };
+    let outcome = run(solver, &path, &extra);
+    let _ = fs::remove_file(&path);
+    let outcome = outcome?;
+
+    let answer = match outcome.status {
+        Status::Ok => answers_in(solver, &outcome.stdout).get(&part).map(|answer| answer_value(answer)),
+        _ => None,
+    };
+    // The temporary file means nothing to the client, so problems point at
+    // "input" instead
+    let stderr = outcome.stderr.replace(&path.display().to_string(), "input");
+    let diagnostics: Vec<String> = stderr.lines().filter(|line| !line.trim().is_empty()).map(str::to_string).collect();
+
+    Ok(Answered { status: outcome.status, answer, elapsed: outcome.elapsed, output: outcome.stdout, diagnostics })
+}
+
+fn answer_value(answer: &str) -> Value {
+    if let Ok(n) = answer.parse::<i64>() {
+        json!(n)
+    } else if let Ok(n) = answer.parse::<u64>() {
+        json!(n)
+    } else {
+        json!(answer)
+    }
+}
+
+fn write_response(stream: &mut TcpStream, response: &Response) -> io::Result<()> {
+    let body = response.body.to_string();
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        411 => "Length Required",
+        413 => "Content Too Large",
+        422 => "Unprocessable Content",
+        431 => "Request Header Fields Too Large",
+        504 => "Gateway Timeout",
+        507 => "Insufficient Storage",
+        _ => "Internal Server Error",
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        response.status,
+        body.len(),
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exec::{self, Limits};
+    use std::{net::SocketAddr, time::Instant};
+
+    // Stands in for the solver binaries: counts the lines of the input, and
+    // fails on an empty one like a day rejecting its input would
+    fn count_lines(solver: &Solver, input: &Path, extra: &[String]) -> Result<Outcome, String> {
+        let contents = fs::read_to_string(input).map_err(|err| err.to_string())?;
+        let (status, stdout, stderr) = if contents.is_empty() {
+            (Status::Failed("exit status: 1".to_string()), String::new(), "input:1:1: the input is empty\n".to_string())
+        } else {
+            let lines = contents.lines().count();
+            (Status::Ok, format!("{} day {} {extra:?}\nLines: {lines}\nPart {}: {lines}\n", solver.year, solver.day, extra[1]), String::new())
+        };
+
+        Ok(Outcome { status, elapsed: Duration::from_millis(5), stdout, stderr, peak_memory: None })
+    }
+
+    // A solver that brings down the thread answering the request
+    fn panics(_solver: &Solver, _input: &Path, _extra: &[String]) -> Result<Outcome, String> {
+        panic!("the solver fell over");
+    }
+
+    // Runs a real process that outlives the timeout
+    fn sleep(_solver: &Solver, _input: &Path, _extra: &[String]) -> Result<Outcome, String> {
+        let limits = Limits { timeout: Some(Duration::from_millis(100)), memory: None };
+        exec::run_streaming(Path::new("sleep"), &["5".to_string()], limits, &|_| {})
+    }
+
+    fn start(run: &'static Run<'static>) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let config = Config {
+            root: exec::workspace_root(),
+            max_input: 64,
+            read_timeout: Duration::from_millis(500),
+            max_connections: 2,
+        };
+        thread::spawn(move || serve(&listener, &config, run));
+
+        addr
+    }
+
+    // Send the raw request and return the status code and JSON body
+    fn request(addr: SocketAddr, raw: &str) -> (u16, Value) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(raw.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let status = response.split_whitespace().nth(1).unwrap().parse().unwrap();
+        let body = response.split_once("\r\n\r\n").unwrap().1;
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    fn post(addr: SocketAddr, path: &str, input: &str) -> (u16, Value) {
+        request(addr, &format!("POST {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{input}", input.len()))
+    }
+
+    #[test]
+    fn lists_the_days() {
+        let addr = start(&count_lines);
+        let (status, body) = request(addr, "GET /days HTTP/1.1\r\nHost: localhost\r\n\r\n");
+
+        assert_eq!(status, 200);
+        let days = body["days"].as_array().unwrap();
+        let day7 = days.iter().find(|d| d["year"] == 2015 && d["day"] == 7).unwrap();
+        assert_eq!(day7["parts"], json!([1, 2]));
+        assert!(day7["inputs"].as_array().unwrap().contains(&json!("example")));
+        let day25 = days.iter().find(|d| d["year"] == 2015 && d["day"] == 25).unwrap();
+        assert_eq!(day25["parts"], json!([1]));
+    }
+
+    #[test]
+    fn solves_the_input() {
+        let addr = start(&count_lines);
+        let (status, body) = post(addr, "/solve/2015/7/2", "123 -> x\nx -> a\n");
+
+        assert_eq!(status, 200);
+        assert_eq!(body["status"], "OK");
+        assert_eq!(body["answer"], 2);
+        assert_eq!(body["output"], "2015 day 7 [\"--part\", \"2\"]\nLines: 2\nPart 2: 2\n");
+        assert_eq!(body["diagnostics"], json!([]));
+        assert_eq!(body["elapsed_secs"], 0.005);
+    }
+
+    #[test]
+    fn answers_in_process() {
+        // Day 1 is answered by its Solution, so the stand-in is never run
+        let addr = start(&panics);
+        let (status, body) = post(addr, "/solve/2015/1/1", "(()");
+
+        assert_eq!(status, 200);
+        assert_eq!(body["status"], "OK");
+        assert_eq!(body["answer"], 1);
+        assert_eq!(body["output"], "Santa ended up on floor 1\n");
+        assert_eq!(body["diagnostics"], json!([]));
+
+        let (status, body) = post(addr, "/solve/2015/1/2", "(((");
+        assert_eq!(status, 200);
+        assert_eq!(body["answer"], Value::Null);
+        assert_eq!(body["output"], "No answer to part 2: Santa never ended up in the basement\n");
+    }
+
+    #[test]
+    fn reports_problems_found_in_process() {
+        let addr = start(&panics);
+        let (status, body) = post(addr, "/solve/2015/1/1", "(x(y");
+
+        assert_eq!(status, 422);
+        assert_eq!(body["status"], "FAIL");
+        assert_eq!(body["detail"], "2 problems with the input");
+        assert_eq!(body["answer"], Value::Null);
+        let diagnostics = body["diagnostics"].as_array().unwrap();
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics[0].as_str().unwrap().starts_with("input:1:2: "), "{diagnostics:?}");
+
+        // The problems stay with the request that had them
+        assert_eq!(post(addr, "/solve/2015/1/1", "((").1["answer"], 2);
+    }
+
+    #[test]
+    fn reports_problems_with_the_input() {
+        let addr = start(&count_lines);
+        let (status, body) = post(addr, "/solve/2015/24/1", "");
+
+        assert_eq!(status, 422);
+        assert_eq!(body["status"], "FAIL");
+        assert_eq!(body["answer"], Value::Null);
+        assert_eq!(body["diagnostics"], json!(["input:1:1: the input is empty"]));
+    }
+
+    #[test]
+    fn times_out_slow_solvers() {
+        let addr = start(&sleep);
+        let start = Instant::now();
+        let (status, body) = post(addr, "/solve/2015/4/1", "abcdef");
+
+        assert_eq!(status, 504);
+        assert_eq!(body["status"], "TIMEOUT");
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn turns_away_large_inputs() {
+        let addr = start(&count_lines);
+        let (status, body) = post(addr, "/solve/2015/1/1", &"(".repeat(65));
+
+        assert_eq!(status, 413);
+        assert_eq!(body["error"], "the input is 65 bytes, the limit is 64");
+    }
+
+    #[test]
+    fn times_out_slow_clients() {
+        let addr = start(&count_lines);
+        // Promises more body than it sends
+        let (status, _) = request(addr, "POST /solve/2015/1/1 HTTP/1.1\r\nContent-Length: 10\r\n\r\n((");
+
+        assert_eq!(status, 408);
+    }
+
+    #[test]
+    fn times_out_clients_that_send_a_byte_at_a_time() {
+        let addr = start(&count_lines);
+        let mut stream = TcpStream::connect(addr).unwrap();
+        let start = Instant::now();
+        // Each byte arrives well within the read timeout, but the request as
+        // a whole doesn't
+        for byte in b"POST /solve/2015/1/1 HTTP/1.1\r\n" {
+            if stream.write_all(&[*byte]).is_err() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        let mut response = String::new();
+        let _ = stream.read_to_string(&mut response);
+
+        assert!(response.starts_with("HTTP/1.1 408 "), "{response}");
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn waits_for_a_free_connection() {
+        let addr = start(&count_lines);
+        // Two idle connections fill the server
+        let _idle: Vec<TcpStream> = (0..2).map(|_| TcpStream::connect(addr).unwrap()).collect();
+        thread::sleep(Duration::from_millis(50));
+
+        // This one is only answered once they have timed out
+        let start = Instant::now();
+        assert_eq!(post(addr, "/solve/2015/1/1", "(()").0, 200);
+        assert!(start.elapsed() >= Duration::from_millis(400), "{:?}", start.elapsed());
+    }
+
+    #[test]
+    fn rejects_an_empty_request_line() {
+        let addr = start(&count_lines);
+        // More than max_connections of them, so a connection that was never
+        // given back would leave nothing for the last request
+        for _ in 0..4 {
+            let (status, body) = request(addr, "\r\n\r\n");
+            assert_eq!(status, 400);
+            assert_eq!(body["error"], "the request line is empty");
+        }
+        assert_eq!(request(addr, " \r\n\r\n").0, 400);
+        assert_eq!(request(addr, "GET /days HTTP/1.1\r\n\r\n").0, 200);
+    }
+
+    #[test]
+    fn frees_the_connection_when_answering_panics() {
+        let addr = start(&panics);
+        for _ in 0..4 {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            stream.write_all(b"POST /solve/2015/4/1 HTTP/1.1\r\nContent-Length: 6\r\n\r\nabcdef").unwrap();
+            let mut response = String::new();
+            let _ = stream.read_to_string(&mut response);
+            assert_eq!(response, "");
+        }
+
+        let start = Instant::now();
+        assert_eq!(request(addr, "GET /days HTTP/1.1\r\n\r\n").0, 200);
+        assert!(start.elapsed() < Duration::from_millis(400), "{:?}", start.elapsed());
+    }
+
+    #[test]
+    fn rejects_unknown_routes() {
+        let addr = start(&count_lines);
+
+        assert_eq!(post(addr, "/solve/2015/26/1", "x").0, 404);
+        assert_eq!(post(addr, "/solve/2015/25/2", "x").0, 404);
+        assert_eq!(post(addr, "/solve/2015/one/1", "x").0, 400);
+        assert_eq!(post(addr, "/days", "").0, 405);
+        assert_eq!(request(addr, "GET /solve/2015/1/1 HTTP/1.1\r\n\r\n").0, 405);
+        assert_eq!(request(addr, "GET /nowhere HTTP/1.1\r\n\r\n").0, 404);
+    }
+}
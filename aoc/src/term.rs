@@ -0,0 +1,247 @@
+// Just enough terminal handling for `aoc tui`.
+//
+// Like watch, this avoids a dependency: raw mode is a couple of termios calls,
+// keys are a handful of escape sequences, and drawing is done on a Canvas of
+// styled cells that is written out in one go with ANSI codes each frame.
+use std::io::{self, Read, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    Char(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Plain,
+    Dim,
+    Bold,
+    // One star
+    Silver,
+    // Both stars
+    Gold,
+    Good,
+    Bad,
+    Selected,
+}
+
+impl Style {
+    fn code(&self) -> &'static str {
+        match self {
+            Style::Plain => "\x1B[0m",
+            Style::Dim => "\x1B[0;2m",
+            Style::Bold => "\x1B[0;1m",
+            Style::Silver => "\x1B[0;37m",
+            Style::Gold => "\x1B[0;1;33m",
+            Style::Good => "\x1B[0;32m",
+            Style::Bad => "\x1B[0;31m",
+            Style::Selected => "\x1B[0;7m",
+        }
+    }
+}
+
+// Puts the terminal into raw mode on the alternate screen, and puts it back
+// the way it was when dropped
+pub struct RawMode {
+    original: libc::termios,
+}
+
+impl RawMode {
+    pub fn enable() -> io::Result<RawMode> {
+        // SAFETY: termios is plain data that tcgetattr fills in
+        let mut original: libc::termios = unsafe { std::mem::zeroed() };
+        if unsafe { libc::tcgetattr(libc::STDIN_FILENO, &mut original) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // Keys arrive one at a time without being echoed, and Ctrl-C arrives
+        // as a key rather than a signal so the terminal is always restored
+        let mut raw = original;
+        raw.c_lflag &= !(libc::ICANON | libc::ECHO | libc::ISIG);
+        raw.c_cc[libc::VMIN] = 1;
+        raw.c_cc[libc::VTIME] = 0;
+        if unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &raw) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // Alternate screen, hidden cursor
+        print!("\x1B[?1049h\x1B[?25l");
+        io::stdout().flush()?;
+
+        Ok(RawMode { original })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        print!("\x1B[0m\x1B[?25h\x1B[?1049l");
+        let _ = io::stdout().flush();
+        unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &self.original) };
+    }
+}
+
+// The terminal's width and height, or 80x24 if it can't be asked
+pub fn size() -> (usize, usize) {
+    // SAFETY: winsize is plain data that the ioctl fills in
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    let ok = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } == 0;
+    if ok && size.ws_col > 0 && size.ws_row > 0 {
+        (size.ws_col as usize, size.ws_row as usize)
+    } else {
+        (80, 24)
+    }
+}
+
+// Read keys from stdin and hand them on until stdin closes or on_key returns
+// false. Ctrl-C is handed on as 'q'.
+pub fn read_keys(on_key: impl Fn(Key) -> bool) {
+    let mut stdin = io::stdin().lock();
+    let mut buffer = [0_u8; 16];
+    loop {
+        let read = match stdin.read(&mut buffer) {
+            Ok(0) | Err(_) => return,
+            Ok(n) => n,
+        };
+        for key in parse_keys(&buffer[..read]) {
+            if !on_key(key) {
+                return;
+            }
+        }
+    }
+}
+
+// The keys in a chunk of input. Escape sequences for keys other than the
+// arrows are dropped.
+fn parse_keys(bytes: &[u8]) -> Vec<Key> {
+    let mut keys: Vec<Key> = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            0x1B if bytes.get(i + 1) == Some(&b'[') => {
+                match bytes.get(i + 2) {
+                    Some(b'A') => keys.push(Key::Up),
+                    Some(b'B') => keys.push(Key::Down),
+                    Some(b'C') => keys.push(Key::Right),
+                    Some(b'D') => keys.push(Key::Left),
+                    _ => {},
+                }
+                // Skip to the end of the sequence
+                i += 2;
+                while i < bytes.len() && !bytes[i].is_ascii_alphabetic() && bytes[i] != b'~' {
+                    i += 1;
+                }
+            },
+            b'\r' | b'\n' => keys.push(Key::Enter),
+            0x03 => keys.push(Key::Char('q')),
+            b if b.is_ascii_graphic() || b == b' ' => keys.push(Key::Char(b as char)),
+            _ => {},
+        }
+        i += 1;
+    }
+
+    keys
+}
+
+// The line without escape sequences or control characters, so output from a
+// solver can't move the cursor or clear the screen
+pub fn clean(line: &str) -> String {
+    let mut cleaned = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\x1B' {
+            // CSI sequences end with a letter, anything else is one character
+            if chars.peek() == Some(&'[') {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() { break; }
+                }
+            } else {
+                chars.next();
+            }
+        } else if c == '\t' {
+            cleaned += "    ";
+        } else if !c.is_control() {
+            cleaned.push(c);
+        }
+    }
+
+    cleaned
+}
+
+// A screen's worth of styled characters
+pub struct Canvas {
+    pub width: usize,
+    pub height: usize,
+    cells: Vec<(char, Style)>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize) -> Canvas {
+        Canvas { width, height, cells: vec![(' ', Style::Plain); width * height] }
+    }
+
+    // Write the text from (x, y), cut off at the right edge
+    pub fn put(&mut self, x: usize, y: usize, text: &str, style: Style) {
+        if y >= self.height { return; }
+        for (i, c) in text.chars().enumerate() {
+            if x + i >= self.width { break; }
+            self.cells[y * self.width + x + i] = (c, style);
+        }
+    }
+
+    // The text without styles, one string per row, for tests
+    #[cfg(test)]
+    pub fn rows(&self) -> Vec<String> {
+        self.cells.chunks(self.width).map(|row| row.iter().map(|(c, _)| c).collect()).collect()
+    }
+
+    // The escape codes and text to draw the whole canvas from the top left
+    pub fn render(&self) -> String {
+        let mut frame = String::from("\x1B[H");
+        let mut style: Option<Style> = None;
+        for (y, row) in self.cells.chunks(self.width).enumerate() {
+            if y > 0 { frame += "\r\n"; }
+            for &(c, cell_style) in row {
+                if style != Some(cell_style) {
+                    frame += cell_style.code();
+                    style = Some(cell_style);
+                }
+                frame.push(c);
+            }
+        }
+        frame += Style::Plain.code();
+
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_arrows_and_letters() {
+        let keys = parse_keys(b"\x1B[A\x1B[Dq\r\x1B[5~e\x03");
+
+        assert_eq!(keys, [Key::Up, Key::Left, Key::Char('q'), Key::Enter, Key::Char('e'), Key::Char('q')]);
+    }
+
+    #[test]
+    fn cleans_escape_codes_out_of_output() {
+        assert_eq!(clean("\x1B[2J\x1B[1;1H..##..\r"), "..##..");
+        assert_eq!(clean("\x1B[1;31mERROR\x1B[0m: bad\tline"), "ERROR: bad    line");
+    }
+
+    #[test]
+    fn clips_text_to_the_canvas() {
+        let mut canvas = Canvas::new(6, 2);
+        canvas.put(2, 0, "abcdef", Style::Bold);
+        canvas.put(0, 5, "gone", Style::Plain);
+
+        assert_eq!(canvas.rows(), ["  abcd", "      "]);
+        assert!(canvas.render().contains("\x1B[0;1mabcd"));
+    }
+}
@@ -0,0 +1,525 @@
+// `aoc tui`: a dashboard for browsing and running the solvers.
+//
+// The calendar has a cell for every day of each year with solvers, with the
+// day's stars from its recorded answers (answers.txt). Enter builds and runs
+// the selected day's solvers under the same limits as `aoc run`, streaming
+// their output into the log as it is printed, and checks the answers from a
+// real input against the recorded ones. The grid days also play an animation
+// of the puzzle next to the log while they run.
+use crate::{
+    answers_in,
+    cargo_packages,
+    exec::{self, Limits, Outcome, Status},
+    failure_detail,
+    last_line,
+    load_answers,
+    registry::{self, Solver},
+    run_solver_streaming,
+    term::{self, Canvas, Key, RawMode, Style},
+    visual::{self, Visual},
+};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, IsTerminal, Write},
+    path::{Path, PathBuf},
+    sync::mpsc::{self, RecvTimeoutError, Sender},
+    thread,
+    time::{Duration, Instant},
+};
+
+// How often the screen is redrawn and the animation moves on
+const FRAME: Duration = Duration::from_millis(100);
+
+// Lines of solver output kept in the log
+const LOG_LINES: usize = 1000;
+
+// Columns taken by the calendar, including its margins
+const CALENDAR_WIDTH: usize = 28;
+
+enum Event {
+    Key(Key),
+    // A line of output from the running solvers
+    Output(String),
+    // A solver finished, with its index in the day's solvers
+    Finished(usize, Result<Outcome, String>),
+    // Every solver for the day has run
+    Done,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Input {
+    Real,
+    Example,
+}
+
+impl Input {
+    fn name(&self) -> &'static str {
+        match self {
+            Input::Real => "real",
+            Input::Example => "example",
+        }
+    }
+}
+
+struct App {
+    root: PathBuf,
+    limits: Limits,
+    years: Vec<u16>,
+    // Index into years and the day of the month
+    selected: (usize, u8),
+    // The input Enter runs on. Real falls back to the example while there is
+    // no real input.
+    input: Input,
+    // Recorded answers for every day, by part
+    answers: HashMap<(u16, u8), HashMap<u8, String>>,
+    // Whether each part's answer from the last run on the real input matched
+    // the recorded one
+    checked: HashMap<(u16, u8), HashMap<u8, bool>>,
+    // The day last run and the input it ran on
+    ran: Option<(u16, u8, Input)>,
+    running: bool,
+    // How each of the day's solvers did, in registry order
+    results: Vec<Option<Result<Outcome, String>>>,
+    log: Vec<String>,
+    visual: Option<Visual>,
+}
+
+// Run the dashboard until q or Ctrl-C is pressed
+pub fn run(root: &Path, limits: Limits) -> bool {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        eprintln!("aoc tui needs to run in a terminal");
+        return false;
+    }
+
+    let (sender, events) = mpsc::channel::<Event>();
+    let keys = sender.clone();
+    thread::spawn(move || term::read_keys(|key| keys.send(Event::Key(key)).is_ok()));
+
+    let _raw = match RawMode::enable() {
+        Ok(raw) => raw,
+        Err(err) => {
+            eprintln!("Unable to set up the terminal: {err}");
+            return false;
+        },
+    };
+
+    let mut app = App::new(root, limits);
+    let mut last_frame = Instant::now() - FRAME;
+    loop {
+        if last_frame.elapsed() >= FRAME {
+            if let Some(visual) = &mut app.visual {
+                visual.tick();
+            }
+            app.show();
+            last_frame = Instant::now();
+        }
+
+        match events.recv_timeout(FRAME.saturating_sub(last_frame.elapsed())) {
+            Ok(Event::Key(Key::Char('q'))) => return true,
+            Ok(Event::Key(key)) => {
+                app.key(key, &sender);
+                app.show();
+            },
+            Ok(event) => app.handle(event),
+            Err(RecvTimeoutError::Timeout) => {},
+            Err(RecvTimeoutError::Disconnected) => return true,
+        }
+    }
+}
+
+impl App {
+    fn new(root: &Path, limits: Limits) -> App {
+        let mut years: Vec<u16> = registry::all().iter().map(|s| s.year).collect();
+        years.dedup();
+
+        let mut app = App {
+            root: root.to_path_buf(),
+            limits,
+            years,
+            selected: (0, 1),
+            input: Input::Real,
+            answers: HashMap::new(),
+            checked: HashMap::new(),
+            ran: None,
+            running: false,
+            results: Vec::new(),
+            log: Vec::new(),
+            visual: None,
+        };
+        app.load_answers();
+
+        app
+    }
+
+    fn load_answers(&mut self) {
+        for solver in registry::all() {
+            let answers = load_answers(&solver.day_dir(&self.root).join("answers.txt"));
+            self.answers.insert((solver.year, solver.day), answers);
+        }
+    }
+
+    fn day(&self) -> (u16, u8) {
+        (self.years[self.selected.0], self.selected.1)
+    }
+
+    fn key(&mut self, key: Key, events: &Sender<Event>) {
+        // The calendar as one list of days, year after year, five to a row
+        let last = self.years.len() * 25 - 1;
+        let at = self.selected.0 * 25 + self.selected.1 as usize - 1;
+        let at = match key {
+            Key::Left => at.saturating_sub(1),
+            Key::Right => (at + 1).min(last),
+            Key::Up => at.saturating_sub(5),
+            Key::Down => (at + 5).min(last),
+            Key::Enter => {
+                self.start(events);
+                at
+            },
+            Key::Char('e') => {
+                self.input = match self.input {
+                    Input::Real => Input::Example,
+                    Input::Example => Input::Real,
+                };
+                at
+            },
+            Key::Char(_) => at,
+        };
+        self.selected = (at / 25, (at % 25 + 1) as u8);
+    }
+
+    // Build and run the selected day's solvers in the background
+    fn start(&mut self, events: &Sender<Event>) {
+        let (year, day) = self.day();
+        let solvers: Vec<&'static Solver> = registry::select(year, Some(day));
+        if solvers.is_empty() || self.running {
+            return;
+        }
+
+        let dir = solvers[0].day_dir(&self.root);
+        let input = match self.input {
+            Input::Real if dir.join("real-input.txt").is_file() => Input::Real,
+            _ => Input::Example,
+        };
+        let path = dir.join(format!("{}-input.txt", input.name()));
+
+        self.log.clear();
+        self.results = solvers.iter().map(|_| None).collect();
+        self.ran = Some((year, day, input));
+        self.visual = None;
+        let Ok(contents) = fs::read_to_string(&path) else {
+            self.log.push(format!("{year} day {day} has no {} input", input.name()));
+            return;
+        };
+        self.visual = visual::for_day(year, day, &contents);
+        self.running = true;
+
+        let root = self.root.clone();
+        let limits = self.limits;
+        let events = events.clone();
+        thread::spawn(move || {
+            let packages = cargo_packages(&solvers);
+            let built = if packages.is_empty() {
+                Ok(())
+            } else {
+                let _ = events.send(Event::Output(format!("Building {}", packages.join(", "))));
+                exec::build_quietly(&root, &packages)
+            };
+
+            if let Err(err) = built {
+                for line in err.lines() {
+                    let _ = events.send(Event::Output(line.to_string()));
+                }
+            } else {
+                let send_line = |line: &str| {
+                    let _ = events.send(Event::Output(line.to_string()));
+                };
+                for (i, solver) in solvers.iter().enumerate() {
+                    send_line(&format!("== {} part {} on {}", solver.package, solver.part, path.strip_prefix(&root).unwrap_or(&path).display()));
+                    let outcome = run_solver_streaming(&root, solver, &path, &[], limits, &send_line);
+                    let _ = events.send(Event::Finished(i, outcome));
+                }
+            }
+            let _ = events.send(Event::Done);
+        });
+    }
+
+    fn handle(&mut self, event: Event) {
+        match event {
+            Event::Key(_) => {},
+            Event::Output(line) => self.push_log(term::clean(&line)),
+            Event::Finished(i, outcome) => {
+                let Some((year, day, input)) = self.ran else { return; };
+                let solver = registry::select(year, Some(day))[i];
+                let summary = match &outcome {
+                    Ok(outcome) => format!("part {}: {} after {:.2}s{}", solver.part, outcome.status, outcome.elapsed.as_secs_f64(), failure_detail(&outcome.status)),
+                    Err(err) => format!("part {}: {err}", solver.part),
+                };
+                self.push_log(summary);
+
+                // The recorded answers are for the real input
+                if input == Input::Real
+                    && let Ok(outcome) = &outcome
+                {
+                    let answers = self.answers.get(&(year, day)).cloned().unwrap_or_default();
+                    let found = answers_in(solver, &outcome.stdout);
+                    let checked = self.checked.entry((year, day)).or_default();
+                    for part in solver.part.numbers() {
+                        if let Some(expected) = answers.get(part) {
+                            checked.insert(*part, outcome.status == Status::Ok && found.get(part) == Some(expected));
+                        }
+                    }
+                }
+                self.results[i] = Some(outcome);
+            },
+            Event::Done => {
+                self.running = false;
+                // A run may have been the reason answers.txt changed
+                self.load_answers();
+            },
+        }
+    }
+
+    fn push_log(&mut self, line: String) {
+        self.log.push(line);
+        if self.log.len() > LOG_LINES {
+            self.log.drain(..self.log.len() - LOG_LINES);
+        }
+    }
+
+    fn show(&self) {
+        let (width, height) = term::size();
+        print!("{}", self.draw(width, height).render());
+        let _ = io::stdout().flush();
+    }
+
+    fn draw(&self, width: usize, height: usize) -> Canvas {
+        let mut canvas = Canvas::new(width, height);
+        canvas.put(1, 0, "Advent of Code", Style::Bold);
+        canvas.put(1, height.saturating_sub(1), "arrows: pick a day   enter: run   e: example/real input   q: quit", Style::Dim);
+
+        self.draw_calendar(&mut canvas, 1, 2);
+
+        let x = CALENDAR_WIDTH + 1;
+        let w = width.saturating_sub(x + 1);
+        let log_top = self.draw_details(&mut canvas, x, 2) + 1;
+        let log_height = height.saturating_sub(log_top + 2);
+
+        let (year, day) = self.day();
+        let visual = match (&self.visual, self.ran) {
+            (Some(visual), Some((y, d, _))) if (y, d) == (year, day) => Some(visual),
+            _ => None,
+        };
+        let log_width = if visual.is_some() { w / 2 } else { w };
+
+        let title = if self.running { "Log (running)" } else { "Log" };
+        canvas.put(x, log_top, title, Style::Bold);
+        let shown = &self.log[self.log.len().saturating_sub(log_height)..];
+        for (i, line) in shown.iter().enumerate() {
+            let line: String = line.chars().take(log_width.saturating_sub(1)).collect();
+            canvas.put(x, log_top + 1 + i, &line, Style::Plain);
+        }
+
+        if let Some(visual) = visual {
+            let vx = x + log_width;
+            canvas.put(vx, log_top, &visual.title(), Style::Bold);
+            visual.draw(&mut canvas, vx, log_top + 1, w - log_width, log_height);
+        }
+
+        canvas
+    }
+
+    // Five rows of five days for each year
+    fn draw_calendar(&self, canvas: &mut Canvas, x: usize, y: usize) {
+        for (yi, &year) in self.years.iter().enumerate() {
+            let top = y + yi * 7;
+            canvas.put(x, top, &year.to_string(), Style::Bold);
+
+            for day in 1..=25_u8 {
+                let row = top + 1 + (day as usize - 1) / 5;
+                let col = x + (day as usize - 1) % 5 * 5;
+                let (text, style) = self.cell(year, day);
+                let style = if self.selected == (yi, day) { Style::Selected } else { style };
+                canvas.put(col, row, &text, style);
+            }
+        }
+    }
+
+    // A day's number and its stars
+    fn cell(&self, year: u16, day: u8) -> (String, Style) {
+        if registry::select(year, Some(day)).is_empty() {
+            return (format!("{day:>2}  "), Style::Dim);
+        }
+
+        let stars = self.answers.get(&(year, day)).map_or(0, |answers| answers.len().min(2));
+        let wrong = self.checked.get(&(year, day)).is_some_and(|checked| checked.values().any(|&ok| !ok));
+        let style = match stars {
+            _ if wrong => Style::Bad,
+            0 => Style::Plain,
+            1 => Style::Silver,
+            _ => Style::Gold,
+        };
+
+        (format!("{day:>2}{:<2}", "*".repeat(stars)), style)
+    }
+
+    // The selected day's solvers, inputs and answers. Returns the row after
+    // the last one used.
+    fn draw_details(&self, canvas: &mut Canvas, x: usize, y: usize) -> usize {
+        let (year, day) = self.day();
+        canvas.put(x, y, &format!("{year} day {day}"), Style::Bold);
+
+        let solvers = registry::select(year, Some(day));
+        if solvers.is_empty() {
+            canvas.put(x, y + 1, "There is no solver for this day yet", Style::Dim);
+            return y + 2;
+        }
+        canvas.put(x + 16, y, solvers[0].package, Style::Dim);
+
+        let dir = solvers[0].day_dir(&self.root);
+        let inputs: Vec<&str> = ["example", "real"].into_iter()
+            .filter(|name| dir.join(format!("{name}-input.txt")).is_file())
+            .collect();
+        let inputs = if inputs.is_empty() { "none".to_string() } else { inputs.join(", ") };
+        canvas.put(x, y + 1, &format!("Runs on: {:<8} Inputs: {inputs}", self.input.name()), Style::Plain);
+
+        let mut row = y + 2;
+        let this_day = self.ran.filter(|&(y, d, _)| (y, d) == (year, day));
+        for (i, solver) in solvers.iter().enumerate() {
+            let result = this_day.and_then(|_| self.results.get(i)).and_then(|r| r.as_ref());
+            let (text, style) = match result {
+                None if self.running && this_day.is_some() => ("running".to_string(), Style::Dim),
+                None => ("-".to_string(), Style::Dim),
+                Some(Err(err)) => (err.clone(), Style::Bad),
+                Some(Ok(outcome)) if outcome.status == Status::Ok => {
+                    (format!("{:<8}{:>7.2}s  {}", outcome.status.to_string(), outcome.elapsed.as_secs_f64(), term::clean(last_line(&outcome.stdout))), Style::Good)
+                },
+                Some(Ok(outcome)) => {
+                    (format!("{:<8}{:>7.2}s{}", outcome.status.to_string(), outcome.elapsed.as_secs_f64(), failure_detail(&outcome.status)), Style::Bad)
+                },
+            };
+            canvas.put(x, row, &format!("part {:<4}", solver.part.to_string()), Style::Plain);
+            canvas.put(x + 10, row, &text, style);
+            row += 1;
+        }
+
+        let answers = self.answers.get(&(year, day));
+        let checked = self.checked.get(&(year, day));
+        canvas.put(x, row, "answers", Style::Plain);
+        let mut col = x + 10;
+        match answers {
+            Some(answers) if !answers.is_empty() => {
+                let mut parts: Vec<&u8> = answers.keys().collect();
+                parts.sort();
+                for part in parts {
+                    let (mark, style) = match checked.and_then(|c| c.get(part)) {
+                        Some(true) => (" ✓", Style::Good),
+                        Some(false) => (" ✗", Style::Bad),
+                        None => ("", Style::Plain),
+                    };
+                    let text = format!("{part}: {}{mark}", answers[part]);
+                    canvas.put(col, row, &text, style);
+                    col += text.chars().count() + 3;
+                }
+            },
+            _ => canvas.put(col, row, "none recorded", Style::Dim),
+        }
+
+        row + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A workspace root with recorded answers for 2015 days 1 and 7
+    fn root() -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-tui-test-{}", std::process::id()));
+        fs::create_dir_all(root.join("2015/01")).unwrap();
+        fs::create_dir_all(root.join("2015/07")).unwrap();
+        fs::write(root.join("2015/01/answers.txt"), "1: 74\n").unwrap();
+        fs::write(root.join("2015/07/answers.txt"), "1: 3176\n2: 14710\n").unwrap();
+        fs::write(root.join("2015/07/example-input.txt"), "123 -> a\n").unwrap();
+
+        root
+    }
+
+    fn app() -> App {
+        App::new(&root(), Limits { timeout: None, memory: None })
+    }
+
+    #[test]
+    fn shows_stars_from_the_recorded_answers() {
+        let app = app();
+        let rows = app.draw(100, 30).rows();
+
+        assert!(rows[2].starts_with(" 2015 "));
+        assert!(rows[3].starts_with("  1*   2    3    4    5"));
+        assert!(rows[4].starts_with("  6    7**  8"));
+        assert!(rows[9].starts_with(" 2016"));
+    }
+
+    #[test]
+    fn moves_around_the_calendar() {
+        let mut app = app();
+        let (events, _) = mpsc::channel();
+
+        for key in [Key::Right, Key::Down, Key::Left] {
+            app.key(key, &events);
+        }
+        assert_eq!(app.day(), (2015, 6));
+
+        // Down off the bottom of 2015 goes on to 2016
+        for _ in 0..4 {
+            app.key(Key::Down, &events);
+        }
+        assert_eq!(app.day(), (2016, 1));
+
+        app.key(Key::Char('e'), &events);
+        assert_eq!(app.input, Input::Example);
+    }
+
+    #[test]
+    fn shows_the_selected_day() {
+        let mut app = app();
+        let (events, _) = mpsc::channel();
+        for _ in 0..6 {
+            app.key(Key::Right, &events);
+        }
+
+        let rows = app.draw(100, 30).rows();
+        assert!(rows[2].contains("2015 day 7      aoc-2015-07-part1-2"));
+        assert!(rows[3].contains("Runs on: real     Inputs: example"));
+        assert!(rows[4].contains("part 1+2  -"));
+        assert!(rows[5].contains("answers   1: 3176   2: 14710"));
+    }
+
+    #[test]
+    fn checks_answers_from_a_run() {
+        let mut app = app();
+        let (events, _) = mpsc::channel();
+        for _ in 0..6 {
+            app.key(Key::Right, &events);
+        }
+        app.ran = Some((2015, 7, Input::Real));
+        app.results = vec![None];
+        app.running = true;
+
+        app.handle(Event::Output("\x1B[2JWire a is 3176".to_string()));
+        let outcome = Outcome {
+            status: Status::Ok,
+            elapsed: Duration::from_millis(20),
+            stdout: "Wire a is 3176\nPart 1: 3176\nThe value of a is 99\nPart 2: 99\n".to_string(),
+            stderr: String::new(),
+            peak_memory: None,
+        };
+        app.handle(Event::Finished(0, Ok(outcome)));
+        app.handle(Event::Done);
+
+        assert_eq!(app.log, ["Wire a is 3176", "part 1+2: OK after 0.02s"]);
+        let rows = app.draw(100, 30).rows();
+        assert!(rows[4].contains("part 1+2  OK         0.02s  Part 2: 99"));
+        assert!(rows[5].contains("1: 3176 ✓   2: 14710 ✗"));
+    }
+}
@@ -0,0 +1,241 @@
+// Animations of the grid days for the visualisation pane of `aoc tui`.
+//
+// The solvers only print their answers, so the animations are played here on
+// the days' own libraries while the solver runs: day 6's lights one
+// instruction at a time, day 18's lights one step at a time, and 2016 day 1's
+// walk one stretch at a time. Grids are shrunk to fit the pane, with each
+// character shaded by how many of the lights it covers are on.
+use crate::term::{Canvas, Style};
+use aoc_2015_06_part1_2 as day06;
+use aoc_2015_18_part1_2 as day18;
+use aoc_2016_01_part1::{self as day01_2016, Instruction, Turn};
+use shared::validate;
+
+// Blocks of lights from none to all of them on
+const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+// Day 6 instructions followed each frame
+const DIRECTIONS_PER_FRAME: usize = 2;
+
+// Day 18 steps shown, as many as the puzzle asks for
+const LIFE_STEPS: usize = 100;
+
+pub enum Visual {
+    // 2015 day 6: the lights after each instruction
+    Lights {
+        grid: Vec<Vec<bool>>,
+        directions: Vec<(String, usize, usize, usize, usize)>,
+        followed: usize,
+    },
+    // 2015 day 18: the lights after each step
+    Life {
+        grid: Vec<Vec<bool>>,
+        steps: usize,
+    },
+    // 2016 day 1: the walk so far, and the first place visited twice
+    Walk {
+        corners: Vec<(isize, isize)>,
+        walked: usize,
+        hq: Option<(isize, isize)>,
+    },
+}
+
+// The animation for the day on the input, if the day has one
+pub fn for_day(year: u16, day: u8, input: &str) -> Option<Visual> {
+    let visual = match (year, day) {
+        (2015, 6) => Visual::Lights {
+            grid: vec![vec![false; 1000]; 1000],
+            directions: day06::parse_directions(input),
+            followed: 0,
+        },
+        (2015, 18) => Visual::Life { grid: day18::parse(input), steps: 0 },
+        (2016, 1) => {
+            let instructions = day01_2016::parse(false, input);
+            let hq = day01_2016::find_hq(false, &instructions).ok().and_then(|walk| walk.first_double_visit);
+            Visual::Walk { corners: corners(&instructions), walked: 0, hq }
+        },
+        _ => return None,
+    };
+    // Problems with the input are for the solver to report
+    validate::take();
+
+    Some(visual)
+}
+
+// Where each stretch of the walk ends, starting from the start facing north
+fn corners(instructions: &[Instruction]) -> Vec<(isize, isize)> {
+    let (mut x, mut y): (isize, isize) = (0, 0);
+    let (mut dx, mut dy): (isize, isize) = (0, 1);
+    let mut corners: Vec<(isize, isize)> = vec![(x, y)];
+    for instruction in instructions {
+        (dx, dy) = match instruction.turn {
+            Turn::LEFT => (-dy, dx),
+            Turn::RIGHT => (dy, -dx),
+        };
+        let distance = instruction.distance as isize;
+        x = x.saturating_add(dx.saturating_mul(distance));
+        y = y.saturating_add(dy.saturating_mul(distance));
+        corners.push((x, y));
+    }
+
+    corners
+}
+
+impl Visual {
+    // Move on to the next frame. Nothing changes once the animation is done.
+    pub fn tick(&mut self) {
+        match self {
+            Visual::Lights { grid, directions, followed } => {
+                let next = (*followed + DIRECTIONS_PER_FRAME).min(directions.len());
+                day06::execute(grid, &directions[*followed..next], |action, light| match action {
+                    "turn on" => *light = true,
+                    "turn off" => *light = false,
+                    _ => *light = !*light,
+                });
+                *followed = next;
+            },
+            Visual::Life { grid, steps } => {
+                if *steps < LIFE_STEPS {
+                    *grid = day18::part1(false, grid, 1);
+                    *steps += 1;
+                }
+            },
+            Visual::Walk { corners, walked, .. } => {
+                *walked = (*walked + 1).min(corners.len() - 1);
+            },
+        }
+    }
+
+    // What the current frame shows
+    pub fn title(&self) -> String {
+        match self {
+            Visual::Lights { directions, followed, .. } => format!("Lights after {followed} of {} instructions", directions.len()),
+            Visual::Life { steps, .. } => format!("Lights after step {steps} of {LIFE_STEPS}"),
+            Visual::Walk { corners, walked, hq } => match hq {
+                Some((x, y)) if *walked + 1 == corners.len() => format!("Walked {walked} stretches, HQ is at {x},{y}"),
+                _ => format!("Walked {walked} of {} stretches", corners.len() - 1),
+            },
+        }
+    }
+
+    // Draw the frame into the w by h area of the canvas at (x, y)
+    pub fn draw(&self, canvas: &mut Canvas, x: usize, y: usize, w: usize, h: usize) {
+        if w == 0 || h == 0 { return; }
+
+        match self {
+            Visual::Lights { grid, .. } | Visual::Life { grid, .. } => shade(grid, canvas, x, y, w, h),
+            Visual::Walk { corners, walked, hq } => {
+                let min_x = corners.iter().map(|c| c.0).min().unwrap_or(0);
+                let max_x = corners.iter().map(|c| c.0).max().unwrap_or(0);
+                let min_y = corners.iter().map(|c| c.1).min().unwrap_or(0);
+                let max_y = corners.iter().map(|c| c.1).max().unwrap_or(0);
+                // North is up
+                let place = |(px, py): (isize, isize)| -> (usize, usize) {
+                    let scale = |v: isize, min: isize, max: isize, size: usize| -> usize {
+                        let span = (max as i128 - min as i128).max(1);
+                        ((v as i128 - min as i128) * (size as i128 - 1) / span) as usize
+                    };
+                    (x + scale(px, min_x, max_x, w), y + h - 1 - scale(py, min_y, max_y, h))
+                };
+
+                for stretch in corners[..=*walked].windows(2) {
+                    let (from, to) = (place(stretch[0]), place(stretch[1]));
+                    let steps = from.0.abs_diff(to.0).max(from.1.abs_diff(to.1));
+                    for step in 0..=steps {
+                        let along = |a: usize, b: usize| (a as i128 + (b as i128 - a as i128) * step as i128 / steps.max(1) as i128) as usize;
+                        let (cx, cy) = (along(from.0, to.0), along(from.1, to.1));
+                        canvas.put(cx, cy, "·", Style::Dim);
+                    }
+                }
+
+                let (sx, sy) = place((0, 0));
+                canvas.put(sx, sy, "S", Style::Bold);
+                let (cx, cy) = place(corners[*walked]);
+                canvas.put(cx, cy, "@", Style::Good);
+                if let Some(hq) = hq
+                    && *walked + 1 == corners.len()
+                {
+                    let (hx, hy) = place(*hq);
+                    canvas.put(hx, hy, "X", Style::Gold);
+                }
+            },
+        }
+    }
+}
+
+// Draw the grid shrunk to w by h, each character shaded by the share of the
+// lights under it that are on
+fn shade(grid: &[Vec<bool>], canvas: &mut Canvas, x: usize, y: usize, w: usize, h: usize) {
+    let rows = grid.len();
+    let cols = grid.first().map_or(0, |row| row.len());
+    if rows == 0 || cols == 0 { return; }
+
+    // The rows or columns of the grid under character i of n
+    let span = |i: usize, n: usize, len: usize| -> (usize, usize) {
+        let start = (i * len / n).min(len - 1);
+        (start, ((i + 1) * len / n).clamp(start + 1, len))
+    };
+
+    for cy in 0..h {
+        let (top, bottom) = span(cy, h, rows);
+        for cx in 0..w {
+            let (left, right) = span(cx, w, cols);
+            let on = grid[top..bottom].iter().map(|row| row[left..right].iter().filter(|&&light| light).count()).sum::<usize>();
+            let total = (bottom - top) * (right - left);
+            let shade = SHADES[(on * (SHADES.len() - 1)).div_ceil(total)];
+            canvas.put(x + cx, y + cy, &shade.to_string(), Style::Gold);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_through_the_lights() {
+        let mut visual = for_day(2015, 18, ".#.\n.#.\n.#.").unwrap();
+        let mut canvas = Canvas::new(3, 3);
+        visual.draw(&mut canvas, 0, 0, 3, 3);
+        assert_eq!(canvas.rows(), [" █ ", " █ ", " █ "]);
+
+        // A blinker turns on its side
+        visual.tick();
+        visual.draw(&mut canvas, 0, 0, 3, 3);
+        assert_eq!(canvas.rows(), ["   ", "███", "   "]);
+        assert_eq!(visual.title(), "Lights after step 1 of 100");
+    }
+
+    #[test]
+    fn shades_blocks_of_lights() {
+        let grid: Vec<Vec<bool>> = vec![vec![true, true, true, false], vec![false, false, false, false]];
+        let mut canvas = Canvas::new(2, 1);
+        shade(&grid, &mut canvas, 0, 0, 2, 1);
+
+        assert_eq!(canvas.rows(), ["▒░"]);
+    }
+
+    #[test]
+    fn follows_day_6_a_few_instructions_at_a_time() {
+        let mut visual = for_day(2015, 6, "turn on 0,0 through 999,499\ntoggle 0,0 through 999,999\nturn off 0,0 through 999,999").unwrap();
+        let mut canvas = Canvas::new(2, 2);
+        visual.tick();
+        visual.draw(&mut canvas, 0, 0, 2, 2);
+
+        assert_eq!(canvas.rows(), ["  ", "██"]);
+        assert_eq!(visual.title(), "Lights after 2 of 3 instructions");
+    }
+
+    #[test]
+    fn walks_to_the_hq() {
+        let mut visual = for_day(2016, 1, "R8, R4, R4, R8").unwrap();
+        for _ in 0..4 {
+            visual.tick();
+        }
+        let mut canvas = Canvas::new(5, 5);
+        visual.draw(&mut canvas, 0, 0, 5, 5);
+
+        assert_eq!(canvas.rows(), ["  @  ", "  ·  ", "S·X··", "  · ·", "  ···"]);
+        assert_eq!(visual.title(), "Walked 4 stretches, HQ is at 4,0");
+    }
+}
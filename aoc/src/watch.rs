@@ -0,0 +1,164 @@
+// Polling file watcher for `aoc watch`.
+//
+// Rather than depend on OS notification APIs, the watcher takes a snapshot of
+// the modification time and size of every file under the watched paths and
+// compares it with the last one. Polling twice a second is plenty for a person
+// saving files, and it behaves the same everywhere.
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+// Directories that are never looked inside, as builds write to them
+const SKIP_DIRS: &[&str] = &["target", ".git", ".checkpoints"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileState {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    snapshot: BTreeMap<PathBuf, FileState>,
+}
+
+impl Watcher {
+    // Watch the given files and directories. Paths that don't exist yet are
+    // watched too, and show up as changed once they are created.
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let mut watcher = Watcher { paths, snapshot: BTreeMap::new() };
+        watcher.snapshot = watcher.scan();
+
+        watcher
+    }
+
+    // The files that were created, modified or removed since the last poll, in
+    // path order
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let snapshot = self.scan();
+
+        let mut changed: BTreeSet<PathBuf> = BTreeSet::new();
+        for (path, state) in &snapshot {
+            if self.snapshot.get(path) != Some(state) {
+                changed.insert(path.clone());
+            }
+        }
+        for path in self.snapshot.keys() {
+            if !snapshot.contains_key(path) {
+                changed.insert(path.clone());
+            }
+        }
+
+        self.snapshot = snapshot;
+        changed.into_iter().collect()
+    }
+
+    fn scan(&self) -> BTreeMap<PathBuf, FileState> {
+        let mut snapshot: BTreeMap<PathBuf, FileState> = BTreeMap::new();
+        for path in &self.paths {
+            scan_path(path, &mut snapshot);
+        }
+
+        snapshot
+    }
+}
+
+fn scan_path(path: &Path, snapshot: &mut BTreeMap<PathBuf, FileState>) {
+    let Ok(metadata) = fs::metadata(path) else { return; };
+
+    if metadata.is_file() {
+        snapshot.insert(path.to_path_buf(), FileState { modified: metadata.modified().ok(), len: metadata.len() });
+        return;
+    }
+
+    let Ok(entries) = fs::read_dir(path) else { return; };
+    for entry in entries.flatten() {
+        let entry_path = entry.path();
+        let skip = entry_path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| SKIP_DIRS.contains(&name));
+        if !skip {
+            scan_path(&entry_path, snapshot);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    // A fresh directory under the system temp dir, removed again on drop
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = env::temp_dir().join(format!("aoc-watch-{name}-{}", process::id()));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            TempDir(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn reports_nothing_without_changes() {
+        let dir = TempDir::new("unchanged");
+        fs::write(dir.0.join("main.rs"), "fn main() {}").unwrap();
+
+        let mut watcher = Watcher::new(vec![dir.0.clone()]);
+        assert!(watcher.poll().is_empty());
+    }
+
+    #[test]
+    fn reports_modified_created_and_removed_files() {
+        let dir = TempDir::new("changes");
+        let src = dir.0.join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("main.rs"), "fn main() {}").unwrap();
+        fs::write(src.join("old.rs"), "").unwrap();
+
+        let mut watcher = Watcher::new(vec![dir.0.clone()]);
+
+        // The size changes as well as the time, so this doesn't depend on the
+        // resolution of the file system's timestamps
+        fs::write(src.join("main.rs"), "fn main() { println!(); }").unwrap();
+        assert_eq!(watcher.poll(), vec![src.join("main.rs")]);
+
+        fs::write(src.join("new.rs"), "").unwrap();
+        fs::remove_file(src.join("old.rs")).unwrap();
+        assert_eq!(watcher.poll(), vec![src.join("new.rs"), src.join("old.rs")]);
+
+        assert!(watcher.poll().is_empty());
+    }
+
+    #[test]
+    fn watches_files_that_do_not_exist_yet() {
+        let dir = TempDir::new("missing");
+        let input = dir.0.join("real-input.txt");
+
+        let mut watcher = Watcher::new(vec![input.clone()]);
+        assert!(watcher.poll().is_empty());
+
+        fs::write(&input, "1").unwrap();
+        assert_eq!(watcher.poll(), vec![input]);
+    }
+
+    #[test]
+    fn ignores_build_output() {
+        let dir = TempDir::new("target");
+        fs::create_dir_all(dir.0.join("target")).unwrap();
+
+        let mut watcher = Watcher::new(vec![dir.0.clone()]);
+        fs::write(dir.0.join("target").join("out"), "built").unwrap();
+        assert!(watcher.poll().is_empty());
+    }
+}
@@ -0,0 +1,13 @@
+#![no_main]
+
+// Day 1 parser and both parts
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_01_part1_2::{parse, part1, part2};
+
+fuzz_target!(|input: &str| {
+    let steps = parse(input);
+    validate::take();
+    part1(&steps);
+    part2(&steps);
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+// Day 2 parser and both parts
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_02_part1_2::{parse, part1, part2};
+
+fuzz_target!(|input: &str| {
+    let presents = parse(input);
+    validate::take();
+    let _ = part1(&presents);
+    let _ = part2(&presents);
+});
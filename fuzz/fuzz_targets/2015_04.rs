@@ -0,0 +1,12 @@
+#![no_main]
+
+// Day 4 mining with any secret key. Only one leading zero is asked for so
+// each run stays quick.
+use libfuzzer_sys::fuzz_target;
+use aoc_2015_04_part1_2::mine_advent_coins;
+use shared::checkpoint::Checkpoint;
+
+fuzz_target!(|key: &str| {
+    let mut checkpoint = Checkpoint::new("fuzz-2015-04", key);
+    mine_advent_coins(key, 1, &mut checkpoint);
+});
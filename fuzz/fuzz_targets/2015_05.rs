@@ -0,0 +1,13 @@
+#![no_main]
+
+// Day 5 parser and both parts
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_05_part1_2::{parse, part1, part2};
+
+fuzz_target!(|input: &str| {
+    let strings = parse(input);
+    validate::take();
+    part1(&strings);
+    part2(&strings);
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+// Day 6 parser and both parts
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_06_part1_2::Day06;
+use shared::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let directions = Day06.parse(input);
+    validate::take();
+    if let Ok(directions) = directions {
+        let _ = Day06.part1(&directions);
+        let _ = Day06.part2(&directions);
+    }
+});
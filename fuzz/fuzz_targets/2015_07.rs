@@ -0,0 +1,14 @@
+#![no_main]
+
+// Day 7 parser and both parts
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_07_part1_2::{parse, part1, part2};
+
+fuzz_target!(|input: &str| {
+    let gates = parse(input);
+    validate::take();
+    if let Ok(a) = part1(&gates) {
+        let _ = part2(&gates, a);
+    }
+});
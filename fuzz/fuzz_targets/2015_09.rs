@@ -0,0 +1,18 @@
+#![no_main]
+
+// Day 9 parser and both parts. Every route is tried, so only small maps are
+// solved.
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_09_part1_2::{parse, part1, part2};
+
+const MAX_LOCATIONS: usize = 7;
+
+fuzz_target!(|input: &str| {
+    let map = parse(false, input);
+    validate::take();
+    if map.locations.len() <= MAX_LOCATIONS {
+        part1(false, &map);
+        part2(false, &map);
+    }
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+// Day 10 look-and-say with any starting sequence
+use libfuzzer_sys::fuzz_target;
+use aoc_2015_10_part1_2::play;
+
+fuzz_target!(|input: &str| {
+    play(false, input, 3);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+// Day 11 password search with any starting password
+use libfuzzer_sys::fuzz_target;
+use aoc_2015_11_part1_2::{FORBIDDEN_CHARS, count_with_chars, next_valid_password};
+
+fuzz_target!(|input: &str| {
+    if let Ok(password) = next_valid_password(false, input) {
+        let _ = count_with_chars(false, &password, &FORBIDDEN_CHARS);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+// Day 12 parser and both parts
+use libfuzzer_sys::fuzz_target;
+use aoc_2015_12_part1_2::{parse, part1, part2};
+
+fuzz_target!(|input: &str| {
+    if let Ok(document) = parse(input) {
+        let _ = part1(false, &document);
+        let _ = part2(false, &document);
+    }
+});
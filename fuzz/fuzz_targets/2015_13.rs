@@ -0,0 +1,18 @@
+#![no_main]
+
+// Day 13 parser and both parts. Every seating is tried, so only small
+// tables are solved.
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_13_part1_2::{parse, part1, part2};
+
+const MAX_GUESTS: usize = 6;
+
+fuzz_target!(|input: &str| {
+    let guests = parse(&false, input);
+    validate::take();
+    if guests.names.len() <= MAX_GUESTS {
+        part1(false, &guests);
+        part2(false, &guests);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+// Day 14 parser and both parts
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_14_part1_2::{parse, part1, part2};
+
+fuzz_target!(|input: &str| {
+    let (names, reindeer) = parse(input);
+    validate::take();
+    part1(false, &names, &reindeer, 1000);
+    part2(false, &names, &reindeer, 1000);
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+// Day 15 parser and both parts. Every mix of ingredients is tried, so only
+// a few ingredients are solved.
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_15_part1_2::{parse, part1, part2};
+
+const MAX_INGREDIENTS: usize = 3;
+
+fuzz_target!(|input: &str| {
+    let ingredients = parse(&false, input);
+    validate::take();
+    if ingredients.len() <= MAX_INGREDIENTS {
+        let _ = part1(false, &ingredients);
+        let _ = part2(false, &ingredients);
+    }
+});
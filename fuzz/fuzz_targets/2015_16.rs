@@ -0,0 +1,13 @@
+#![no_main]
+
+// Day 16 parser and both parts
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_16_part1_2::{parse, part1, part2};
+
+fuzz_target!(|input: &str| {
+    let sues = parse(input);
+    validate::take();
+    part1(&sues);
+    part2(&sues);
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+// Day 17 parser and both parts
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_17_part1_2::{combinations_by_size, parse, part1, part2};
+
+fuzz_target!(|input: &str| {
+    let buckets = parse(input);
+    validate::take();
+    if let Ok(by_size) = combinations_by_size(false, &buckets, 150) {
+        let _ = part1(&by_size);
+        part2(&by_size);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+// Day 18 parser and both parts
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_18_part1_2::{count_on, parse, part1, part2};
+
+fuzz_target!(|input: &str| {
+    let grid = parse(input);
+    validate::take();
+    count_on(&part1(false, &grid, 10));
+    count_on(&part2(false, &grid, 10));
+});
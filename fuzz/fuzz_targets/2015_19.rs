@@ -0,0 +1,13 @@
+#![no_main]
+
+// Day 19 parser and part 1. Part 2 is a search that can take as long as
+// the molecule allows, so it is left to the real inputs.
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_19_part1_2::{parse, part1};
+
+fuzz_target!(|input: &str| {
+    let (molecule, replacements) = parse(input);
+    validate::take();
+    part1(&molecule, &replacements);
+});
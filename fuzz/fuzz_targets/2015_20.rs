@@ -0,0 +1,10 @@
+#![no_main]
+
+// Day 20 both parts with any target number of presents
+use libfuzzer_sys::fuzz_target;
+use aoc_2015_20_part1_2::{part1, part2};
+
+fuzz_target!(|target: u16| {
+    part1(target as usize);
+    part2(false, target as usize);
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+// Day 21 parser and both parts
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_21_part1_2::{parse, part1, part2};
+
+fuzz_target!(|input: &str| {
+    let boss = parse(false, input);
+    validate::take();
+    part1(false, boss);
+    part2(false, boss);
+});
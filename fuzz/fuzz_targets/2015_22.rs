@@ -0,0 +1,20 @@
+#![no_main]
+
+// Day 22 parser and both parts. The search grows with the boss, so only weak
+// bosses are fought.
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_22_part1_2::{build_game, part1, part2};
+
+const MAX_BOSS_HP: usize = 60;
+
+fuzz_target!(|input: String| {
+    let game = build_game(false, false, &Some(input));
+    validate::take();
+    if let Ok((boss_hp, boss_damage, player_hp, player_mana, spellbook)) = game
+        && boss_hp <= MAX_BOSS_HP
+    {
+        part1(false, boss_hp, boss_damage, player_hp, player_mana, &spellbook);
+        part2(false, boss_hp, boss_damage, player_hp, player_mana, &spellbook);
+    }
+});
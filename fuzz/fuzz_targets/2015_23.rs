@@ -0,0 +1,14 @@
+#![no_main]
+
+// Day 23 parser and computer. Computer::run gives up on programs that loop or
+// run for longer than its step limit, so every input finishes.
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_23_part1::{Computer, parse};
+
+fuzz_target!(|input: &str| {
+    let instructions = parse(input);
+    validate::take();
+
+    let _ = Computer::new().run(false, &instructions);
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+// Day 24 parser and balancing into three and four groups. Every grouping is
+// tried, so only a few packages are balanced.
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2015_24_part1::{balance, parse};
+
+const MAX_PACKAGES: usize = 12;
+
+fuzz_target!(|input: &str| {
+    let weights = parse(input);
+    validate::take();
+    if weights.len() <= MAX_PACKAGES {
+        let _ = balance(false, weights.clone(), 3);
+        let _ = balance(false, weights, 4);
+    }
+});
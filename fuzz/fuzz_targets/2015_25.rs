@@ -0,0 +1,9 @@
+#![no_main]
+
+// Day 25 with any row and column
+use libfuzzer_sys::fuzz_target;
+use aoc_2015_25_part1::part1;
+
+fuzz_target!(|position: (usize, usize)| {
+    let _ = part1(false, position.0, position.1);
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+// 2016 day 1 parser and both parts
+use libfuzzer_sys::fuzz_target;
+use shared::validate;
+use aoc_2016_01_part1::{find_hq, parse, part1, part2};
+
+fuzz_target!(|input: &str| {
+    let instructions = parse(false, input);
+    validate::take();
+    if let Ok(walk) = find_hq(false, &instructions) {
+        let _ = part1(&walk);
+        let _ = part2(&walk);
+    }
+});
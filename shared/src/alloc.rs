@@ -0,0 +1,174 @@
+// Allocation accounting.
+//
+// With the `alloc-stats` feature the CountingAllocator is installed as the
+// global allocator. It passes everything through to the system allocator while
+// keeping count of the bytes currently allocated, the peak, the total bytes ever
+// allocated and the number of allocations. Without the feature nothing is
+// counted and measure() returns None.
+use serde::Serialize;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+static CURRENT: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+static TOTAL: AtomicU64 = AtomicU64::new(0);
+static COUNT: AtomicU64 = AtomicU64::new(0);
+
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record_alloc(size: usize) {
+        let size = size as u64;
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        TOTAL.fetch_add(size, Ordering::Relaxed);
+        COUNT.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        CURRENT.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: every call is passed straight to the system allocator, the counters
+// are only updated around it
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::record_dealloc(layout.size());
+    }
+
+    // A realloc counts as one allocation of the new size, so growing a Vec
+    // shows up in the totals the same way as allocating a new one would
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AllocStats {
+    // Most bytes allocated at once, including anything still held from before
+    pub peak_bytes: u64,
+    // Bytes allocated in total, whether or not they were freed again
+    pub total_bytes: u64,
+    // Number of allocations
+    pub allocations: u64,
+}
+
+// Whether the counting allocator is installed
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+// Run f and count what it allocates. Returns None for the stats if the counting
+// allocator isn't installed.
+//
+// Measurements shouldn't be nested, as the inner one resets the peak.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<AllocStats>) {
+    if !enabled() {
+        return (f(), None);
+    }
+
+    PEAK.store(CURRENT.load(Ordering::Relaxed), Ordering::Relaxed);
+    let total = TOTAL.load(Ordering::Relaxed);
+    let count = COUNT.load(Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = AllocStats {
+        peak_bytes: PEAK.load(Ordering::Relaxed),
+        total_bytes: TOTAL.load(Ordering::Relaxed) - total,
+        allocations: COUNT.load(Ordering::Relaxed) - count,
+    };
+
+    (result, Some(stats))
+}
+
+// Format a byte count with a KiB/MiB/GiB suffix
+pub fn human_bytes(bytes: u64) -> String {
+    let bytes = bytes as f64;
+    if bytes >= (1u64 << 30) as f64 {
+        format!("{:.1} GiB", bytes / (1u64 << 30) as f64)
+    } else if bytes >= (1u64 << 20) as f64 {
+        format!("{:.1} MiB", bytes / (1u64 << 20) as f64)
+    } else if bytes >= (1u64 << 10) as f64 {
+        format!("{:.1} KiB", bytes / (1u64 << 10) as f64)
+    } else {
+        format!("{bytes:.0} B")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "alloc-stats"))]
+    fn counters() -> [u64; 4] {
+        [&CURRENT, &PEAK, &TOTAL, &COUNT].map(|counter| counter.load(Ordering::Relaxed))
+    }
+
+    // Drives the allocator by hand, which only gives exact counts when it isn't
+    // also counting everything else as the global allocator
+    #[cfg(not(feature = "alloc-stats"))]
+    #[test]
+    fn counts_allocations_reallocations_and_frees() {
+        let allocator = CountingAllocator;
+        let layout = Layout::from_size_align(100, 8).unwrap();
+        let [current, _, total, count] = counters();
+
+        // SAFETY: every pointer is freed with the layout it was allocated with
+        unsafe {
+            let ptr = allocator.alloc(layout);
+            let ptr = allocator.realloc(ptr, layout, 300);
+            assert_eq!(counters(), [current + 300, current + 300, total + 400, count + 2]);
+
+            let zeroed = allocator.alloc_zeroed(layout);
+            allocator.dealloc(ptr, Layout::from_size_align(300, 8).unwrap());
+            allocator.dealloc(zeroed, layout);
+        }
+
+        assert_eq!(counters(), [current, current + 400, total + 500, count + 3]);
+        assert_eq!(measure(|| Vec::<u8>::with_capacity(10)).1, None);
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn measures_what_a_phase_allocates() {
+        let (held, stats) = measure(|| {
+            let freed: Vec<u8> = Vec::with_capacity(4096);
+            drop(freed);
+            // Returned so it's still held when the stats are read, as other
+            // tests allocating at the same time can only add to the counts
+            vec![0u8; 1 << 20]
+        });
+        let stats = stats.unwrap();
+
+        assert!(stats.allocations >= 2, "{stats:?}");
+        assert!(stats.total_bytes >= (1 << 20) + 4096, "{stats:?}");
+        assert!(stats.peak_bytes >= 1 << 20, "{stats:?}");
+        drop(held);
+    }
+}
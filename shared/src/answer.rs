@@ -0,0 +1,111 @@
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
+use std::fmt;
+
+// The answer to one part of a puzzle. Integers are kept in 128 bits so that
+// answers wider than 64 bits are exact. With the `bigint` feature enabled
+// there is also an arbitrary precision variant for anything wider still.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    #[cfg(feature = "bigint")]
+    Big(BigInt),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            #[cfg(feature = "bigint")]
+            Answer::Big(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+// u128 does not always fit in an i128. Without the `bigint` feature values
+// that don't fit are kept as text so they are still exact.
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Number(n),
+            #[cfg(feature = "bigint")]
+            Err(_) => Answer::Big(BigInt::from(n)),
+            #[cfg(not(feature = "bigint"))]
+            Err(_) => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        // Keep small values as plain numbers so they compare equal
+        match i128::try_from(&n) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Big(n),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_of_any_width_compare_equal() {
+        assert_eq!(Answer::from(42u8), Answer::Number(42));
+        assert_eq!(Answer::from(42usize), Answer::from(42i64));
+        assert_eq!(Answer::from(-7isize), Answer::Number(-7));
+        assert_eq!(Answer::from(u128::from(u64::MAX)), Answer::from(u64::MAX));
+    }
+
+    #[test]
+    fn displays_the_bare_value() {
+        assert_eq!(Answer::from(-1234i32).to_string(), "-1234");
+        assert_eq!(Answer::from("abcdffaa").to_string(), "abcdffaa");
+        assert_eq!(Answer::from(String::from("hxbxxyzz")), Answer::Text("hxbxxyzz".to_string()));
+    }
+
+    #[test]
+    fn u128_wider_than_i128_stays_exact() {
+        let answer = Answer::from(u128::MAX);
+        assert_eq!(answer.to_string(), u128::MAX.to_string());
+        assert!(!matches!(answer, Answer::Number(_)));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn small_big_integers_are_plain_numbers() {
+        assert_eq!(Answer::from(BigInt::from(99)), Answer::Number(99));
+        let huge = BigInt::from(u128::MAX) * BigInt::from(10);
+        assert_eq!(Answer::from(huge.clone()), Answer::Big(huge));
+    }
+}
@@ -0,0 +1,133 @@
+// Overflow-safe arithmetic for solvers.
+//
+// With the `checked-arith` feature enabled every operation is checked and an
+// overflow is returned as AocError::Overflow. Without it the plain operators are
+// used, which panic in debug builds and in the workspace release profile (see
+// overflow-checks in the workspace Cargo.toml).
+//
+//     cargo run -p aoc-2015-24-part1 --features shared/checked-arith -- ...
+use crate::error::AocError;
+use std::fmt::Display;
+
+// Integer types that the arithmetic helpers work with
+pub trait Integer: Copy + Display {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn unchecked_add(self, rhs: Self) -> Self;
+    fn unchecked_sub(self, rhs: Self) -> Self;
+    fn unchecked_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> { <$t>::checked_add(self, rhs) }
+                fn checked_sub(self, rhs: Self) -> Option<Self> { <$t>::checked_sub(self, rhs) }
+                fn checked_mul(self, rhs: Self) -> Option<Self> { <$t>::checked_mul(self, rhs) }
+                fn unchecked_add(self, rhs: Self) -> Self { self + rhs }
+                fn unchecked_sub(self, rhs: Self) -> Self { self - rhs }
+                fn unchecked_mul(self, rhs: Self) -> Self { self * rhs }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+// Apply the checked or unchecked version of an operation depending on the
+// arithmetic policy
+fn apply<T: Integer>(
+    operation: &'static str,
+    a: T,
+    b: T,
+    checked: fn(T, T) -> Option<T>,
+    unchecked: fn(T, T) -> T,
+) -> Result<T, AocError> {
+    if cfg!(feature = "checked-arith") {
+        checked(a, b).ok_or_else(|| AocError::Overflow {
+            operation,
+            lhs: a.to_string(),
+            rhs: b.to_string(),
+        })
+    } else {
+        Ok(unchecked(a, b))
+    }
+}
+
+// a + b
+pub fn add<T: Integer>(a: T, b: T) -> Result<T, AocError> {
+    apply("add", a, b, T::checked_add, T::unchecked_add)
+}
+
+// a - b
+pub fn sub<T: Integer>(a: T, b: T) -> Result<T, AocError> {
+    apply("subtract", a, b, T::checked_sub, T::unchecked_sub)
+}
+
+// a * b
+pub fn mul<T: Integer>(a: T, b: T) -> Result<T, AocError> {
+    apply("multiply", a, b, T::checked_mul, T::unchecked_mul)
+}
+
+// Sum of every value in the iterator
+pub fn sum<T, I>(values: I) -> Result<T, AocError>
+where
+    T: Integer,
+    I: IntoIterator<Item = T>,
+{
+    values.into_iter().try_fold(T::ZERO, add)
+}
+
+// Product of every value in the iterator
+pub fn product<T, I>(values: I) -> Result<T, AocError>
+where
+    T: Integer,
+    I: IntoIterator<Item = T>,
+{
+    values.into_iter().try_fold(T::ONE, mul)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations_in_range_give_the_plain_result() {
+        assert_eq!(add(2u8, 3).unwrap(), 5);
+        assert_eq!(sub(-2i32, 3).unwrap(), -5);
+        assert_eq!(mul(u64::MAX / 2, 2).unwrap(), u64::MAX - 1);
+        assert_eq!(sum([1usize, 2, 3, 4]).unwrap(), 10);
+        assert_eq!(product([1i64, -2, 3, 4]).unwrap(), -24);
+    }
+
+    #[test]
+    fn empty_sums_and_products_are_the_identity() {
+        assert_eq!(sum(Vec::<u32>::new()).unwrap(), 0);
+        assert_eq!(product(Vec::<u32>::new()).unwrap(), 1);
+    }
+
+    #[cfg(feature = "checked-arith")]
+    #[test]
+    fn overflow_is_an_error() {
+        let err = mul(200u8, 2).unwrap_err();
+        assert!(matches!(&err, AocError::Overflow { operation: "multiply", lhs, rhs } if lhs == "200" && rhs == "2"));
+        assert_eq!(err.to_string(), "Arithmetic overflow trying to multiply 200 and 2");
+        assert!(matches!(sub(0usize, 1), Err(AocError::Overflow { operation: "subtract", .. })));
+        assert!(matches!(sum([u32::MAX, 1]), Err(AocError::Overflow { operation: "add", .. })));
+    }
+
+    #[cfg(not(feature = "checked-arith"))]
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn overflow_panics() {
+        let _ = sum([u32::MAX, 1]);
+    }
+}
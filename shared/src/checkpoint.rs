@@ -0,0 +1,177 @@
+// Checkpoints for long-running solvers.
+//
+// A solver periodically saves its state (a counter, a frontier, its best answer
+// so far, ...) to a file in the .checkpoints directory. If the run is
+// interrupted it can be started again with --resume to carry on from the last
+// checkpoint. Each checkpoint records a hash of the input it was made from, and
+// is ignored if the input has changed.
+use serde::{
+    Deserialize,
+    Serialize,
+    de::DeserializeOwned,
+};
+use std::{
+    fs,
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+// Directory the checkpoint files are written to, relative to where the solver
+// is run
+const CHECKPOINT_DIR: &str = ".checkpoints";
+
+// How often save_if_due actually writes
+const SAVE_INTERVAL: Duration = Duration::from_secs(10);
+
+static RESUME: AtomicBool = AtomicBool::new(false);
+
+// Set whether solvers should resume from their checkpoints, usually from the
+// --resume flag
+pub fn set_resume(resume: bool) {
+    RESUME.store(resume, Ordering::Relaxed);
+}
+
+pub fn resume_requested() -> bool {
+    RESUME.load(Ordering::Relaxed)
+}
+
+#[derive(Serialize, Deserialize)]
+struct File<T> {
+    input_hash: String,
+    state: T,
+}
+
+pub struct Checkpoint {
+    path: PathBuf,
+    input_hash: String,
+    last_save: Instant,
+}
+
+impl Checkpoint {
+    // A checkpoint for the named solver running on this input
+    pub fn new(name: &str, input: &str) -> Checkpoint {
+        let input_hash = format!("{:016x}", hash_input(input));
+        let path = PathBuf::from(CHECKPOINT_DIR).join(format!("{name}-{input_hash}.json"));
+
+        Checkpoint { path, input_hash, last_save: Instant::now() }
+    }
+
+    // The saved state, if --resume was given and there is a checkpoint for this
+    // input
+    pub fn resume<T: DeserializeOwned>(&self) -> Option<T> {
+        if !resume_requested() {
+            return None;
+        }
+
+        let contents = fs::read_to_string(&self.path).ok()?;
+        let file: File<T> = match serde_json::from_str(&contents) {
+            Ok(f) => f,
+            Err(err) => {
+                eprintln!("Ignoring unreadable checkpoint {}: {err}", self.path.display());
+                return None;
+            }
+        };
+        if file.input_hash != self.input_hash {
+            eprintln!("Ignoring checkpoint {} as it was made from a different input", self.path.display());
+            return None;
+        }
+
+        eprintln!("Resuming from checkpoint {}", self.path.display());
+        Some(file.state)
+    }
+
+    // Save the state if it has been long enough since the last save
+    pub fn save_if_due<T: Serialize>(&mut self, state: &T) {
+        if self.last_save.elapsed() >= SAVE_INTERVAL {
+            self.save(state);
+        }
+    }
+
+    // Save the state now. The file is written to a temporary name first and
+    // then renamed, so an interruption never leaves a half written checkpoint.
+    // A checkpoint that can't be written is reported but doesn't stop the
+    // solver.
+    pub fn save<T: Serialize>(&mut self, state: &T) {
+        self.last_save = Instant::now();
+
+        let file = File { input_hash: self.input_hash.clone(), state };
+        let contents = match serde_json::to_string(&file) {
+            Ok(s) => s,
+            Err(err) => {
+                eprintln!("Unable to serialize checkpoint: {err}");
+                return;
+            }
+        };
+
+        let tmp_path = self.path.with_extension("json.tmp");
+        let result = fs::create_dir_all(CHECKPOINT_DIR)
+            .and_then(|_| fs::write(&tmp_path, contents))
+            .and_then(|_| fs::rename(&tmp_path, &self.path));
+        if let Err(err) = result {
+            eprintln!("Unable to write checkpoint {}: {err}", self.path.display());
+        }
+    }
+
+    // Remove the checkpoint once the solver has finished
+    pub fn clear(&self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+// 64 bit FNV-1a. Unlike the std hashers this is stable between runs and Rust
+// versions, so checkpoints stay valid.
+fn hash_input(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process;
+
+    // A checkpoint with a name no other test run uses
+    fn checkpoint(test: &str, input: &str) -> Checkpoint {
+        Checkpoint::new(&format!("test-{test}-{}", process::id()), input)
+    }
+
+    #[test]
+    fn hash_is_fnv_1a() {
+        assert_eq!(hash_input(""), 0xcbf29ce484222325);
+        assert_eq!(hash_input("a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(hash_input("foobar"), 0x85944171f73967e8);
+    }
+
+    #[test]
+    fn saved_state_round_trips() {
+        set_resume(true);
+        let mut checkpoint = checkpoint("round-trip", "3113322113\n");
+        let state = (42usize, vec!["abc".to_string(), "def".to_string()]);
+
+        checkpoint.save(&state);
+        assert_eq!(checkpoint.resume::<(usize, Vec<String>)>(), Some(state));
+        assert!(!checkpoint.path.with_extension("json.tmp").exists());
+
+        checkpoint.clear();
+        assert_eq!(checkpoint.resume::<(usize, Vec<String>)>(), None);
+    }
+
+    #[test]
+    fn checkpoints_from_another_input_are_ignored() {
+        set_resume(true);
+        let mut checkpoint = checkpoint("mismatch", "hxbxwxba\n");
+        checkpoint.save(&7u32);
+
+        // Same file, but recorded against a different input
+        let other = File { input_hash: format!("{:016x}", hash_input("hxbxxyzz\n")), state: 7u32 };
+        fs::write(&checkpoint.path, serde_json::to_string(&other).unwrap()).unwrap();
+        assert_eq!(checkpoint.resume::<u32>(), None);
+
+        fs::write(&checkpoint.path, "{ not json").unwrap();
+        assert_eq!(checkpoint.resume::<u32>(), None);
+
+        checkpoint.clear();
+    }
+}
@@ -0,0 +1,293 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+};
+
+// A cycle found in a sequence of states x0, x1 = f(x0), x2 = f(x1), ...
+// `start` is the index of the first state that is part of the cycle (mu) and
+// `length` is the number of steps it takes to get back to it (lambda).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    // Map any step number onto the earliest step that has the same state.
+    pub fn index_of(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+// Apply the step function n times to the initial state
+pub fn advance<T, F>(initial: T, n: usize, mut step: F) -> T
+where
+    F: FnMut(&T) -> T,
+{
+    let mut state = initial;
+    for _ in 0..n {
+        state = step(&state);
+    }
+
+    state
+}
+
+// Find a cycle using Brent's algorithm. Only a couple of states are kept in
+// memory at once, so this suits large states that are cheap to compare.
+//
+// Returns None if no cycle was found within `limit` steps.
+pub fn brent<T, F>(initial: &T, limit: usize, step: F) -> Option<Cycle>
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    brent_search(initial, limit, step).ok()
+}
+
+// Find a cycle using Floyd's tortoise and hare algorithm.
+//
+// Returns None if no cycle was found within `limit` steps.
+pub fn floyd<T, F>(initial: &T, limit: usize, step: F) -> Option<Cycle>
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    floyd_search(initial, limit, step).ok()
+}
+
+// Find a cycle by remembering every state seen. Each state is only computed
+// once, at the cost of keeping all of them in memory.
+//
+// Returns None if no cycle was found within `limit` steps.
+pub fn find_cycle_hashed<T, F>(initial: &T, limit: usize, step: F) -> Option<Cycle>
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    hashed_search(initial, limit, step).ok().map(|(cycle, _)| cycle)
+}
+
+// Return the state after n steps, skipping over whole cycles once one has been
+// found. States are remembered in a HashMap, so the step function is called
+// at most once per distinct state.
+pub fn nth_state<T, F>(initial: T, n: usize, step: F) -> T
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    match hashed_search(&initial, n, step) {
+        Ok((cycle, mut history)) => history.swap_remove(cycle.index_of(n)),
+        Err(state) => state,
+    }
+}
+
+// Return the state after n steps using Brent's algorithm to find the cycle.
+// Uses constant memory, but the step function is called roughly three times
+// as often as with nth_state.
+pub fn nth_state_brent<T, F>(initial: T, n: usize, mut step: F) -> T
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    match brent_search(&initial, n, &mut step) {
+        Ok(cycle) => advance(initial, cycle.index_of(n), step),
+        Err(state) => state,
+    }
+}
+
+// Brent's algorithm. If the limit is reached before a cycle is found, the state
+// at step `limit` is returned as the error.
+fn brent_search<T, F>(initial: &T, limit: usize, mut step: F) -> Result<Cycle, T>
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    if limit == 0 {
+        return Err(initial.clone());
+    }
+
+    // Find the cycle length by teleporting the tortoise to the hare at each
+    // power of two
+    let mut power: usize = 1;
+    let mut length: usize = 1;
+    let mut steps: usize = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if steps == limit {
+            return Err(hare);
+        }
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+        steps += 1;
+    }
+
+    // Put the hare `length` steps ahead of the tortoise and walk them together
+    // until they meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = advance(initial.clone(), length, &mut step);
+    let mut start: usize = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Ok(Cycle { start, length })
+}
+
+// Floyd's algorithm. If the limit is reached before a cycle is found, the state
+// at step `limit` is returned as the error.
+fn floyd_search<T, F>(initial: &T, limit: usize, mut step: F) -> Result<Cycle, T>
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    if limit == 0 {
+        return Err(initial.clone());
+    }
+
+    // The hare moves twice as fast as the tortoise until they meet inside the
+    // cycle
+    let mut steps: usize = 0;
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    loop {
+        for _ in 0..2 {
+            hare = step(&hare);
+            steps += 1;
+            if steps == limit {
+                return Err(hare);
+            }
+        }
+        tortoise = step(&tortoise);
+        if tortoise == hare {
+            break;
+        }
+    }
+
+    // Restart the tortoise and move both one step at a time to find the start
+    let mut tortoise = initial.clone();
+    let mut start: usize = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    // Walk the hare around the cycle once to measure it
+    let mut hare = step(&tortoise);
+    let mut length: usize = 1;
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Ok(Cycle { start, length })
+}
+
+// Remember every state and the step it was first seen at. If the limit is
+// reached before a cycle is found, the state at step `limit` is returned as the
+// error.
+fn hashed_search<T, F>(initial: &T, limit: usize, mut step: F) -> Result<(Cycle, Vec<T>), T>
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    let mut seen: HashMap<T, usize> = HashMap::new();
+    let mut history: Vec<T> = Vec::new();
+    let mut state = initial.clone();
+
+    for n in 0..limit {
+        if let Some(&start) = seen.get(&state) {
+            return Ok((Cycle { start, length: n - start }, history));
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), n);
+        history.push(state);
+        state = next;
+    }
+
+    Err(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, ... enters a cycle of length 5 at step 3
+    fn step(n: &usize) -> usize {
+        if *n == 7 { 3 } else { n + 1 }
+    }
+
+    const CYCLE: Cycle = Cycle { start: 3, length: 5 };
+
+    #[test]
+    fn every_search_finds_the_same_cycle() {
+        assert_eq!(brent(&0, 100, step), Some(CYCLE));
+        assert_eq!(floyd(&0, 100, step), Some(CYCLE));
+        assert_eq!(find_cycle_hashed(&0, 100, step), Some(CYCLE));
+    }
+
+    #[test]
+    fn finds_a_cycle_that_starts_at_the_initial_state() {
+        let step = |n: &usize| (n + 1) % 4;
+        let cycle = Cycle { start: 0, length: 4 };
+
+        assert_eq!(brent(&0, 100, step), Some(cycle));
+        assert_eq!(floyd(&0, 100, step), Some(cycle));
+        assert_eq!(find_cycle_hashed(&0, 100, step), Some(cycle));
+    }
+
+    #[test]
+    fn finds_a_state_that_maps_to_itself() {
+        let step = |n: &usize| if *n < 5 { n + 1 } else { 5 };
+        let cycle = Cycle { start: 5, length: 1 };
+
+        assert_eq!(brent(&0, 100, step), Some(cycle));
+        assert_eq!(floyd(&0, 100, step), Some(cycle));
+        assert_eq!(find_cycle_hashed(&0, 100, step), Some(cycle));
+    }
+
+    #[test]
+    fn gives_up_at_the_limit() {
+        let step = |n: &usize| n + 1;
+
+        assert_eq!(brent(&0, 50, step), None);
+        assert_eq!(floyd(&0, 50, step), None);
+        assert_eq!(find_cycle_hashed(&0, 50, step), None);
+        assert_eq!(brent(&0, 0, step), None);
+    }
+
+    #[test]
+    fn index_of_maps_steps_back_into_the_cycle() {
+        assert_eq!(CYCLE.index_of(2), 2);
+        assert_eq!(CYCLE.index_of(3), 3);
+        assert_eq!(CYCLE.index_of(8), 3);
+        assert_eq!(CYCLE.index_of(1_000_000_000), 3 + (1_000_000_000 - 3) % 5);
+    }
+
+    #[test]
+    fn nth_state_matches_stepping_one_at_a_time() {
+        for n in [0, 1, 3, 7, 8, 12, 100, 1_000_003] {
+            let expected = if n < 10_000 { advance(0, n, step) } else { 3 + (n - 3) % 5 };
+            assert_eq!(nth_state(0, n, step), expected, "step {n}");
+            assert_eq!(nth_state_brent(0, n, step), expected, "step {n}");
+        }
+    }
+
+    #[test]
+    fn nth_state_without_a_cycle_steps_all_the_way() {
+        assert_eq!(nth_state(0, 40, |n: &usize| n + 2), 80);
+        assert_eq!(nth_state_brent(0, 40, |n: &usize| n + 2), 80);
+    }
+}
@@ -0,0 +1,192 @@
+// Differential testing of a fast solver against a slow reference.
+//
+// When a day is sped up, the old straightforward code makes a good reference.
+// check() feeds both the same generated inputs and fails if their answers ever
+// differ. A failing input is shrunk first, by dropping lines and making numbers
+// smaller for as long as the answers still differ, so the panic message shows a
+// small case to debug rather than a full-size input.
+use crate::generate;
+use std::{
+    fmt::Debug,
+    panic::{self, AssertUnwindSafe},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    // Number of inputs to try
+    pub cases: usize,
+    // Inputs are generated with sizes from 1 up to this
+    pub max_size: usize,
+    // Seed for the first case, each case after that uses the next seed
+    pub seed: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { cases: 100, max_size: 10, seed: 1 }
+    }
+}
+
+// Run the reference and candidate on generated inputs for the day and panic
+// with the smallest input found where their answers differ.
+//
+// # Panics
+//
+// This function will panic if the answers differ, or if there is no generator
+// for the day
+pub fn check<A, R, C>(year: u16, day: u8, config: Config, reference: R, candidate: C)
+where
+    A: PartialEq + Debug,
+    R: Fn(&str) -> A,
+    C: Fn(&str) -> A,
+{
+    let generator = generate::find(year, day)
+        .unwrap_or_else(|| panic!("There is no input generator for {year} day {day}"));
+
+    let differs = |input: &str| -> bool {
+        // An input the reference can't handle isn't a useful counterexample
+        match run(&reference, input) {
+            Some(expected) => run(&candidate, input).is_none_or(|actual| actual != expected),
+            None => false,
+        }
+    };
+
+    for case in 0..config.cases {
+        let size = 1 + case % config.max_size.max(1);
+        let seed = config.seed + case as u64;
+        let input = generator.generate(size, seed);
+
+        if differs(&input) {
+            let minimal = shrink(&input, differs);
+            panic!(
+                "{year} day {day}: answers differ for the input generated with size {size} and seed {seed}.\n\
+                 Shrunk to:\n{minimal}\n\
+                 reference: {:?}\n\
+                 candidate: {:?}",
+                run(&reference, &minimal),
+                run(&candidate, &minimal),
+            );
+        }
+    }
+}
+
+// The answer, or None if the solver panicked
+fn run<A, F: Fn(&str) -> A>(solver: &F, input: &str) -> Option<A> {
+    panic::catch_unwind(AssertUnwindSafe(|| solver(input))).ok()
+}
+
+// Shrink an input for as long as it still fails. Lines are removed in chunks,
+// halving the chunk size down to single lines, and then the numbers in each
+// line are made smaller. This repeats until nothing more can be taken away.
+pub fn shrink<F: Fn(&str) -> bool>(input: &str, fails: F) -> String {
+    let mut lines: Vec<String> = input.lines().map(|l| l.to_string()).collect();
+
+    loop {
+        let mut progress = false;
+
+        // Drop chunks of lines
+        let mut chunk = lines.len().div_ceil(2).max(1);
+        while chunk >= 1 && !lines.is_empty() {
+            let mut start = 0;
+            while start < lines.len() {
+                let end = (start + chunk).min(lines.len());
+                let candidate: Vec<String> = lines[..start].iter().chain(&lines[end..]).cloned().collect();
+                if !candidate.is_empty() && fails(&candidate.join("\n")) {
+                    lines = candidate;
+                    progress = true;
+                } else {
+                    start = end;
+                }
+            }
+            if chunk == 1 {
+                break;
+            }
+            chunk /= 2;
+        }
+
+        // Make numbers smaller, one at a time
+        for i in 0..lines.len() {
+            for candidate_line in smaller_numbers(&lines[i]) {
+                let mut candidate = lines.clone();
+                candidate[i] = candidate_line;
+                if fails(&candidate.join("\n")) {
+                    lines = candidate;
+                    progress = true;
+                    break;
+                }
+            }
+        }
+
+        if !progress {
+            return lines.join("\n");
+        }
+    }
+}
+
+// Copies of the line with one number made smaller: set to 0, halved, or one
+// less. Numbers that are already 0 are left alone.
+fn smaller_numbers(line: &str) -> Vec<String> {
+    let mut candidates: Vec<String> = Vec::new();
+
+    let bytes = line.as_bytes();
+    let mut start = 0;
+    while start < bytes.len() {
+        if !bytes[start].is_ascii_digit() {
+            start += 1;
+            continue;
+        }
+        let mut end = start;
+        while end < bytes.len() && bytes[end].is_ascii_digit() {
+            end += 1;
+        }
+
+        if let Ok(n) = line[start..end].parse::<u64>()
+            && n > 0
+        {
+            let mut smaller: Vec<u64> = vec![0, n / 2, n - 1];
+            smaller.dedup();
+            for m in smaller {
+                candidates.push(format!("{}{m}{}", &line[..start], &line[end..]));
+            }
+        }
+        start = end;
+    }
+
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinks_to_the_line_that_fails() {
+        let input = "3\n8\n41\n7\n12";
+        let minimal = shrink(input, |s| s.lines().any(|l| l.parse::<u64>().unwrap_or(0) > 40));
+
+        assert_eq!(minimal, "41");
+    }
+
+    #[test]
+    fn shrinks_numbers_to_the_boundary() {
+        let input = "x = 100, y = 7";
+        let minimal = shrink(input, |s| {
+            s.split_whitespace().filter_map(|w| w.trim_end_matches(',').parse::<u64>().ok()).next() >= Some(50)
+        });
+
+        assert_eq!(minimal, "x = 50, y = 0");
+    }
+
+    #[test]
+    fn passes_when_the_solvers_agree() {
+        let sum = |input: &str| -> u64 { input.lines().filter_map(|l| l.parse::<u64>().ok()).sum() };
+        check(2015, 17, Config::default(), sum, |input: &str| input.lines().map(|l| l.parse::<u64>().unwrap()).sum());
+    }
+
+    #[test]
+    #[should_panic(expected = "answers differ")]
+    fn fails_when_the_solvers_disagree() {
+        let count = |input: &str| input.lines().count();
+        check(2015, 17, Config::default(), count, |input: &str| input.lines().count().min(5));
+    }
+}
@@ -0,0 +1,230 @@
+use crate::validate;
+use std::{
+    error::Error,
+    fmt,
+    io::{self, IsTerminal},
+    process,
+};
+
+// Where something is in the input: the line and column it starts at, counting
+// from 1, and how many characters it covers. text is the whole line, so reports
+// can show it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub input: String,
+    pub line: usize,
+    pub column: usize,
+    pub len: usize,
+    pub text: String,
+}
+
+impl Span {
+    // The span of part, a slice of line_text, which is the given line of the
+    // input loaded by load_input_file
+    pub fn of(line: usize, line_text: &str, part: &str) -> Span {
+        Span {
+            input: validate::input_name(),
+            line,
+            column: validate::column(line_text, part),
+            len: part.chars().count(),
+            text: line_text.to_string(),
+        }
+    }
+
+    // The span of len characters from the given line and column of the input
+    // loaded by load_input_file
+    pub fn at(line: usize, column: usize, len: usize) -> Span {
+        Span {
+            input: validate::input_name(),
+            line,
+            column,
+            len,
+            text: validate::source_line(line).unwrap_or_default(),
+        }
+    }
+
+    // The text the span covers
+    pub fn snippet(&self) -> String {
+        self.text.chars().skip(self.column.saturating_sub(1)).take(self.len).collect()
+    }
+}
+
+// Errors that solvers can report instead of panicking or silently carrying on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    // An arithmetic operation overflowed its integer type
+    Overflow {
+        operation: &'static str,
+        lhs: String,
+        rhs: String,
+    },
+    // The solver was asked for a part it doesn't solve
+    Unsolved {
+        part: u8,
+    },
+    // The input parsed, but there is no way to solve it as it is
+    Unsolvable {
+        reason: String,
+    },
+    // Something in the input can't be used. expected is what should have been
+    // at the span, and hint a suggestion for fixing it. The span is boxed to
+    // keep the error small.
+    Input {
+        span: Box<Span>,
+        message: String,
+        expected: Option<String>,
+        hint: Option<String>,
+    },
+}
+
+impl AocError {
+    // Print the error to stderr and exit the process with code 1
+    pub fn exit(&self) -> ! {
+        eprintln!("{}", self.report(io::stderr().is_terminal()));
+        process::exit(1);
+    }
+
+    // The error as it is shown to the user. Input errors show the line from the
+    // input with the span underlined, in colour if asked for:
+    //
+    //   ERROR: register does not exist
+    //    --> real-input.txt:3:5
+    //     |
+    //   3 | tpl c
+    //     |     ^ expected a or b
+    //     = hint: the computer only has registers a and b
+    pub fn report(&self, color: bool) -> String {
+        let paint = |code: &str, text: &str| {
+            if color { format!("\x1b[{code}m{text}\x1b[0m") } else { text.to_string() }
+        };
+        let error = paint("1;31", "ERROR:");
+
+        let AocError::Input { span, message, expected, hint } = self else {
+            return format!("{error} {self}");
+        };
+
+        let number = span.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let bar = paint("1;34", "|");
+        let marker = format!(
+            "{}{}",
+            " ".repeat(span.column.saturating_sub(1)),
+            "^".repeat(span.len.max(1)),
+        );
+        let label = match expected {
+            Some(expected) => format!("{marker} expected {expected}"),
+            None => marker,
+        };
+
+        let mut report = vec![
+            format!("{error} {message}"),
+            format!("{gutter}{} {}:{}:{}", paint("1;34", "-->"), span.input, span.line, span.column),
+            format!("{gutter} {bar}"),
+            format!("{} {bar} {}", paint("1;34", &number), span.text),
+            format!("{gutter} {bar} {}", paint("1;31", &label)),
+        ];
+        if let Some(hint) = hint {
+            report.push(format!("{gutter} {} {}", paint("1;34", "="), paint("1;36", &format!("hint: {hint}"))));
+        }
+
+        report.join("\n")
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Overflow { operation, lhs, rhs } => {
+                write!(f, "Arithmetic overflow trying to {operation} {lhs} and {rhs}")
+            },
+            AocError::Unsolved { part } => {
+                write!(f, "There is no solution for part {part}")
+            },
+            AocError::Unsolvable { reason } => {
+                write!(f, "The input can't be solved: {reason}")
+            },
+            AocError::Input { span, message, expected, .. } => {
+                write!(f, "{}:{}:{}: {message}: {}", span.input, span.line, span.column, span.snippet())?;
+                match expected {
+                    Some(expected) => write!(f, ", expected {expected}"),
+                    None => Ok(()),
+                }
+            },
+        }
+    }
+}
+
+impl Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input_error(column: usize, len: usize, expected: Option<&str>, hint: Option<&str>) -> AocError {
+        AocError::Input {
+            span: Box::new(Span {
+                input: "real-input.txt".to_string(),
+                line: 12,
+                column,
+                len,
+                text: "tpl c".to_string(),
+            }),
+            message: "register does not exist".to_string(),
+            expected: expected.map(str::to_string),
+            hint: hint.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn report_underlines_the_span() {
+        let err = input_error(5, 1, Some("a or b"), Some("the computer only has registers a and b"));
+        assert_eq!(err.report(false), [
+            "ERROR: register does not exist",
+            "  --> real-input.txt:12:5",
+            "   |",
+            "12 | tpl c",
+            "   |     ^ expected a or b",
+            "   = hint: the computer only has registers a and b",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn report_without_expected_or_hint_is_just_the_marker() {
+        let err = input_error(1, 3, None, None);
+        assert_eq!(err.report(false), [
+            "ERROR: register does not exist",
+            "  --> real-input.txt:12:1",
+            "   |",
+            "12 | tpl c",
+            "   | ^^^",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn empty_spans_still_get_a_caret() {
+        let err = input_error(6, 0, Some("a register"), None);
+        assert!(err.report(false).ends_with("\n   |      ^ expected a register"), "{}", err.report(false));
+    }
+
+    #[test]
+    fn report_is_coloured_when_asked() {
+        let report = input_error(5, 1, Some("a or b"), Some("use a or b")).report(true);
+        assert!(report.starts_with("\x1b[1;31mERROR:\x1b[0m register does not exist\n"), "{report:?}");
+        assert!(report.contains("\x1b[1;31m    ^ expected a or b\x1b[0m"), "{report:?}");
+        assert!(report.contains("\x1b[1;36mhint: use a or b\x1b[0m"), "{report:?}");
+        assert!(!input_error(5, 1, None, None).report(false).contains('\x1b'));
+    }
+
+    #[test]
+    fn other_errors_report_their_message() {
+        let err = AocError::Unsolvable { reason: "no group adds up".to_string() };
+        assert_eq!(err.report(false), "ERROR: The input can't be solved: no group adds up");
+        assert_eq!(AocError::Unsolved { part: 2 }.report(false), "ERROR: There is no solution for part 2");
+    }
+
+    #[test]
+    fn display_shows_the_location_and_snippet() {
+        assert_eq!(input_error(5, 1, Some("a or b"), None).to_string(), "real-input.txt:12:5: register does not exist: c, expected a or b");
+        assert_eq!(input_error(1, 3, None, Some("ignored")).to_string(), "real-input.txt:12:1: register does not exist: tpl");
+    }
+}
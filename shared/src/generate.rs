@@ -0,0 +1,38 @@
+// Random puzzle inputs.
+//
+// Every day has a generator that writes a valid input of a given size from a
+// seed. The same seed and size always give the same input, so a generated
+// input can be shared or reproduced with just those two numbers. What the size
+// means depends on the day (lines, grid width, number of cities, ...), see
+// Generator::size.
+mod y2015;
+
+use crate::rng::Rng;
+
+pub(crate) use y2015::names;
+
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    // What the size counts, for help text
+    pub size: &'static str,
+    pub default_size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    // Inputs are written without a trailing newline, like the example inputs,
+    // as some days would read it as part of the input
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let input = (self.generate)(&mut rng, size);
+
+        input.trim_end_matches('\n').to_string()
+    }
+}
+
+pub static GENERATORS: &[Generator] = &y2015::GENERATORS;
+
+pub fn find(year: u16, day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.year == year && g.day == day)
+}
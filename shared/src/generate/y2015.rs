@@ -0,0 +1,558 @@
+// Input generators for 2015
+use super::Generator;
+use crate::rng::Rng;
+use std::collections::HashSet;
+
+const fn generator(day: u8, size: &'static str, default_size: usize, generate: fn(&mut Rng, usize) -> String) -> Generator {
+    Generator { year: 2015, day, size, default_size, generate }
+}
+
+pub static GENERATORS: [Generator; 25] = [
+    generator(1, "instructions", 7000, day01),
+    generator(2, "presents", 1000, day02),
+    generator(3, "moves", 8192, day03),
+    generator(4, "letters in the key", 8, day04),
+    generator(5, "strings", 1000, day05),
+    generator(6, "instructions", 300, day06),
+    generator(7, "gates", 340, day07),
+    generator(8, "strings", 300, day08),
+    generator(9, "cities", 8, day09),
+    generator(10, "digits", 10, day10),
+    generator(11, "letters in the password", 8, day11),
+    generator(12, "values", 2000, day12),
+    generator(13, "people", 8, day13),
+    generator(14, "reindeer", 9, day14),
+    generator(15, "ingredients", 4, day15),
+    generator(16, "aunts", 500, day16),
+    generator(17, "containers", 20, day17),
+    generator(18, "grid width", 100, day18),
+    generator(19, "replacement steps", 50, day19),
+    generator(20, "largest target", 1_000_000, day20),
+    generator(21, "boss hit points", 100, day21),
+    generator(22, "boss hit points", 55, day22),
+    generator(23, "instructions per branch", 20, day23),
+    generator(24, "packages", 29, day24),
+    generator(25, "largest row or column", 3000, day25),
+];
+
+const SYLLABLES: &[&str] = &[
+    "al", "ar", "be", "bo", "ca", "da", "el", "fa", "ga", "ha", "is", "jo",
+    "ka", "la", "li", "ma", "mo", "na", "no", "or", "pa", "ra", "ri", "sa",
+    "ta", "to", "ul", "va", "ve", "ya", "zo", "zu",
+];
+
+// Distinct capitalised names made of letters only, e.g. "Tamora"
+pub(crate) fn names(rng: &mut Rng, count: usize) -> Vec<String> {
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut names: Vec<String> = Vec::new();
+
+    while names.len() < count {
+        let syllables = rng.range(2..=3) + names.len() / 1000;
+        let name: String = (0..syllables).map(|_| *rng.pick(SYLLABLES)).collect();
+        let mut chars = name.chars();
+        let name = match chars.next() {
+            Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+            None => continue,
+        };
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    names
+}
+
+fn lowercase_string(rng: &mut Rng, len: usize, alphabet: &[u8]) -> String {
+    (0..len).map(|_| *rng.pick(alphabet) as char).collect()
+}
+
+fn day01(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| if rng.chance(1, 2) { '(' } else { ')' }).collect::<String>() + "\n"
+}
+
+fn day02(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{}x{}x{}\n", rng.range(1..=30), rng.range(1..=30), rng.range(1..=30)))
+        .collect()
+}
+
+fn day03(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| *rng.pick(&['^', 'v', '<', '>'])).collect::<String>() + "\n"
+}
+
+fn day04(rng: &mut Rng, size: usize) -> String {
+    lowercase_string(rng, size.max(1), b"abcdefghijklmnopqrstuvwxyz") + "\n"
+}
+
+// Half of the strings get three vowels and a double letter, so a good number
+// of them are nice rather than almost none
+fn day05(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        let mut s: Vec<u8> = lowercase_string(rng, 16, b"abcdefghijklmnopqrstuvwxyz").into_bytes();
+        if rng.chance(1, 2) {
+            for _ in 0..3 {
+                s[rng.range(0..=15)] = *rng.pick(b"aeiou");
+            }
+            let i = rng.range(0..=14);
+            s[i + 1] = s[i];
+        }
+        text += &String::from_utf8(s).unwrap_or_default();
+        text += "\n";
+    }
+
+    text
+}
+
+fn day06(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        let action = *rng.pick(&["turn on", "turn off", "toggle"]);
+        let (x1, x2) = (rng.range(0..=999), rng.range(0..=999));
+        let (y1, y2) = (rng.range(0..=999), rng.range(0..=999));
+        text += &format!("{action} {},{} through {},{}\n", x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2));
+    }
+
+    text
+}
+
+// Wire names in order: c, d, ..., z, aa, ab, ... Wires a and b are left for the
+// puzzle's own use.
+fn wire_name(index: usize) -> String {
+    let mut n = index + 3;
+    let mut name: Vec<u8> = Vec::new();
+    while n > 0 {
+        n -= 1;
+        name.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+    name.reverse();
+
+    String::from_utf8(name).unwrap_or_default()
+}
+
+// A netlist where every gate only reads wires defined before it, so the circuit
+// is acyclic. Wire b is a signal (part 2 overrides it) and wire a is the last
+// gate. The lines are shuffled, as the puzzle's are.
+fn day07(rng: &mut Rng, size: usize) -> String {
+    let gates = size.max(3);
+    let inputs = gates / 10 + 1;
+
+    let mut defined: Vec<String> = Vec::new();
+    let mut lines: Vec<String> = Vec::new();
+    for i in 0..inputs {
+        let wire = wire_name(i);
+        lines.push(format!("{} -> {wire}", rng.range(0..=65535)));
+        defined.push(wire);
+    }
+    lines.push(format!("{} -> b", rng.range(0..=65535)));
+    defined.push("b".to_string());
+
+    for i in 0..gates {
+        let output = if i + 1 == gates { "a".to_string() } else { wire_name(inputs + i) };
+
+        // First inputs lean towards recent wires so the circuit is deep, and
+        // second inputs can be any earlier wire. b is read all the way through
+        // so that it still affects a, and part 2 changes the answer. Shifts are
+        // kept short so the signal doesn't just drain to 0.
+        let pick = |rng: &mut Rng| -> String { rng.pick(&defined).clone() };
+        let first = if i == 0 || rng.chance(1, 10) {
+            "b".to_string()
+        } else {
+            defined[defined.len() - rng.range(1..=defined.len().min(8))].clone()
+        };
+
+        let gate = match rng.below(6) {
+            0 => format!("NOT {first}"),
+            1 => format!("{first} LSHIFT {}", rng.range(1..=3)),
+            2 => format!("{first} RSHIFT {}", rng.range(1..=3)),
+            3 if rng.chance(1, 8) => format!("1 AND {first}"),
+            3 => format!("{first} AND {}", pick(rng)),
+            4 => format!("{first} OR {}", pick(rng)),
+            _ => first,
+        };
+        lines.push(format!("{gate} -> {output}"));
+        defined.push(output);
+    }
+
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
+
+fn day08(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        text.push('"');
+        for _ in 0..rng.range(0..=30) {
+            match rng.below(10) {
+                0 => text += "\\\\",
+                1 => text += "\\\"",
+                2 => text += &format!("\\x{:02x}", rng.below(256)),
+                _ => text.push(*rng.pick(b"abcdefghijklmnopqrstuvwxyz") as char),
+            }
+        }
+        text += "\"\n";
+    }
+
+    text
+}
+
+fn day09(rng: &mut Rng, size: usize) -> String {
+    let cities = names(rng, size.max(2));
+
+    let mut text = String::new();
+    for (i, a) in cities.iter().enumerate() {
+        for b in &cities[i + 1..] {
+            text += &format!("{a} to {b} = {}\n", rng.range(10..=150));
+        }
+    }
+
+    text
+}
+
+// Runs of 1, 2 or 3 of the digits 1 to 3, like the puzzle's look-and-say seeds
+fn day10(rng: &mut Rng, size: usize) -> String {
+    let mut digits = String::new();
+    let mut last: usize = 0;
+    while digits.len() < size.max(1) {
+        let mut digit = rng.range(1..=3);
+        while digit == last {
+            digit = rng.range(1..=3);
+        }
+        let run = rng.range(1..=3).min(size.max(1) - digits.len());
+        digits += &digit.to_string().repeat(run);
+        last = digit;
+    }
+
+    digits + "\n"
+}
+
+fn day11(rng: &mut Rng, size: usize) -> String {
+    lowercase_string(rng, size.max(1), b"abcdefghjkmnpqrstuvwxyz") + "\n"
+}
+
+// Nested arrays and objects with about `size` values in total. Some object
+// values are "red" so part 2 has something to skip.
+fn day12(rng: &mut Rng, size: usize) -> String {
+    let mut remaining = size.max(1);
+    let mut json = String::from("[");
+    while remaining > 0 {
+        if json.len() > 1 {
+            json.push(',');
+        }
+        json_container(rng, 1, &mut remaining, &mut json);
+    }
+
+    json + "]\n"
+}
+
+fn json_container(rng: &mut Rng, depth: usize, remaining: &mut usize, json: &mut String) {
+    let object = rng.chance(1, 2);
+    json.push(if object { '{' } else { '[' });
+
+    let children = rng.range(1..=6);
+    for i in 0..children {
+        if *remaining == 0 {
+            break;
+        }
+        if i > 0 {
+            json.push(',');
+        }
+        if object {
+            json.push_str(&format!("\"{}\":", (b'a' + i as u8) as char));
+        }
+
+        if depth < 8 && rng.chance(1, 3) {
+            json_container(rng, depth + 1, remaining, json);
+        } else {
+            *remaining -= 1;
+            if rng.chance(1, 4) {
+                json.push_str(&format!("\"{}\"", rng.pick(&["red", "green", "blue", "orange", "violet", "yellow"])));
+            } else {
+                json.push_str(&rng.range_i64(-50..=200).to_string());
+            }
+        }
+    }
+
+    json.push(if object { '}' } else { ']' });
+}
+
+fn day13(rng: &mut Rng, size: usize) -> String {
+    let people = names(rng, size.max(2));
+
+    let mut text = String::new();
+    for a in &people {
+        for b in &people {
+            if a == b {
+                continue;
+            }
+            let change = if rng.chance(1, 2) { "gain" } else { "lose" };
+            text += &format!("{a} would {change} {} happiness units by sitting next to {b}.\n", rng.range(1..=100));
+        }
+    }
+
+    text
+}
+
+fn day14(rng: &mut Rng, size: usize) -> String {
+    names(rng, size.max(1)).into_iter()
+        .map(|name| format!(
+            "{name} can fly {} km/s for {} seconds, but then must rest for {} seconds.\n",
+            rng.range(5..=30),
+            rng.range(3..=20),
+            rng.range(20..=170),
+        ))
+        .collect()
+}
+
+// Each ingredient is good for one property and bad for the others, as in the
+// puzzle, so the best cookie needs a mix
+fn day15(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    for (i, name) in names(rng, size.max(1)).into_iter().enumerate() {
+        let good = i % 4;
+        let mut properties: Vec<i64> = (0..4)
+            .map(|p| if p == good { rng.range_i64(2..=5) } else { rng.range_i64(-1..=0) })
+            .collect();
+        // Alternate between light and heavy ingredients, so 500 calories can
+        // be hit
+        properties.push(if i % 2 == 0 { rng.range_i64(1..=4) } else { rng.range_i64(6..=9) });
+        text += &format!(
+            "{name}: capacity {}, durability {}, flavor {}, texture {}, calories {}\n",
+            properties[0], properties[1], properties[2], properties[3], properties[4],
+        );
+    }
+
+    text
+}
+
+// The things the ticker tape reports, and their values
+const SUE_TICKER: [(&str, usize); 10] = [
+    ("children", 3), ("cats", 7), ("samoyeds", 2), ("pomeranians", 3), ("akitas", 0),
+    ("vizslas", 0), ("goldfish", 5), ("trees", 3), ("cars", 2), ("perfumes", 1),
+];
+
+// Every aunt has three things remembered. One aunt is planted to match the
+// tape exactly (part 1) and another to match with part 2's ranges. Every other
+// aunt gets at least one exact-match thing that contradicts the tape, so
+// neither part finds more than one.
+fn day16(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let part1_sue = rng.range(1..=size);
+    let mut part2_sue = rng.range(1..=size);
+    while part2_sue == part1_sue {
+        part2_sue = rng.range(1..=size);
+    }
+
+    let mut text = String::new();
+    for sue in 1..=size {
+        let mut things: Vec<usize> = (0..SUE_TICKER.len()).collect();
+        rng.shuffle(&mut things);
+        let mut things = things[..3].to_vec();
+
+        let values: Vec<usize> = if sue == part1_sue {
+            // Cats at exactly the tape's reading, which part 2 reads as "more
+            // than", so this aunt doesn't match part 2 as well
+            things = vec![0, 1, 2];
+            things.iter().map(|&t| SUE_TICKER[t].1).collect()
+        } else if sue == part2_sue {
+            things = vec![1, 3, 8];
+            vec![SUE_TICKER[1].1 + rng.range(1..=3), rng.range(0..=SUE_TICKER[3].1 - 1), SUE_TICKER[8].1]
+        } else {
+            // Things read the same way in both parts
+            let exact = [0, 2, 4, 5, 8, 9];
+            if !things.iter().any(|t| exact.contains(t)) {
+                things[0] = *rng.pick(&exact);
+            }
+            let mut contradicted = false;
+            things.iter()
+                .map(|&t| {
+                    let (_, value) = SUE_TICKER[t];
+                    if exact.contains(&t) && !contradicted {
+                        contradicted = true;
+                        (value + rng.range(1..=9)) % 10
+                    } else {
+                        rng.range(0..=10)
+                    }
+                })
+                .collect()
+        };
+
+        let things: Vec<String> = things.iter().zip(values)
+            .map(|(&t, value)| format!("{}: {value}", SUE_TICKER[t].0))
+            .collect();
+        text += &format!("Sue {sue}: {}\n", things.join(", "));
+    }
+
+    text
+}
+
+fn day17(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| format!("{}\n", rng.range(5..=50))).collect()
+}
+
+fn day18(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size.max(1) {
+        text += &(0..size.max(1)).map(|_| if rng.chance(1, 2) { '#' } else { '.' }).collect::<String>();
+        text += "\n";
+    }
+
+    text
+}
+
+// Elements that have replacements, and ones that only ever appear on the right
+// hand side, mirroring the Rn/Y/Ar structure of the puzzle's grammar
+const DAY19_ELEMENTS: &[&str] = &["Al", "B", "Ca", "F", "H", "Mg", "N", "O", "P", "Si", "Th", "Ti"];
+
+// A grammar plus a molecule derived from e with `size` replacements, so the
+// molecule can always be made
+fn day19(rng: &mut Rng, size: usize) -> String {
+    let pair = |rng: &mut Rng| -> Vec<&'static str> {
+        match rng.below(6) {
+            0 => vec![*rng.pick(DAY19_ELEMENTS), "Rn", *rng.pick(DAY19_ELEMENTS), "Ar"],
+            1 => vec![*rng.pick(DAY19_ELEMENTS), "Rn", *rng.pick(DAY19_ELEMENTS), "Y", *rng.pick(DAY19_ELEMENTS), "Ar"],
+            _ => vec![*rng.pick(DAY19_ELEMENTS), *rng.pick(DAY19_ELEMENTS)],
+        }
+    };
+
+    let mut rules: Vec<(&str, Vec<&str>)> = Vec::new();
+    for _ in 0..rng.range(2..=3) {
+        rules.push(("e", vec![*rng.pick(DAY19_ELEMENTS), *rng.pick(DAY19_ELEMENTS)]));
+    }
+    for element in DAY19_ELEMENTS {
+        for _ in 0..rng.range(1..=4) {
+            rules.push((element, pair(rng)));
+        }
+    }
+
+    let mut molecule: Vec<&str> = vec!["e"];
+    for _ in 0..size.max(1) {
+        let positions: Vec<usize> = (0..molecule.len())
+            .filter(|&i| rules.iter().any(|(from, _)| *from == molecule[i]))
+            .collect();
+        let position = *rng.pick(&positions);
+        let options: Vec<&Vec<&str>> = rules.iter()
+            .filter(|(from, _)| *from == molecule[position])
+            .map(|(_, to)| to)
+            .collect();
+        let replacement = (*rng.pick(&options)).clone();
+        molecule.splice(position..=position, replacement);
+    }
+
+    let mut text: String = rules.iter().map(|(from, to)| format!("{from} => {}\n", to.concat())).collect();
+    text += "\n";
+    text += &molecule.concat();
+    text + "\n"
+}
+
+fn day20(rng: &mut Rng, size: usize) -> String {
+    format!("{}\n", rng.range(size.max(2) / 2..=size.max(2)))
+}
+
+fn day21(rng: &mut Rng, size: usize) -> String {
+    format!("Hit Points: {}\nDamage: {}\nArmor: {}\n", size.max(1), rng.range(4..=10), rng.range(0..=4))
+}
+
+fn day22(rng: &mut Rng, size: usize) -> String {
+    format!("Hit Points: {}\nDamage: {}\n", size.max(1), rng.range(7..=10))
+}
+
+// The puzzle's program shape: the first jump picks a branch depending on
+// whether a starts at 0 or 1, each branch builds a number in a with inc and tpl,
+// and the loop at the end counts the Collatz steps from it to 1. Values are
+// kept small enough that the loop can't overflow.
+fn day23(rng: &mut Rng, size: usize) -> String {
+    const LIMIT: u64 = 1_000_000_000;
+
+    let branch = |rng: &mut Rng, start: u64| -> Vec<String> {
+        let mut a = start;
+        let mut ops: Vec<String> = Vec::new();
+        if a == 0 {
+            ops.push("inc a".to_string());
+            a = 1;
+        }
+        while ops.len() < size.max(2) || a < 2 {
+            if a * 3 < LIMIT && rng.chance(1, 2) {
+                ops.push("tpl a".to_string());
+                a *= 3;
+            } else {
+                ops.push("inc a".to_string());
+                a += 1;
+            }
+        }
+        ops
+    };
+
+    let first = branch(rng, 0);
+    let second = branch(rng, 1);
+
+    let mut lines: Vec<String> = vec![format!("jio a, +{}", first.len() + 2)];
+    lines.extend(first);
+    lines.push(format!("jmp +{}", second.len() + 1));
+    lines.extend(second);
+    lines.extend([
+        "jio a, +8", "inc b", "jie a, +4", "tpl a", "inc a", "jmp +2", "hlf a", "jmp -7",
+    ].map(|s| s.to_string()));
+
+    lines.join("\n") + "\n"
+}
+
+// Distinct weights made of twelve groups with the same sum, so the packages
+// can be split into three or four equal groups
+fn day24(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(24);
+    let max_weight = size * 4 + 20;
+    let longest = size.div_ceil(12);
+    let sum = longest * max_weight / 3;
+
+    'attempt: loop {
+        let mut used: HashSet<usize> = HashSet::new();
+        let mut weights: Vec<usize> = Vec::new();
+
+        for group in 0..12 {
+            let len = size / 12 + usize::from(group < size % 12);
+            let upper = (2 * sum / len).clamp(1, max_weight);
+
+            let mut tries = 0;
+            loop {
+                tries += 1;
+                if tries > 10_000 {
+                    continue 'attempt;
+                }
+
+                let mut chosen: Vec<usize> = Vec::new();
+                while chosen.len() < len - 1 {
+                    let w = rng.range(1..=upper);
+                    if !used.contains(&w) && !chosen.contains(&w) {
+                        chosen.push(w);
+                    }
+                }
+                let total: usize = chosen.iter().sum();
+                if total >= sum {
+                    continue;
+                }
+                let last = sum - total;
+                if last > max_weight || used.contains(&last) || chosen.contains(&last) {
+                    continue;
+                }
+
+                chosen.push(last);
+                used.extend(chosen.iter().copied());
+                weights.extend(chosen);
+                break;
+            }
+        }
+
+        weights.sort();
+        return weights.iter().map(|w| format!("{w}\n")).collect();
+    }
+}
+
+fn day25(rng: &mut Rng, size: usize) -> String {
+    format!(
+        "To continue, please consult the code grid in the manual.  Enter the code at row {}, column {}.\n",
+        rng.range(1..=size.max(1)),
+        rng.range(1..=size.max(1)),
+    )
+}
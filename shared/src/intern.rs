@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+// Map names to dense ids (0, 1, 2, ...) in the order they are first seen, and
+// back again. Solvers can then keep their data in Vecs indexed by id and only
+// look the names up again for output.
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    ids: HashMap<String, usize>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Get the id for a name, giving it the next free id if it hasn't been seen
+    // before
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id = self.names.len();
+        self.ids.insert(name.to_string(), id);
+        self.names.push(name.to_string());
+
+        id
+    }
+
+    // Get the id for a name without adding it
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    // Get the name for an id
+    //
+    // # Panics
+    //
+    // This function will panic if the id was not handed out by this interner
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    // Get the names for a list of ids, in the same order
+    //
+    // # Panics
+    //
+    // This function will panic if any id was not handed out by this interner
+    pub fn names_of(&self, ids: &[usize]) -> Vec<&str> {
+        ids.iter().map(|&id| self.name(id)).collect()
+    }
+
+    // Number of names interned so far. Every id is less than this.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    // Iterate over (id, name) pairs in id order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.names.iter().enumerate().map(|(id, name)| (id, name.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_dense_in_the_order_names_are_first_seen() {
+        let mut interner = Interner::new();
+        assert!(interner.is_empty());
+
+        assert_eq!(interner.intern("London"), 0);
+        assert_eq!(interner.intern("Dublin"), 1);
+        assert_eq!(interner.intern("London"), 0);
+        assert_eq!(interner.intern("Belfast"), 2);
+
+        assert_eq!(interner.len(), 3);
+        assert_eq!(interner.iter().collect::<Vec<_>>(), vec![(0, "London"), (1, "Dublin"), (2, "Belfast")]);
+    }
+
+    #[test]
+    fn looks_names_up_both_ways() {
+        let mut interner = Interner::new();
+        let alice = interner.intern("Alice");
+        let bob = interner.intern("Bob");
+
+        assert_eq!(interner.get("Bob"), Some(bob));
+        assert_eq!(interner.get("Carol"), None);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.name(alice), "Alice");
+        assert_eq!(interner.names_of(&[bob, alice, bob]), vec!["Bob", "Alice", "Bob"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unknown_ids_panic() {
+        let mut interner = Interner::new();
+        interner.intern("Alice");
+        interner.name(1);
+    }
+}
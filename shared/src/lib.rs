@@ -1,3 +1,29 @@
+pub mod alloc;
+pub mod answer;
+pub mod arith;
+pub mod checkpoint;
+pub mod cycle;
+pub mod differential;
+pub mod error;
+pub mod generate;
+pub mod intern;
+pub mod math;
+pub mod parallel;
+pub mod progress;
+pub mod property;
+pub mod rng;
+pub mod snapshot;
+pub mod solution;
+pub mod stats;
+pub mod validate;
+
+pub use answer::Answer;
+pub use error::AocError;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: alloc::CountingAllocator = alloc::CountingAllocator;
+
 use std::{
     env,
     process,
@@ -5,6 +31,62 @@ use std::{
     path::Path,
 };
 
+// Collect the arguments, taking out the common flags that every day accepts
+// and applying them. These are:
+//
+//   --part <p>     the part to solve: 1, 2 or both (the default)
+//   --threads <n>  the number of worker threads for parallel searches
+//   --resume       resume long searches from their last checkpoint
+//   --timings      print the time taken by each phase on stderr
+//   --stats        print the allocations made by each phase on stderr
+//   --stats-json   print all of the per-phase stats on stderr as JSON
+//   --check        only parse the input and list any lines that can't be used
+//   --lenient      skip lines that can't be used instead of stopping
+//   --answers      also print each answer as "Part <n>: <answer>", for the runner
+//
+// # Exits - code 1
+//
+// This function will exit the process if a common flag is missing its value
+pub fn collect_args() -> Vec<String> {
+    let mut args: Vec<String> = Vec::new();
+
+    let mut iter = env::args();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--part" => {
+                match iter.next().as_deref() {
+                    Some("1") => solution::set_part(Some(1)),
+                    Some("2") => solution::set_part(Some(2)),
+                    Some("both") => solution::set_part(None),
+                    _ => {
+                        eprintln!("--part must be followed by 1, 2 or both");
+                        process::exit(1);
+                    }
+                }
+            },
+            "--threads" => {
+                match iter.next().and_then(|n| n.parse::<usize>().ok()) {
+                    Some(n) if n > 0 => parallel::set_threads(n),
+                    _ => {
+                        eprintln!("--threads must be followed by a number of threads greater than 0");
+                        process::exit(1);
+                    }
+                }
+            },
+            "--resume" => checkpoint::set_resume(true),
+            "--timings" => stats::set_timings(true),
+            "--stats" => stats::set_alloc_table(true),
+            "--stats-json" => stats::set_json(true),
+            "--check" => validate::set_check(true),
+            "--lenient" => validate::set_lenient(true),
+            "--answers" => solution::set_answer_lines(true),
+            _ => args.push(arg),
+        }
+    }
+
+    args
+}
+
 // Read the arguments and confirm an input file was provided
 // The function allows for an optional 3rd argument, --debug
 // 
@@ -12,11 +94,11 @@ use std::{
 //
 // This function will exit the process if the number of arguments are incorrect
 pub fn parse_args() -> Vec<String> {
-    let args: Vec<String> = env::args().collect();
+    let args: Vec<String> = collect_args();
 
     if args.len() < 2 || (args.len() == 3 && &args[2] != "--debug") {
-        eprintln!("Usage: {} <input file> [--debug]", &args[0]);
-        eprintln!("       OR cargo run -- <input file> [--debug]");
+        eprintln!("Usage: {} <input file> [--debug] [--part <1|2|both>] [--threads <n>] [--resume] [--timings] [--stats] [--check] [--lenient]", &args[0]);
+        eprintln!("       OR cargo run -- <input file> [--debug] [--part <1|2|both>] [--threads <n>] [--resume] [--timings] [--stats] [--check] [--lenient]");
         process::exit(1);
     }
 
@@ -37,15 +119,16 @@ pub fn load_input_file(filename: &str) -> String {
         process::exit(1);
     }
 
-    let file_contents = match fs::read_to_string(filename) {
-        Ok(s) => s,
+    match fs::read_to_string(filename) {
+        Ok(s) => {
+            validate::set_input(filename, &s);
+            s
+        },
         Err(err) => {
             eprintln!("There was a problem reading from the input file: {err}");
             process::exit(1);
         }
-    };
-
-    file_contents
+    }
 }
 
 // Read the arguments and confirm an input file was provided or the number of iterations was provided
@@ -55,15 +138,23 @@ pub fn load_input_file(filename: &str) -> String {
 //
 // This function will exit the process if the number of arguments are incorrect
 pub fn parse_args_iterations() -> Vec<String> {
-    let args: Vec<String> = env::args().collect();
+    check_args_iterations(collect_args())
+}
 
+// Confirm the arguments have an input file and optionally the number of
+// iterations, for days that take flags of their own out first
+//
+// # Exits - code 1
+//
+// This function will exit the process if the number of arguments are incorrect
+pub fn check_args_iterations(args: Vec<String>) -> Vec<String> {
     if
         args.len() < 2
         || (args.len() == 3 && !(&args[2] == "--debug" || args[2].parse::<usize>().is_ok())) 
         || (args.len() == 4 && !(&args[2] == "--debug" || args[2].parse::<usize>().is_ok()) && !(&args[3] == "--debug" || args[3].parse::<usize>().is_ok()))
     {
-        eprintln!("Usage: {} <input file> [<iterations>] [--debug]", &args[0]);
-        eprintln!("       OR cargo run -- <input file> [iterations] [--debug]");
+        eprintln!("Usage: {} <input file> [<iterations>] [--debug] [--part <1|2|both>] [--threads <n>] [--resume] [--timings] [--stats] [--check] [--lenient]", &args[0]);
+        eprintln!("       OR cargo run -- <input file> [iterations] [--debug] [--part <1|2|both>] [--threads <n>] [--resume] [--timings] [--stats] [--check] [--lenient]");
         process::exit(1);
     }
 
@@ -0,0 +1,337 @@
+// Number theory and modular arithmetic helpers
+
+// Greatest common divisor
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+// Least common multiple. Returns None if the result does not fit in a u64.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+// Least common multiple of every value in the iterator. An empty iterator gives
+// 1. Returns None if the result does not fit in a u64.
+pub fn lcm_all<I>(values: I) -> Option<u64>
+where
+    I: IntoIterator<Item = u64>,
+{
+    values.into_iter().try_fold(1, lcm)
+}
+
+// Extended Euclidean algorithm. Returns (g, x, y) such that a*x + b*y = g,
+// where g is the greatest common divisor of a and b.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    // Keep the gcd positive
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// (a * b) % m without overflowing, by doing the multiplication in 128 bits.
+//
+// # Panics
+//
+// This function will panic if m is 0
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+// (a * b) % m, or None if a * b overflows a u64 before the reduction. Use this
+// to confirm the plain `*` and `%` are safe, otherwise use mul_mod.
+pub fn checked_mul_mod(a: u64, b: u64, m: u64) -> Option<u64> {
+    a.checked_mul(b)?.checked_rem(m)
+}
+
+// (base ^ exp) % m using binary exponentiation.
+//
+// # Panics
+//
+// This function will panic if m is 0
+pub fn mod_pow(base: u64, exp: u64, m: u64) -> u64 {
+    if m == 1 {
+        return 0;
+    }
+
+    let mut result: u64 = 1;
+    let mut base = base % m;
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+
+    result
+}
+
+// The value x such that (a * x) % m == 1, or None if a and m are not coprime.
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a as i128, m as i128);
+    if g != 1 {
+        return None;
+    }
+
+    Some(x.rem_euclid(m as i128) as u64)
+}
+
+// Chinese remainder theorem. Takes pairs of (remainder, modulus) and returns
+// (x, lcm of the moduli) where x satisfies x % modulus == remainder for every
+// pair. The moduli do not have to be coprime.
+//
+// Returns None if the congruences contradict each other, a modulus is 0, or the
+// combined modulus does not fit in a u64.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    let mut x: u64 = 0;
+    let mut modulus: u64 = 1;
+
+    for &(r, m) in congruences {
+        if m == 0 {
+            return None;
+        }
+        let r = r % m;
+
+        // Solve x + modulus * k = r (mod m) for k
+        let (g, p, _) = extended_gcd(modulus as i128, m as i128);
+        let diff = r as i128 - x as i128;
+        if diff % g != 0 {
+            return None;
+        }
+
+        // With moduli near 2^64 these products overflow an i128, so they are
+        // done in u128 on factors already reduced below the modulus
+        let step = (m as i128 / g) as u64;
+        let quotient = (diff / g).rem_euclid(step as i128) as u64;
+        let k = mul_mod(quotient, p.rem_euclid(step as i128) as u64, step);
+        let new_modulus = lcm(modulus, m)?;
+        x = ((x as u128 + modulus as u128 * k as u128) % new_modulus as u128) as u64;
+        modulus = new_modulus;
+    }
+
+    Some((x, modulus))
+}
+
+// Sum of the divisors of every number from 0 to limit (inclusive). Index 0 is
+// always 0.
+pub fn divisor_sum_sieve(limit: usize) -> Vec<u64> {
+    bounded_divisor_sum_sieve(limit, usize::MAX)
+}
+
+// Sum of the divisors d of every number n from 0 to limit (inclusive), only
+// counting divisors where n / d <= max_multiple. In other words each divisor
+// only contributes to its first max_multiple multiples.
+pub fn bounded_divisor_sum_sieve(limit: usize, max_multiple: usize) -> Vec<u64> {
+    let mut sums: Vec<u64> = vec![0; limit + 1];
+
+    for d in 1..=limit {
+        for n in (d..=limit).step_by(d).take(max_multiple) {
+            sums[n] += d as u64;
+        }
+    }
+
+    sums
+}
+
+// Smallest prime factor of every number from 0 to limit (inclusive). Indexes 0
+// and 1 are set to 0 as they have no prime factors.
+pub fn smallest_prime_factor_sieve(limit: usize) -> Vec<usize> {
+    let mut spf: Vec<usize> = vec![0; limit + 1];
+
+    for p in 2..=limit {
+        if spf[p] != 0 {
+            continue;
+        }
+        // p is prime
+        for n in (p..=limit).step_by(p) {
+            if spf[n] == 0 {
+                spf[n] = p;
+            }
+        }
+    }
+
+    spf
+}
+
+// Factorize n into (prime, exponent) pairs in ascending order using a table
+// from smallest_prime_factor_sieve.
+//
+// # Panics
+//
+// This function will panic if n is outside of the table
+pub fn factorize(n: usize, spf: &[usize]) -> Vec<(usize, u32)> {
+    let mut factors: Vec<(usize, u32)> = Vec::new();
+    let mut n = n;
+
+    while n > 1 {
+        let p = spf[n];
+        let mut exponent: u32 = 0;
+        while n.is_multiple_of(p) {
+            n /= p;
+            exponent += 1;
+        }
+        factors.push((p, exponent));
+    }
+
+    factors
+}
+
+// Factorize n into (prime, exponent) pairs in ascending order by trial
+// division. Useful when n is too large to sieve up to.
+pub fn factorize_trial(n: u64) -> Vec<(u64, u32)> {
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    let mut n = n;
+
+    let mut p: u64 = 2;
+    while p <= n / p {
+        if n.is_multiple_of(p) {
+            let mut exponent: u32 = 0;
+            while n.is_multiple_of(p) {
+                n /= p;
+                exponent += 1;
+            }
+            factors.push((p, exponent));
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extended_gcd_gives_bezout_coefficients() {
+        for (a, b) in [(240, 46), (46, 240), (17, 5), (0, 9), (9, 0), (-12, 18), (12, -18)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64) as i128, "gcd of {a} and {b}");
+            assert_eq!(a * x + b * y, g, "coefficients for {a} and {b}");
+        }
+    }
+
+    #[test]
+    fn mul_mod_does_not_overflow() {
+        assert_eq!(mul_mod(u64::MAX, u64::MAX, 1_000_000_007), ((u64::MAX as u128).pow(2) % 1_000_000_007) as u64);
+        assert_eq!(mul_mod(6, 7, 5), 2);
+        assert_eq!(checked_mul_mod(6, 7, 5), Some(2));
+        assert_eq!(checked_mul_mod(u64::MAX, 2, 5), None);
+        assert_eq!(checked_mul_mod(6, 7, 0), None);
+    }
+
+    #[test]
+    fn mod_pow_matches_repeated_multiplication() {
+        for (base, exp, m) in [(2, 10, 1000), (3, 0, 7), (5, 3, 1), (7, 13, 11), (u64::MAX, 5, 97)] {
+            let expected = (0..exp).fold(1 % m, |acc, _| mul_mod(acc, base, m));
+            assert_eq!(mod_pow(base, exp, m), expected, "{base}^{exp} mod {m}");
+        }
+        // Fermat's little theorem
+        assert_eq!(mod_pow(123_456_789, 1_000_000_006, 1_000_000_007), 1);
+    }
+
+    #[test]
+    fn mod_inverse_only_exists_for_coprime_values() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(10, 17).map(|x| x * 10 % 17), Some(1));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_inverse(4, 0), None);
+    }
+
+    #[test]
+    fn crt_solves_coprime_moduli() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn crt_handles_moduli_that_share_factors() {
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(7, 3), (0, 0)]), None);
+    }
+
+    #[test]
+    fn crt_handles_large_moduli() {
+        let (a, b) = (4_294_967_291, 4_294_967_279);
+        let (x, m) = crt(&[(a - 1, a), (b - 5, b)]).unwrap();
+        assert_eq!(m, a * b);
+        assert_eq!((x % a, x % b), (a - 1, b - 5));
+        assert_eq!(crt(&[(1, a), (1, b), (1, 4_294_967_231)]), None);
+    }
+
+    #[test]
+    fn crt_handles_moduli_above_2_63() {
+        // The largest prime below 2^64, and 2^64 - 1 = 3 * 5 * 17 * 257 * 641 * 65537 * 6700417
+        let prime: u64 = 18_446_744_073_709_551_557;
+        let x: u64 = 12_345_678_901_234_567_890;
+
+        assert_eq!(crt(&[(x, prime), (x % prime, prime)]), Some((x, prime)));
+        assert_eq!(crt(&[(x % 641, 641), (x, u64::MAX)]), Some((x, u64::MAX)));
+        assert_eq!(crt(&[(x, u64::MAX), (x % 6_700_417, 6_700_417)]), Some((x, u64::MAX)));
+        assert_eq!(crt(&[(x % 65_537, 65_537), (x % (u64::MAX / 65_537), u64::MAX / 65_537)]), Some((x, u64::MAX)));
+        assert_eq!(crt(&[(1, 2), (x, prime)]), None);
+    }
+
+    #[test]
+    fn divisor_sum_sieves_match_brute_force() {
+        let sums = divisor_sum_sieve(30);
+        let bounded = bounded_divisor_sum_sieve(30, 3);
+        for n in 1..=30 {
+            let divisors = (1..=n).filter(|d| n % d == 0);
+            assert_eq!(sums[n], divisors.clone().sum::<usize>() as u64, "divisors of {n}");
+            assert_eq!(bounded[n], divisors.filter(|d| n / d <= 3).sum::<usize>() as u64, "bounded divisors of {n}");
+        }
+        assert_eq!(sums[0], 0);
+    }
+
+    #[test]
+    fn factorize_agrees_with_trial_division() {
+        let spf = smallest_prime_factor_sieve(1000);
+        assert_eq!(&spf[..8], &[0, 0, 2, 3, 2, 5, 2, 7]);
+        for n in 1..=1000 {
+            let sieved: Vec<(u64, u32)> = factorize(n, &spf).into_iter().map(|(p, e)| (p as u64, e)).collect();
+            assert_eq!(sieved, factorize_trial(n as u64), "factors of {n}");
+            assert_eq!(sieved.iter().map(|&(p, e)| p.pow(e)).product::<u64>(), n as u64);
+        }
+        assert_eq!(factorize(360, &spf), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factorize_trial(600_851_475_143), vec![(71, 1), (839, 1), (1471, 1), (6857, 1)]);
+    }
+
+    #[test]
+    fn factorize_trial_handles_prime_factors_above_2_32() {
+        // p * p overflows a u64 before p reaches these
+        assert_eq!(factorize_trial(18_446_744_073_709_551_557), vec![(18_446_744_073_709_551_557, 1)]);
+        assert_eq!(factorize_trial(u64::MAX), vec![(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65_537, 1), (6_700_417, 1)]);
+    }
+}
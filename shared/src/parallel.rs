@@ -0,0 +1,197 @@
+// Data-parallel helpers for brute-force searches.
+//
+// The search space is split into chunks that are handed out to worker threads
+// in order. Results never depend on the number of threads or how the chunks
+// happened to be scheduled.
+use crate::checkpoint::Checkpoint;
+use std::{
+    ops::Range,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+};
+
+// Indexes handed to a worker at a time by find_first
+const FIND_CHUNK_SIZE: usize = 1024;
+
+// Indexes searched between checkpoints by find_first_checkpointed
+const CHECKPOINT_BLOCK_SIZE: usize = 1 << 16;
+
+// Number of chunks per thread for map_reduce, so that uneven chunks balance out
+const CHUNKS_PER_THREAD: usize = 4;
+
+// 0 means no thread count has been set
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+// Set the number of worker threads, usually from the --threads flag
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads, Ordering::Relaxed);
+}
+
+// The number of worker threads to use. Defaults to the number of cores if
+// --threads was not given.
+pub fn threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+        n => n,
+    }
+}
+
+// Find the smallest index in the range that satisfies the predicate.
+//
+// Workers stop picking up new chunks once an index has been found below them,
+// so the search is cancelled as soon as the answer is certain. An open-ended
+// search can use usize::MAX as the end of the range.
+pub fn find_first<F>(threads: usize, range: Range<usize>, pred: F) -> Option<usize>
+where
+    F: Fn(usize) -> bool + Sync,
+{
+    if threads <= 1 {
+        return range.into_iter().find(|&n| pred(n));
+    }
+
+    let next_chunk = AtomicUsize::new(range.start);
+    let found = AtomicUsize::new(usize::MAX);
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                let start = next_chunk.fetch_add(FIND_CHUNK_SIZE, Ordering::Relaxed);
+                // Stop if we've run off the end, or if there is already an
+                // answer smaller than anything in this chunk
+                if start < range.start || start >= range.end || start >= found.load(Ordering::Relaxed) {
+                    break;
+                }
+
+                let end = start.saturating_add(FIND_CHUNK_SIZE).min(range.end);
+                if let Some(n) = (start..end).find(|&n| pred(n)) {
+                    found.fetch_min(n, Ordering::Relaxed);
+                    break;
+                }
+            });
+        }
+    });
+
+    match found.into_inner() {
+        usize::MAX => None,
+        n => Some(n),
+    }
+}
+
+// Like find_first, but the range is searched in blocks and the start of the
+// next block is saved to the checkpoint between them. With --resume the search
+// carries on from the last saved block. The checkpoint is cleared once the
+// search has finished.
+pub fn find_first_checkpointed<F>(threads: usize, range: Range<usize>, checkpoint: &mut Checkpoint, pred: F) -> Option<usize>
+where
+    F: Fn(usize) -> bool + Sync,
+{
+    let mut start = match checkpoint.resume::<usize>() {
+        Some(saved) => saved.max(range.start),
+        None => range.start,
+    };
+
+    while start < range.end {
+        let end = start.saturating_add(CHECKPOINT_BLOCK_SIZE).min(range.end);
+        if let Some(n) = find_first(threads, start..end, &pred) {
+            checkpoint.clear();
+            return Some(n);
+        }
+        start = end;
+        checkpoint.save_if_due(&start);
+    }
+
+    checkpoint.clear();
+    None
+}
+
+// Map every index in the range and combine the results with reduce, starting
+// from identity.
+//
+// Each chunk is folded on its own and the chunk results are then reduced in
+// order, so as long as reduce is associative the result is the same as a
+// sequential fold.
+pub fn map_reduce<T, M, R>(threads: usize, range: Range<usize>, identity: T, map: M, reduce: R) -> T
+where
+    T: Clone + Send + Sync,
+    M: Fn(usize) -> T + Sync,
+    R: Fn(T, T) -> T + Sync,
+{
+    if threads <= 1 || range.len() <= 1 {
+        return range.fold(identity, |acc, n| reduce(acc, map(n)));
+    }
+
+    let chunk_count = (threads * CHUNKS_PER_THREAD).min(range.len());
+    let chunk_size = range.len().div_ceil(chunk_count);
+    let next_chunk = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<T>>> = Mutex::new(vec![None; chunk_count]);
+
+    thread::scope(|scope| {
+        for _ in 0..threads.min(chunk_count) {
+            scope.spawn(|| loop {
+                let chunk = next_chunk.fetch_add(1, Ordering::Relaxed);
+                if chunk >= chunk_count {
+                    break;
+                }
+
+                let start = range.start + chunk * chunk_size;
+                let end = (start + chunk_size).min(range.end);
+                let result = (start..end).fold(identity.clone(), |acc, n| reduce(acc, map(n)));
+                results.lock().unwrap()[chunk] = Some(result);
+            });
+        }
+    });
+
+    results.into_inner()
+        .unwrap()
+        .into_iter()
+        .flatten()
+        .fold(identity, &reduce)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_first_gives_the_same_answer_for_any_thread_count() {
+        // Matches in several chunks, so a later chunk can finish first
+        let pred = |n: usize| n % 3_001 == 1_234 || n == 9_999;
+        for threads in [1, 2, 3, 4, 8, 16] {
+            assert_eq!(find_first(threads, 0..100_000, pred), Some(1_234), "{threads} threads");
+            assert_eq!(find_first(threads, 1_235..100_000, pred), Some(4_235), "{threads} threads");
+            assert_eq!(find_first(threads, 0..1_000, pred), None, "{threads} threads");
+        }
+    }
+
+    #[test]
+    fn find_first_stays_inside_the_range() {
+        for threads in [1, 4] {
+            assert_eq!(find_first(threads, 500..1_500, |n| n % 1_000 == 0), Some(1_000), "{threads} threads");
+            assert_eq!(find_first(threads, 1_001..2_000, |n| n % 1_000 == 0), None, "{threads} threads");
+            assert_eq!(find_first(threads, 5..5, |_| true), None, "{threads} threads");
+        }
+    }
+
+    #[test]
+    fn find_first_handles_an_open_ended_range() {
+        for threads in [1, 4] {
+            assert_eq!(find_first(threads, usize::MAX - 5_000..usize::MAX, |n| n == usize::MAX - 2), Some(usize::MAX - 2));
+            assert_eq!(find_first(threads, usize::MAX - 5_000..usize::MAX, |_| false), None);
+        }
+    }
+
+    #[test]
+    fn map_reduce_matches_a_sequential_fold() {
+        // String concatenation is associative but not commutative, so chunks
+        // reduced out of order would show up
+        let expected: String = (0..500).map(|n| n.to_string()).collect();
+        for threads in [1, 2, 3, 7, 64] {
+            let joined = map_reduce(threads, 0..500, String::new(), |n| n.to_string(), |a, b| a + &b);
+            assert_eq!(joined, expected, "{threads} threads");
+            assert_eq!(map_reduce(threads, 0..0, 0, |n| n, |a, b| a + b), 0);
+        }
+    }
+}
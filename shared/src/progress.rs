@@ -0,0 +1,153 @@
+// Progress reporting for long searches.
+//
+// A search calls start() once, tick() for every node it explores and best()
+// whenever it improves on its answer. While running, a status line with the
+// node count, rate and best answer so far is drawn on stderr a few times a
+// second. On Ctrl-C the best answer found so far is printed before exiting, so
+// an interrupted search still gives a usable bound.
+use std::{
+    fmt::Display,
+    io::{self, IsTerminal, Write},
+    process,
+    sync::{
+        Mutex,
+        OnceLock,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+// How often the status line is redrawn
+const REPORT_INTERVAL: Duration = Duration::from_millis(250);
+
+// Only look at the clock every this many ticks, to keep tick() cheap
+const TICKS_PER_CLOCK_CHECK: u64 = 1024;
+
+// Exit code used when interrupted, as a shell would for SIGINT
+const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+struct State {
+    label: String,
+    start: Instant,
+    last_report: Instant,
+    best: Option<String>,
+}
+
+static STATE: Mutex<Option<State>> = Mutex::new(None);
+static NODES: AtomicU64 = AtomicU64::new(0);
+static DRAW: AtomicBool = AtomicBool::new(false);
+static HANDLER: OnceLock<()> = OnceLock::new();
+
+// Start reporting progress for a search. The status line is only drawn if
+// `draw` is set and stderr is a terminal, so it can be turned off when debug
+// messages are being printed.
+pub fn start(label: &str, draw: bool) {
+    let now = Instant::now();
+    *STATE.lock().unwrap() = Some(State {
+        label: label.to_string(),
+        start: now,
+        last_report: now,
+        best: None,
+    });
+    NODES.store(0, Ordering::Relaxed);
+    DRAW.store(draw && io::stderr().is_terminal(), Ordering::Relaxed);
+
+    HANDLER.get_or_init(|| {
+        if let Err(err) = ctrlc::set_handler(interrupted) {
+            eprintln!("Unable to install the Ctrl-C handler: {err}");
+        }
+    });
+}
+
+// Count one more explored node
+pub fn tick() {
+    let nodes = NODES.fetch_add(1, Ordering::Relaxed) + 1;
+    if nodes.is_multiple_of(TICKS_PER_CLOCK_CHECK) && DRAW.load(Ordering::Relaxed) {
+        report(false);
+    }
+}
+
+// Record a new best answer
+pub fn best<T: Display>(best: T) {
+    if let Some(state) = STATE.lock().unwrap().as_mut() {
+        state.best = Some(best.to_string());
+    }
+}
+
+// The best answer recorded so far, if any
+pub fn best_so_far() -> Option<String> {
+    STATE.lock().unwrap().as_ref().and_then(|state| state.best.clone())
+}
+
+// Number of nodes explored so far
+pub fn nodes() -> u64 {
+    NODES.load(Ordering::Relaxed)
+}
+
+// Stop reporting and clear the status line
+pub fn finish() {
+    if DRAW.swap(false, Ordering::Relaxed) {
+        clear_line();
+    }
+    *STATE.lock().unwrap() = None;
+}
+
+// Redraw the status line if enough time has passed, or always if forced
+fn report(force: bool) {
+    let mut guard = STATE.lock().unwrap();
+    let Some(state) = guard.as_mut() else { return; };
+
+    let now = Instant::now();
+    if !force && now.duration_since(state.last_report) < REPORT_INTERVAL {
+        return;
+    }
+    state.last_report = now;
+
+    let nodes = NODES.load(Ordering::Relaxed);
+    let elapsed = now.duration_since(state.start).as_secs_f64();
+    let rate = if elapsed > 0.0 { nodes as f64 / elapsed } else { 0.0 };
+    let best = state.best.as_deref().unwrap_or("none");
+
+    let mut stderr = io::stderr().lock();
+    let _ = write!(stderr, "\r\x1B[2K{}: {} nodes, {}/s, best so far {}", state.label, nodes, human_count(rate), best);
+    let _ = stderr.flush();
+}
+
+fn clear_line() {
+    let mut stderr = io::stderr().lock();
+    let _ = write!(stderr, "\r\x1B[2K");
+    let _ = stderr.flush();
+}
+
+// Format a count with a k/M/G suffix
+fn human_count(n: f64) -> String {
+    if n >= 1e9 {
+        format!("{:.1}G", n / 1e9)
+    } else if n >= 1e6 {
+        format!("{:.1}M", n / 1e6)
+    } else if n >= 1e3 {
+        format!("{:.1}k", n / 1e3)
+    } else {
+        format!("{n:.0}")
+    }
+}
+
+// Ctrl-C handler. Print the best answer found so far and exit.
+fn interrupted() {
+    if DRAW.load(Ordering::Relaxed) {
+        clear_line();
+    }
+
+    let (label, best) = match STATE.lock().unwrap().as_ref() {
+        Some(state) => (state.label.clone(), state.best.clone()),
+        None => process::exit(INTERRUPTED_EXIT_CODE),
+    };
+
+    eprintln!("{label}: interrupted after exploring {} nodes", nodes());
+    match best {
+        Some(best) => println!("Best answer found so far (may not be optimal): {best}"),
+        None => println!("No answer found before being interrupted"),
+    }
+
+    process::exit(INTERRUPTED_EXIT_CODE);
+}
@@ -0,0 +1,178 @@
+// Property tests for puzzle invariants.
+//
+// Some things should hold for every input, not just the examples: escaping a
+// string and reading it back gives the same string, a route visits every
+// location once, and so on. check() runs a property against random inputs from
+// a strategy and fails on the first input it doesn't hold for. That input is
+// shrunk the same way as in differential::check, by dropping lines and making
+// numbers smaller, so the panic message shows a small case to debug.
+//
+// A strategy writes an input from a seeded Rng and a size, like the per-day
+// generators, so a failing case can be reproduced from its seed alone. The
+// helpers below cover the usual shapes of input: number lists, text, grids,
+// graphs between names and instruction lists.
+pub use crate::differential::Config;
+
+use crate::{differential, generate, rng::Rng};
+use std::{
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+};
+
+// Run the property on inputs from the strategy and panic with the smallest
+// input found that it doesn't hold for. The property returns why it failed,
+// and a panic in the property counts as a failure.
+//
+// # Panics
+//
+// This function will panic if the property fails for any input
+pub fn check<S, P>(name: &str, config: Config, strategy: S, property: P)
+where
+    S: Fn(&mut Rng, usize) -> String,
+    P: Fn(&str) -> Result<(), String>,
+{
+    let broken = |input: &str| -> Option<String> {
+        match panic::catch_unwind(AssertUnwindSafe(|| property(input))) {
+            Ok(Ok(())) => None,
+            Ok(Err(reason)) => Some(reason),
+            Err(_) => Some("the property panicked".to_string()),
+        }
+    };
+
+    for case in 0..config.cases {
+        let size = 1 + case % config.max_size.max(1);
+        let seed = config.seed + case as u64;
+        let input = strategy(&mut Rng::new(seed), size);
+
+        if broken(&input).is_some() {
+            let minimal = differential::shrink(&input, |i| broken(i).is_some());
+            panic!(
+                "{name} doesn't hold for the input generated with size {size} and seed {seed}.\n\
+                 Shrunk to:\n{minimal}\n\
+                 {}",
+                broken(&minimal).unwrap_or_default(),
+            );
+        }
+    }
+}
+
+// One number from the range per line, size lines
+pub fn numbers(range: RangeInclusive<usize>) -> impl Fn(&mut Rng, usize) -> String {
+    move |rng, size| (0..size).map(|_| rng.range(range.clone()).to_string()).collect::<Vec<String>>().join("\n")
+}
+
+// A single line of size characters picked from the alphabet
+//
+// # Panics
+//
+// The strategy will panic if the alphabet is empty
+pub fn text(alphabet: &'static str) -> impl Fn(&mut Rng, usize) -> String {
+    let chars: Vec<char> = alphabet.chars().collect();
+    move |rng, size| (0..size).map(|_| *rng.pick(&chars)).collect()
+}
+
+// A square grid, size characters wide, of cells picked from the alphabet
+//
+// # Panics
+//
+// The strategy will panic if the alphabet is empty
+pub fn grid(cells: &'static str) -> impl Fn(&mut Rng, usize) -> String {
+    let row = text(cells);
+    move |rng, size| (0..size).map(|_| row(rng, size)).collect::<Vec<String>>().join("\n")
+}
+
+// A line for every pair of size distinct names, with a weight from the range.
+// line writes one edge, e.g. "{from} to {to} = {weight}". With both_ways each
+// pair gets a line in each direction, as days with one-way weights need.
+pub fn name_graph(
+    weights: RangeInclusive<i64>,
+    both_ways: bool,
+    line: fn(&str, &str, i64) -> String,
+) -> impl Fn(&mut Rng, usize) -> String {
+    move |rng, size| {
+        let names = generate::names(rng, size.max(2));
+        let mut lines: Vec<String> = Vec::new();
+        for (i, from) in names.iter().enumerate() {
+            for (j, to) in names.iter().enumerate() {
+                if i < j || (both_ways && i != j) {
+                    lines.push(line(from, to, rng.range_i64(weights.clone())));
+                }
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+// size instructions, each picked from the templates. Every {n} in a template
+// is replaced by a signed offset from the range, written like "+3" or "-2".
+//
+// # Panics
+//
+// The strategy will panic if there are no templates
+pub fn instructions(templates: &'static [&'static str], offsets: RangeInclusive<i64>) -> impl Fn(&mut Rng, usize) -> String {
+    move |rng, size| {
+        (0..size)
+            .map(|_| {
+                let template = *rng.pick(templates);
+                let mut line = String::new();
+                let mut parts = template.split("{n}");
+                line += parts.next().unwrap_or_default();
+                for part in parts {
+                    line += &format!("{:+}", rng.range_i64(offsets.clone()));
+                    line += part;
+                }
+                line
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passes_when_the_property_holds() {
+        check("sizes are in range", Config::default(), numbers(3..=9), |input| {
+            match input.lines().map(|l| l.parse::<usize>().unwrap()).find(|n| !(3..=9).contains(n)) {
+                Some(n) => Err(format!("{n} is out of range")),
+                None => Ok(()),
+            }
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Shrunk to:\n50\nthe sum is 50")]
+    fn shrinks_the_input_that_fails() {
+        check("the sum is under 50", Config::default(), numbers(0..=100), |input| {
+            let sum: usize = input.lines().map(|l| l.parse::<usize>().unwrap()).sum();
+            if sum < 50 { Ok(()) } else { Err(format!("the sum is {sum}")) }
+        });
+    }
+
+    #[test]
+    fn grids_are_square() {
+        let input = grid("#.")(&mut Rng::new(7), 5);
+
+        assert_eq!(input.lines().count(), 5);
+        assert!(input.lines().all(|l| l.len() == 5 && l.chars().all(|c| c == '#' || c == '.')));
+    }
+
+    #[test]
+    fn name_graphs_have_an_edge_per_pair() {
+        let one_way = name_graph(1..=9, false, |a, b, w| format!("{a} to {b} = {w}"))(&mut Rng::new(7), 4);
+        let both_ways = name_graph(-9..=9, true, |a, b, w| format!("{a} {b} {w}"))(&mut Rng::new(7), 4);
+
+        assert_eq!(one_way.lines().count(), 6);
+        assert_eq!(both_ways.lines().count(), 12);
+    }
+
+    #[test]
+    fn instructions_fill_in_offsets() {
+        let input = instructions(&["jmp {n}", "jio a, {n}"], -3..=3)(&mut Rng::new(7), 20);
+
+        assert_eq!(input.lines().count(), 20);
+        assert!(input.lines().all(|l| l.ends_with(|c: char| c.is_ascii_digit()) && (l.contains('+') || l.contains('-'))));
+    }
+}
@@ -0,0 +1,85 @@
+// A small seeded random number generator for generating inputs.
+//
+// This is SplitMix64. It is nowhere near good enough for cryptography, but it
+// is fast, has no dependencies, and the same seed always gives the same numbers
+// on every platform and Rust version, so generated inputs can be reproduced
+// from their seed alone.
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    // A number from 0 up to but not including n, without modulo bias
+    //
+    // # Panics
+    //
+    // This function will panic if n is 0
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Rng::below needs a non-empty range");
+
+        // Reject the top sliver of values that would make the lower results
+        // more likely
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+
+    // A number in the inclusive range
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (lo, hi) = range.into_inner();
+        if lo >= hi {
+            return lo;
+        }
+        lo + self.below((hi - lo) as u64 + 1) as usize
+    }
+
+    // A signed number in the inclusive range
+    pub fn range_i64(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = range.into_inner();
+        if lo >= hi {
+            return lo;
+        }
+        lo.wrapping_add(self.below(hi.abs_diff(lo) + 1) as i64)
+    }
+
+    // True with a probability of numerator / denominator
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    // A random element of the slice
+    //
+    // # Panics
+    //
+    // This function will panic if the slice is empty
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    // Shuffle the slice in place (Fisher-Yates)
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
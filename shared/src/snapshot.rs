@@ -0,0 +1,210 @@
+// Snapshot testing of solver output.
+//
+// Debug traces like day 22's turn-by-turn combat are a good description of how
+// a solver behaves, so tests can pin them down. assert_snapshot compares the
+// output against a copy committed as <name>.snap and fails with a diff if they
+// differ. The new output is then written next to it as <name>.snap.new, ready
+// to be looked at and accepted, either by rerunning the tests with AOC_ACCEPT=1
+// or with `aoc accept`, which moves every pending snapshot into place.
+use std::{
+    env,
+    fs,
+    io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+// Set to accept new output as the snapshot instead of failing
+pub const ACCEPT_VAR: &str = "AOC_ACCEPT";
+
+// Extension of new output waiting to be accepted
+pub const PENDING_EXTENSION: &str = "snap.new";
+
+// Lines of unchanged output shown around each change in a diff
+const CONTEXT: usize = 3;
+
+// Compare actual against the snapshot called name in dir. Snapshots are
+// written with AOC_ACCEPT=1 set.
+//
+// # Panics
+//
+// This function will panic if the snapshot is missing or doesn't match, or if
+// the snapshot files can't be read or written
+pub fn assert_snapshot(dir: impl AsRef<Path>, name: &str, actual: &str) {
+    let path = dir.as_ref().join(format!("{name}.snap"));
+    let pending = path.with_extension(PENDING_EXTENSION);
+
+    if env::var_os(ACCEPT_VAR).is_some() {
+        fs::create_dir_all(dir.as_ref())
+            .unwrap_or_else(|err| panic!("Unable to create {}: {err}", dir.as_ref().display()));
+        fs::write(&path, actual).unwrap_or_else(|err| panic!("Unable to write {}: {err}", path.display()));
+        let _ = fs::remove_file(&pending);
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).ok();
+    if expected.as_deref() == Some(actual) {
+        let _ = fs::remove_file(&pending);
+        return;
+    }
+
+    fs::create_dir_all(dir.as_ref())
+        .unwrap_or_else(|err| panic!("Unable to create {}: {err}", dir.as_ref().display()));
+    fs::write(&pending, actual).unwrap_or_else(|err| panic!("Unable to write {}: {err}", pending.display()));
+
+    let problem = match expected {
+        Some(expected) => format!("Snapshot {name} has changed:\n{}", diff(&expected, actual)),
+        None => format!("Snapshot {name} doesn't exist yet"),
+    };
+    panic!(
+        "{problem}\nThe new output is in {}. Run `aoc accept` or rerun the tests with {ACCEPT_VAR}=1 to accept it.",
+        pending.display(),
+    );
+}
+
+// Run a solver binary and return what it printed to stdout. Tests get the path
+// of their crate's binary from env!("CARGO_BIN_EXE_<name>").
+//
+// # Panics
+//
+// This function will panic if the binary can't be run or doesn't succeed
+pub fn run_binary(binary: &str, args: &[&str]) -> String {
+    let output = Command::new(binary)
+        .args(args)
+        .output()
+        .unwrap_or_else(|err| panic!("Unable to run {binary}: {err}"));
+    assert!(
+        output.status.success(),
+        "{binary} {} failed with {}\n{}",
+        args.join(" "),
+        output.status,
+        String::from_utf8_lossy(&output.stderr),
+    );
+
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+// The lines that differ between expected and actual: lines only in expected
+// start with -, lines only in actual with +, and a few unchanged lines around
+// each change start with a space. Each group of changes is headed by the line
+// it starts at in expected.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // lengths[i][j] is the longest common subsequence of old[i..] and new[j..]
+    let mut lengths = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    // Walk the table to get each line tagged with how it changed, and the line
+    // of expected it is at
+    let mut edits: Vec<(char, usize, &str)> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            edits.push((' ', i, old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lengths[i + 1][j] >= lengths[i][j + 1]) {
+            edits.push(('-', i, old[i]));
+            i += 1;
+        } else {
+            edits.push(('+', i, new[j]));
+            j += 1;
+        }
+    }
+
+    // Only keep the changes and the context around them
+    let changed: Vec<usize> = (0..edits.len()).filter(|&k| edits[k].0 != ' ').collect();
+    let mut shown = vec![false; edits.len()];
+    for &k in &changed {
+        let end = (k + CONTEXT + 1).min(edits.len());
+        shown[k.saturating_sub(CONTEXT)..end].iter_mut().for_each(|show| *show = true);
+    }
+
+    let mut out = String::new();
+    for (k, &(tag, line, text)) in edits.iter().enumerate() {
+        if !shown[k] {
+            continue;
+        }
+        if k == 0 || !shown[k - 1] {
+            out.push_str(&format!("@@ line {} @@\n", line + 1));
+        }
+        out.push_str(&format!("{tag}{text}\n"));
+    }
+
+    out
+}
+
+// Every snapshot waiting to be accepted under dir. The target directory and
+// hidden directories are skipped.
+pub fn pending(dir: &Path) -> Vec<PathBuf> {
+    let mut found: Vec<PathBuf> = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return found;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if path.is_dir() {
+            if name != "target" && !name.starts_with('.') {
+                found.extend(pending(&path));
+            }
+        } else if name.ends_with(&format!(".{PENDING_EXTENSION}")) {
+            found.push(path);
+        }
+    }
+    found.sort();
+
+    found
+}
+
+// Move a pending snapshot over the snapshot it is for, and return the path of
+// the snapshot
+pub fn accept(pending: &Path) -> io::Result<PathBuf> {
+    let snapshot = pending.with_extension("");
+    fs::rename(pending, &snapshot)?;
+
+    Ok(snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_shows_changes_with_context() {
+        let expected = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10";
+        let actual = "1\n2\n3\n4\n5\nsix\n7\n8\n9\n10";
+
+        assert_eq!(diff(expected, actual), "@@ line 3 @@\n 3\n 4\n 5\n-6\n+six\n 7\n 8\n 9\n");
+    }
+
+    #[test]
+    fn diff_is_empty_for_the_same_output() {
+        assert_eq!(diff("a\nb", "a\nb"), "");
+    }
+
+    #[test]
+    fn accepts_and_then_matches_a_snapshot() {
+        let dir = env::temp_dir().join(format!("aoc-snapshot-test-{}", std::process::id()));
+        let result = std::panic::catch_unwind(|| assert_snapshot(&dir, "new", "output\n"));
+        assert!(result.is_err());
+
+        let accepted = accept(&dir.join("new.snap.new")).unwrap();
+        assert_eq!(accepted, dir.join("new.snap"));
+        assert_snapshot(&dir, "new", "output\n");
+        assert!(pending(&dir).is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
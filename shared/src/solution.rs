@@ -0,0 +1,328 @@
+// The common shape of a day, and a driver to run it.
+//
+// A day implements Solution: parse turns the input text into whatever the parts
+// work from, and part1/part2 produce the answers. The driver runs each phase
+// under Stats::measure, so every day gets --timings, --stats and --stats-json
+// for free.
+//
+// Days with settings of their own (day 10's rounds, day 14's race length, day
+// 24's groups) keep them as fields on the type that implements Solution. The
+// day's main reads its own arguments into them and hands the input to
+// run_file, or to run_input for the days that are given numbers instead of a
+// file. The fields default to the puzzle's own settings, so a day can be
+// solved from its input alone.
+use crate::{
+    Answer,
+    AocError,
+    load_input_file,
+    parse_args,
+    stats::Stats,
+    validate,
+};
+use std::{
+    fmt,
+    io::{self, IsTerminal},
+    process,
+    sync::{
+        Mutex,
+        PoisonError,
+        atomic::{AtomicBool, AtomicU8, Ordering},
+    },
+};
+
+// The part asked for on the command line, 0 for both
+static PART: AtomicU8 = AtomicU8::new(0);
+static ANSWER_LINES: AtomicBool = AtomicBool::new(false);
+
+// Only one solution is run in process at a time, as the problems found while
+// parsing are collected in one place
+static IN_PROCESS: Mutex<()> = Mutex::new(());
+
+// Only solve the given part, usually from the --part flag. None solves both.
+pub fn set_part(part: Option<u8>) {
+    PART.store(part.unwrap_or(0), Ordering::Relaxed);
+}
+
+// Whether this run should solve the given part
+pub fn wants(part: u8) -> bool {
+    let selected = PART.load(Ordering::Relaxed);
+    selected == 0 || selected == part
+}
+
+// Also print every answer on a line of its own, usually from the --answers
+// flag. The runner asks for these so it can tell which answer belongs to which
+// part without reading the text around them.
+pub fn set_answer_lines(on: bool) {
+    ANSWER_LINES.store(on, Ordering::Relaxed);
+}
+
+// Print the answer to a part as "Part <n>: <answer>", if answer lines were
+// asked for
+pub fn answer(part: u8, answer: impl fmt::Display) {
+    if ANSWER_LINES.load(Ordering::Relaxed) {
+        println!("Part {part}: {answer}");
+    }
+}
+
+pub trait Solution {
+    type Input;
+
+    // The parts this crate solves. --part picks one of them.
+    const PARTS: &'static [u8] = &[1, 2];
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError>;
+
+    fn part1(&self, _input: &Self::Input) -> Result<Answer, AocError> {
+        Err(AocError::Unsolved { part: 1 })
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer, AocError> {
+        Err(AocError::Unsolved { part: 2 })
+    }
+
+    // The line printed for an answer
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        format!("Part {part}: {answer}")
+    }
+}
+
+// A Solution with its parsed input hidden, so the solutions for different days
+// can sit in one table and be run in process, as aoc serve does
+pub trait Solve: Sync {
+    // The parts the solution covers
+    fn parts(&self) -> &'static [u8];
+
+    // Parse the input and solve one part of it. Nothing is printed and nothing
+    // exits: problems with the input come back with the answer.
+    fn solve_part(&self, input: &str, part: u8) -> Solved;
+}
+
+impl fmt::Debug for dyn Solve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Solve {{ parts: {:?} }}", self.parts())
+    }
+}
+
+// One part solved in process: the answer with the line the day prints for it,
+// or every problem with the input and the error that stopped the part
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub answer: Result<(Answer, String), Vec<AocError>>,
+    pub stats: Stats,
+}
+
+impl<S: Solution + Sync> Solve for S {
+    fn parts(&self) -> &'static [u8] {
+        S::PARTS
+    }
+
+    fn solve_part(&self, input: &str, part: u8) -> Solved {
+        let _only = IN_PROCESS.lock().unwrap_or_else(PoisonError::into_inner);
+        validate::set_input("input", input);
+        validate::take();
+
+        let mut stats = Stats::new();
+        let parsed = stats.measure("parse", || self.parse(input));
+        let mut problems = validate::take();
+
+        let answer = match parsed {
+            Ok(parsed) if problems.is_empty() => {
+                let answer = match part {
+                    1 if S::PARTS.contains(&1) => stats.measure("part1", || self.part1(&parsed)),
+                    2 if S::PARTS.contains(&2) => stats.measure("part2", || self.part2(&parsed)),
+                    _ => Err(AocError::Unsolved { part }),
+                };
+                match answer {
+                    Ok(answer) => Ok((answer.clone(), self.describe(part, &answer))),
+                    Err(err) => Err(vec![err]),
+                }
+            },
+            Ok(_) => Err(problems),
+            Err(err) => {
+                problems.push(err);
+                Err(problems)
+            },
+        };
+
+        Solved { answer, stats }
+    }
+}
+
+// The answers from a run and the stats for each phase. Each part asked for has
+// its answer, or the reason there isn't one.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub answers: Vec<(u8, Result<Answer, AocError>)>,
+    pub stats: Stats,
+}
+
+// Parse the input once and solve every part the solution covers that was asked
+// for
+pub fn solve<S: Solution>(solution: &S, input: &str) -> Result<Run, AocError> {
+    let mut stats = Stats::new();
+    let parsed = stats.measure("parse", || solution.parse(input))?;
+    validate::finish();
+
+    let mut answers: Vec<(u8, Result<Answer, AocError>)> = Vec::new();
+    for &part in S::PARTS.iter().filter(|&&part| wants(part)) {
+        let answer = match part {
+            1 => stats.measure("part1", || solution.part1(&parsed)),
+            2 => stats.measure("part2", || solution.part2(&parsed)),
+            _ => Err(AocError::Unsolved { part }),
+        };
+        answers.push((part, answer));
+    }
+
+    Ok(Run { answers, stats })
+}
+
+// Run a day from the command line: read the input file named in the arguments,
+// solve it, and print the answers followed by any stats that were asked for.
+//
+// # Exits - code 1
+//
+// This function will exit the process if the arguments or input file are bad,
+// or the solution returns an error
+pub fn run<S: Solution>(solution: &S) {
+    let args = parse_args();
+    run_file(solution, &args[1]);
+}
+
+// Run a day on the named input file, for days that read their own arguments
+//
+// # Exits - code 1
+//
+// This function will exit the process if the input file is bad, or the
+// solution returns an error
+pub fn run_file<S: Solution>(solution: &S, filename: &str) {
+    let file_contents = load_input_file(filename);
+    run_input(solution, &file_contents);
+}
+
+// Run a day on the input text and print what it found. Days given numbers on
+// the command line instead of an input file (day 20's target, day 25's row and
+// column) pass them in as text.
+//
+// A part that can't be solved for this input says why and the other parts
+// carry on, like an external solution's errors.
+//
+// # Exits - code 1
+//
+// This function will exit the process if the solution returns any other error.
+// The answers to the other parts are printed first.
+pub fn run_input<S: Solution>(solution: &S, input: &str) {
+    let run = match solve(solution, input) {
+        Ok(run) => run,
+        Err(err) => err.exit(),
+    };
+
+    let mut failed = false;
+    for (part, result) in &run.answers {
+        match result {
+            Ok(value) => {
+                println!("{}", solution.describe(*part, value));
+                answer(*part, value);
+            },
+            Err(AocError::Unsolvable { reason }) => println!("No answer to part {part}: {reason}"),
+            Err(err) => {
+                eprintln!("{}", err.report(io::stderr().is_terminal()));
+                failed = true;
+            },
+        }
+    }
+    run.stats.report();
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    // Counts its parses. Part 1 is the number of lines and part 2 has no
+    // answer.
+    struct Lines {
+        parses: Cell<usize>,
+    }
+
+    impl Solution for Lines {
+        type Input = usize;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+            self.parses.set(self.parses.get() + 1);
+            Ok(input.lines().count())
+        }
+
+        fn part1(&self, lines: &Self::Input) -> Result<Answer, AocError> {
+            Ok((*lines).into())
+        }
+
+        fn part2(&self, _lines: &Self::Input) -> Result<Answer, AocError> {
+            Err(AocError::Unsolvable { reason: "no second part".to_string() })
+        }
+    }
+
+    #[test]
+    fn solves_every_part_from_one_parse() {
+        let lines = Lines { parses: Cell::new(0) };
+        let run = solve(&lines, "a\nb\nc\n").unwrap();
+
+        assert_eq!(lines.parses.get(), 1);
+        assert_eq!(run.answers, vec![
+            (1, Ok(Answer::Number(3))),
+            (2, Err(AocError::Unsolvable { reason: "no second part".to_string() })),
+        ]);
+        let phases: Vec<&str> = run.stats.phases.iter().map(|phase| phase.name.as_str()).collect();
+        assert_eq!(phases, ["parse", "part1", "part2"]);
+    }
+
+    // Rejects every line that isn't a number, and sums the rest
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = usize;
+
+        const PARTS: &'static [u8] = &[1];
+
+        fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+            let mut sum = 0;
+            for (i, line) in input.lines().enumerate() {
+                match line.parse::<usize>() {
+                    Ok(n) => sum += n,
+                    Err(_) => validate::reject(i + 1, 1, line, "expected a number"),
+                }
+            }
+            Ok(sum)
+        }
+
+        fn part1(&self, sum: &Self::Input) -> Result<Answer, AocError> {
+            Ok((*sum).into())
+        }
+
+        fn describe(&self, _part: u8, answer: &Answer) -> String {
+            format!("The sum is {answer}")
+        }
+    }
+
+    #[test]
+    fn solves_a_part_in_process() {
+        let solved = Sum.solve_part("1\n2\n", 1);
+
+        assert_eq!(solved.answer, Ok((Answer::Number(3), "The sum is 3".to_string())));
+        assert_eq!(solved.stats.phases.len(), 2);
+        assert_eq!(Sum.solve_part("1\n", 2).answer, Err(vec![AocError::Unsolved { part: 2 }]));
+    }
+
+    #[test]
+    fn returns_the_problems_with_the_input() {
+        let problems = Sum.solve_part("1\nx\n3\ny\n", 1).answer.unwrap_err();
+
+        let problems: Vec<String> = problems.iter().map(AocError::to_string).collect();
+        assert_eq!(problems, ["input:2:1: expected a number: x", "input:4:1: expected a number: y"]);
+        // Nothing is left behind for the next input
+        assert_eq!(Sum.solve_part("5\n", 1).answer.map(|(answer, _)| answer), Ok(Answer::Number(5)));
+    }
+}
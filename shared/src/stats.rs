@@ -0,0 +1,147 @@
+// Per-phase statistics for a run.
+//
+// A solver wraps each phase (parse, part1, part2) in Stats::measure and calls
+// report() at the end. Every phase is timed with a monotonic clock, and with the
+// `alloc-stats` feature its allocations are counted too. What gets printed is
+// chosen on the command line:
+//
+//   --timings     the time taken by each phase
+//   --stats       the allocation counts for each phase
+//   --stats-json  everything, as a single JSON object
+//
+// Everything is printed on stderr, so it never gets mixed up with the answers on
+// stdout.
+use crate::alloc::{self, AllocStats};
+use serde::{Serialize, Serializer};
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+static TIMINGS: AtomicBool = AtomicBool::new(false);
+static ALLOC_TABLE: AtomicBool = AtomicBool::new(false);
+static JSON: AtomicBool = AtomicBool::new(false);
+
+// Print the time taken by each phase, usually from the --timings flag
+pub fn set_timings(on: bool) {
+    TIMINGS.store(on, Ordering::Relaxed);
+}
+
+// Print the allocation counts for each phase, usually from the --stats flag
+pub fn set_alloc_table(on: bool) {
+    ALLOC_TABLE.store(on, Ordering::Relaxed);
+}
+
+// Print the stats as JSON, usually from the --stats-json flag
+pub fn set_json(on: bool) {
+    JSON.store(on, Ordering::Relaxed);
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Phase {
+    pub name: String,
+    #[serde(rename = "elapsed_secs", serialize_with = "serialize_secs")]
+    pub elapsed: Duration,
+    // None unless the counting allocator is installed
+    pub alloc: Option<AllocStats>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Stats {
+    pub phases: Vec<Phase>,
+}
+
+impl Stats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Run one phase of the solver and record its stats
+    pub fn measure<T, F: FnOnce() -> T>(&mut self, name: &str, f: F) -> T {
+        let start = Instant::now();
+        let (result, alloc) = alloc::measure(f);
+        let elapsed = start.elapsed();
+
+        self.phases.push(Phase { name: name.to_string(), elapsed, alloc });
+
+        result
+    }
+
+    // Time taken by every phase together
+    pub fn total(&self) -> Duration {
+        self.phases.iter().map(|phase| phase.elapsed).sum()
+    }
+
+    // Print the stats chosen on the command line, if any
+    pub fn report(&self) {
+        let timings = TIMINGS.load(Ordering::Relaxed);
+        let alloc_table = ALLOC_TABLE.load(Ordering::Relaxed);
+
+        if timings || alloc_table {
+            eprint!("{}", self.to_text(timings, alloc_table));
+        }
+        if JSON.load(Ordering::Relaxed) {
+            match serde_json::to_string(self) {
+                Ok(json) => eprintln!("{json}"),
+                Err(err) => eprintln!("Unable to serialize the stats: {err}"),
+            }
+        }
+    }
+
+    // A table with a row per phase, with the time taken and/or allocation
+    // counts
+    pub fn to_text(&self, timings: bool, alloc_table: bool) -> String {
+        let missing_alloc = alloc_table && !alloc::enabled();
+        let alloc_table = alloc_table && alloc::enabled();
+        if missing_alloc && !timings {
+            return "Allocation stats are only counted with the shared/alloc-stats feature\n".to_string();
+        }
+
+        let mut text = format!("{:<8}", "phase");
+        if timings { text += &format!("{:>12}", "time"); }
+        if alloc_table { text += &format!("{:>12}{:>12}{:>14}", "peak", "allocated", "allocations"); }
+        text += "\n";
+
+        for phase in &self.phases {
+            text += &format!("{:<8}", phase.name);
+            if timings { text += &format!("{:>12}", human_duration(phase.elapsed)); }
+            if alloc_table {
+                let alloc = phase.alloc.unwrap_or_default();
+                text += &format!(
+                    "{:>12}{:>12}{:>14}",
+                    alloc::human_bytes(alloc.peak_bytes),
+                    alloc::human_bytes(alloc.total_bytes),
+                    alloc.allocations,
+                );
+            }
+            text += "\n";
+        }
+
+        if timings {
+            text += &format!("{:<8}{:>12}\n", "total", human_duration(self.total()));
+        }
+        if missing_alloc {
+            text += "Allocation stats are only counted with the shared/alloc-stats feature\n";
+        }
+
+        text
+    }
+}
+
+// Format a duration in the largest unit that keeps it above 1
+pub fn human_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    if secs >= 1.0 {
+        format!("{secs:.2} s")
+    } else if secs >= 1e-3 {
+        format!("{:.2} ms", secs * 1e3)
+    } else if secs >= 1e-6 {
+        format!("{:.2} µs", secs * 1e6)
+    } else {
+        format!("{} ns", duration.as_nanos())
+    }
+}
+
+fn serialize_secs<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
@@ -0,0 +1,193 @@
+// Problems found in an input while parsing it, and what to do about them.
+//
+// Parsers call reject for every line they can't use instead of printing a
+// message and carrying on. Once parsing is done the day calls finish, which
+// applies the policy:
+//
+//   strict (the default)  list the problems and exit, so no line is lost
+//   --lenient             warn about the problems and solve without the lines
+//   --check               only parse, list every problem and exit
+use crate::{AocError, error::Span};
+use std::{
+    io::{self, IsTerminal},
+    process,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+static LENIENT: AtomicBool = AtomicBool::new(false);
+static CHECK: AtomicBool = AtomicBool::new(false);
+static INPUT: Mutex<String> = Mutex::new(String::new());
+static SOURCE: Mutex<String> = Mutex::new(String::new());
+static PROBLEMS: Mutex<Vec<AocError>> = Mutex::new(Vec::new());
+
+// Skip bad lines with a warning instead of stopping, usually from --lenient
+pub fn set_lenient(lenient: bool) {
+    LENIENT.store(lenient, Ordering::Relaxed);
+}
+
+pub fn lenient() -> bool {
+    LENIENT.load(Ordering::Relaxed)
+}
+
+// Only parse the input and report its problems, usually from --check
+pub fn set_check(check: bool) {
+    CHECK.store(check, Ordering::Relaxed);
+}
+
+pub fn checking() -> bool {
+    CHECK.load(Ordering::Relaxed)
+}
+
+// Name the input that problems are reported against, and keep its text so
+// reports can show the lines. load_input_file does this for the file it reads.
+pub fn set_input(name: &str, contents: &str) {
+    *INPUT.lock().unwrap() = name.to_string();
+    *SOURCE.lock().unwrap() = contents.to_string();
+}
+
+pub fn input_name() -> String {
+    INPUT.lock().unwrap().clone()
+}
+
+// The text of a line of the input, counting from 1
+pub fn source_line(line: usize) -> Option<String> {
+    let source = SOURCE.lock().unwrap();
+    source.lines().nth(line.checked_sub(1)?).map(str::to_string)
+}
+
+// Record a problem with the given line of the input. Lines and columns count
+// from 1. text is the part of the line that is wrong, or the whole line if it
+// can't be narrowed down.
+pub fn reject(line: usize, column: usize, text: &str, message: &str) {
+    reject_error(AocError::Input {
+        span: Box::new(Span::at(line, column, text.chars().count())),
+        message: message.to_string(),
+        expected: None,
+        hint: None,
+    });
+}
+
+// Record a problem that already has its span, for parsers that can say what
+// was expected or give a hint
+pub fn reject_error(error: AocError) {
+    PROBLEMS.lock().unwrap().push(error);
+}
+
+// The column that part starts at in line, where part is a slice of line. The
+// first column if it isn't.
+pub fn column(line: &str, part: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (part.as_ptr() as usize).wrapping_sub(start);
+    if offset <= line.len() {
+        line[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+// The problems recorded so far
+pub fn problems() -> Vec<AocError> {
+    PROBLEMS.lock().unwrap().clone()
+}
+
+// Take the problems recorded so far, leaving none behind. Used by finish, and
+// by anything that parses many inputs in one process, like the fuzz targets.
+pub fn take() -> Vec<AocError> {
+    PROBLEMS.lock().unwrap().drain(..).collect()
+}
+
+// Act on the problems found while parsing, following the policy set on the
+// command line. Returns when solving should go ahead.
+//
+// # Exits
+//
+// This function will exit the process with code 0 after --check if there were
+// no problems, and with code 1 if there were problems and either --check was
+// given or the policy is strict
+pub fn finish() {
+    let problems = take();
+    let input = input_name();
+
+    if checking() {
+        for problem in &problems {
+            println!("{problem}");
+        }
+        if problems.is_empty() {
+            println!("{input}: no problems found");
+            process::exit(0);
+        }
+        println!("{input}: {} problem(s) found", problems.len());
+        process::exit(1);
+    }
+
+    if problems.is_empty() {
+        return;
+    }
+
+    if lenient() {
+        for problem in &problems {
+            eprintln!("WARNING: skipped {problem}");
+        }
+        return;
+    }
+
+    let color = io::stderr().is_terminal();
+    for problem in &problems {
+        eprintln!("{}\n", problem.report(color));
+    }
+    eprintln!("{input}: {} line(s) could not be used. Pass --lenient to solve without them.", problems.len());
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_counts_characters_up_to_the_part() {
+        let line = "Frosting: capacity 4, durability -2";
+        assert_eq!(column(line, line), 1);
+        assert_eq!(column(line, &line[10..18]), 11);
+        assert_eq!(column(line, &line[line.len()..]), line.len() + 1);
+
+        let line = "ünïcödé 12";
+        assert_eq!(column(line, &line[line.len() - 2..]), 9);
+    }
+
+    #[test]
+    fn column_of_text_from_elsewhere_is_the_first() {
+        let line = "inc a";
+        let other = String::from("a");
+        assert_eq!(column(line, &other), 1);
+    }
+
+    // The policies that exit the process are covered by running a day's binary
+    // in 2015/02's tests. These share the global problem list, so they are
+    // kept in one test.
+    #[test]
+    fn finish_returns_when_solving_can_go_ahead() {
+        set_input("input.txt", "1x1x1\n1xbx1\n1x1xc\n");
+        assert_eq!(source_line(2).as_deref(), Some("1xbx1"));
+        assert_eq!(source_line(0), None);
+        assert_eq!(source_line(4), None);
+
+        // Strict with nothing wrong
+        finish();
+
+        // Lenient with problems, which are used up by finish
+        reject(2, 3, "b", "Length was not a valid number!");
+        reject(3, 5, "c", "Height was not a valid number!");
+        let recorded = problems();
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(recorded[0].to_string(), "input.txt:2:3: Length was not a valid number!: b");
+        assert_eq!(recorded[1].to_string(), "input.txt:3:5: Height was not a valid number!: c");
+
+        set_lenient(true);
+        finish();
+        set_lenient(false);
+        assert!(problems().is_empty());
+    }
+}